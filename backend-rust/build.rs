@@ -0,0 +1,4 @@
+// migrations/ 디렉토리에 파일이 추가되면 다시 빌드 (sqlx::migrate! 가 컴파일 시 포함)
+fn main() {
+    println!("cargo:rerun-if-changed=migrations");
+}
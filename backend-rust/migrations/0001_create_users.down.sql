@@ -0,0 +1,2 @@
+-- users 테이블은 다른 백엔드와 공유하므로 삭제하지 않고 이 백엔드가 추가한 트리거만 제거
+DROP TRIGGER IF EXISTS update_users_updated_at ON users;
@@ -0,0 +1,60 @@
+-- 역할 기반 접근 제어 (RBAC)
+-- users.role 컬럼은 다른 백엔드와 공유하므로 그대로 두고 "기본 역할"로 취급하며,
+-- user_roles는 추가로 부여된 역할을 저장한다
+CREATE TABLE IF NOT EXISTS roles (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(50) UNIQUE NOT NULL,
+    description VARCHAR(255) NOT NULL DEFAULT '',
+    is_system BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS permissions (
+    id SERIAL PRIMARY KEY,
+    code VARCHAR(100) UNIQUE NOT NULL,
+    description VARCHAR(255) NOT NULL DEFAULT ''
+);
+
+CREATE TABLE IF NOT EXISTS role_permissions (
+    role_id INTEGER NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    permission_id INTEGER NOT NULL REFERENCES permissions(id) ON DELETE CASCADE,
+    PRIMARY KEY (role_id, permission_id)
+);
+
+CREATE TABLE IF NOT EXISTS user_roles (
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role_id INTEGER NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    PRIMARY KEY (user_id, role_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_roles_role_id ON user_roles (role_id);
+
+CREATE TRIGGER update_roles_updated_at
+    BEFORE UPDATE ON roles
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 기본 역할 (users.role 값으로 사용되므로 삭제 불가)
+INSERT INTO roles (name, description, is_system) VALUES
+    ('user', '일반 사용자', true),
+    ('admin', '관리자', true)
+ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO permissions (code, description) VALUES
+    ('users.read', '사용자 조회'),
+    ('users.update', '다른 사용자 정보 및 역할/활성 상태 수정'),
+    ('users.delete', '사용자 삭제'),
+    ('roles.manage', '역할 및 권한 관리'),
+    ('announcements.publish', '공지사항 게시')
+ON CONFLICT (code) DO NOTHING;
+
+-- 관리자는 모든 권한, 일반 사용자는 조회 권한
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id FROM roles r CROSS JOIN permissions p WHERE r.name = 'admin'
+ON CONFLICT DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id FROM roles r JOIN permissions p ON p.code = 'users.read' WHERE r.name = 'user'
+ON CONFLICT DO NOTHING;
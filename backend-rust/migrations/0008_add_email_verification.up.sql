@@ -0,0 +1,19 @@
+-- 이메일 인증 일시 (NULL이면 미인증)
+ALTER TABLE users ADD COLUMN IF NOT EXISTS email_verified_at TIMESTAMPTZ;
+
+-- 기능 도입 이전에 가입한 사용자는 인증된 것으로 간주
+UPDATE users SET email_verified_at = created_at WHERE email_verified_at IS NULL;
+
+-- 이메일 인증 토큰 (토큰 원문 대신 SHA-256 해시만 저장, 1회용)
+-- 발급 당시의 이메일을 함께 저장해 그 사이 이메일이 바뀌면 인증되지 않도록 한다
+CREATE TABLE IF NOT EXISTS email_verification_tokens (
+    id BIGSERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    email VARCHAR(255) NOT NULL,
+    token_hash VARCHAR(64) UNIQUE NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_email_verification_tokens_user_id ON email_verification_tokens (user_id);
@@ -0,0 +1,12 @@
+-- 로그인 실패 카운터 (여러 인스턴스가 같은 상태를 공유하도록 데이터베이스에 저장)
+-- scope: 'account'(소문자 이메일) 또는 'ip'(클라이언트 IP)
+CREATE TABLE IF NOT EXISTS login_throttles (
+    scope VARCHAR(10) NOT NULL,
+    key VARCHAR(255) NOT NULL,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    last_failed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_until TIMESTAMPTZ,
+    PRIMARY KEY (scope, key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_login_throttles_last_failed_at ON login_throttles (last_failed_at);
@@ -0,0 +1,12 @@
+-- 삭제된 사용자가 남아 있으면 되돌리지 않음 (deleted_at이 없어지면 삭제된 사용자가 되살아남)
+-- 복구하거나 영구 삭제한 뒤 다시 실행
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM users WHERE deleted_at IS NOT NULL) THEN
+        RAISE EXCEPTION '삭제된 사용자가 있어 되돌릴 수 없습니다. 복구하거나 영구 삭제한 뒤 다시 실행하세요';
+    END IF;
+END $$;
+
+DROP INDEX IF EXISTS idx_users_deleted_at;
+ALTER TABLE users DROP COLUMN IF EXISTS deleted_email;
+ALTER TABLE users DROP COLUMN IF EXISTS deleted_at;
@@ -0,0 +1,11 @@
+-- 사용자 소프트 삭제
+-- deleted_at이 있으면 삭제된 사용자 (목록/조회/로그인에서 제외하고 보관 기간이 지나면 영구 삭제)
+ALTER TABLE users ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+-- 이메일 유니크 제약(users_email_key)은 deleted_at을 모르는 다른 백엔드도 의존하므로 그대로 유지한다
+-- 삭제할 때 원래 이메일은 deleted_email에 옮기고 email을 'deleted+<id>@deleted.invalid'로 바꿔
+-- 같은 이메일로 다시 가입할 수 있게 하고, 복구할 때 되돌린다 (원래 이메일 길이와 관계없이 VARCHAR(255)에 들어감)
+ALTER TABLE users ADD COLUMN IF NOT EXISTS deleted_email VARCHAR(255);
+
+-- 보관 기간이 지난 사용자 영구 삭제용
+CREATE INDEX IF NOT EXISTS idx_users_deleted_at ON users (deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,3 @@
+DELETE FROM permissions WHERE code = 'departments.manage';
+DROP TABLE IF EXISTS department_members;
+DROP TABLE IF EXISTS departments;
@@ -0,0 +1,50 @@
+-- 부서 트리 (parent_id가 NULL이면 최상위 부서)
+-- 하위 부서나 소속 사용자가 있는 부서는 삭제할 수 없음
+CREATE TABLE IF NOT EXISTS departments (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    parent_id INTEGER REFERENCES departments(id) ON DELETE RESTRICT,
+    head_user_id INTEGER REFERENCES users(id) ON DELETE SET NULL, -- 부서장
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CHECK (parent_id IS NULL OR parent_id <> id)
+);
+
+-- 같은 상위 부서 아래에서 이름 중복 불가 (최상위 부서끼리도 포함)
+CREATE UNIQUE INDEX IF NOT EXISTS departments_parent_name_key ON departments (COALESCE(parent_id, 0), name);
+CREATE INDEX IF NOT EXISTS idx_departments_parent_id ON departments (parent_id);
+
+DROP TRIGGER IF EXISTS update_departments_updated_at ON departments;
+CREATE TRIGGER update_departments_updated_at
+    BEFORE UPDATE ON departments
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 사용자 소속 (사용자당 한 부서), 직함과 직속 상사
+CREATE TABLE IF NOT EXISTS department_members (
+    user_id INTEGER PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    department_id INTEGER NOT NULL REFERENCES departments(id) ON DELETE RESTRICT,
+    title VARCHAR(100),
+    manager_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CHECK (manager_id IS NULL OR manager_id <> user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_department_members_department_id ON department_members (department_id);
+CREATE INDEX IF NOT EXISTS idx_department_members_manager_id ON department_members (manager_id);
+
+DROP TRIGGER IF EXISTS update_department_members_updated_at ON department_members;
+CREATE TRIGGER update_department_members_updated_at
+    BEFORE UPDATE ON department_members
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+INSERT INTO permissions (code, description) VALUES
+    ('departments.manage', '부서 및 소속 관리')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id FROM roles r JOIN permissions p ON p.code = 'departments.manage' WHERE r.name = 'admin'
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,25 @@
+-- 사용자 프로필 (임직원 디렉터리 확장 항목)
+-- visibility는 항목별 공개 범위 ({"phone": "colleagues", ...}), 없는 항목은 애플리케이션 기본값 적용
+CREATE TABLE IF NOT EXISTS user_profiles (
+    user_id INTEGER PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    phone VARCHAR(30),
+    extension VARCHAR(20),      -- 내선 번호
+    office_location VARCHAR(100),
+    job_title VARCHAR(100),
+    hire_date DATE,
+    birthday DATE,
+    bio TEXT,
+    skills TEXT[] NOT NULL DEFAULT '{}',
+    visibility JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+-- 기술 태그로 사용자 찾기
+CREATE INDEX IF NOT EXISTS idx_user_profiles_skills ON user_profiles USING GIN (skills);
+
+DROP TRIGGER IF EXISTS update_user_profiles_updated_at ON user_profiles;
+CREATE TRIGGER update_user_profiles_updated_at
+    BEFORE UPDATE ON user_profiles
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,5 @@
+-- 프로필 사진 (파일 저장소의 avatars/<해시>/<크기>.png, 해시는 원본 이미지의 SHA-256)
+-- 같은 이미지를 올린 사용자끼리는 파일을 공유하므로 참조하는 사용자가 없을 때만 삭제
+ALTER TABLE users ADD COLUMN IF NOT EXISTS avatar_hash VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS idx_users_avatar_hash ON users (avatar_hash) WHERE avatar_hash IS NOT NULL;
@@ -0,0 +1,7 @@
+-- 사용자 검색용 전문 검색 인덱스 (이름, 이메일, 역할)
+-- 언어별 형태소 분석 없이 공백/기호 단위로 나누는 simple 설정 사용
+-- 식이 services/search.rs의 SEARCH_DOCUMENT와 같아야 인덱스를 사용함
+-- 부분 일치/유사도 검색은 0004의 트라이그램 인덱스(idx_users_name_trgm, idx_users_email_trgm) 사용
+CREATE INDEX IF NOT EXISTS idx_users_search_document ON users
+    USING GIN (to_tsvector('simple', name || ' ' || email || ' ' || COALESCE(role, '')))
+    WHERE deleted_at IS NULL;
@@ -0,0 +1,35 @@
+-- 공지사항 (본문은 Markdown, 작성자가 삭제되어도 공지는 유지)
+-- audience_roles가 비어 있으면 전체 공지, 아니면 해당 역할(기본 역할 또는 추가 역할) 사용자에게만 표시
+CREATE TABLE IF NOT EXISTS announcements (
+    id SERIAL PRIMARY KEY,
+    title VARCHAR(200) NOT NULL,
+    body TEXT NOT NULL,
+    author_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    pinned BOOLEAN NOT NULL DEFAULT false,
+    publish_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ,
+    audience_roles TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CONSTRAINT announcements_schedule_check CHECK (expires_at IS NULL OR expires_at > publish_at)
+);
+
+-- 피드 정렬 (고정 공지 먼저, 최근 게시 순)
+CREATE INDEX IF NOT EXISTS idx_announcements_feed ON announcements (pinned DESC, publish_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_announcements_audience_roles ON announcements USING GIN (audience_roles);
+
+DROP TRIGGER IF EXISTS update_announcements_updated_at ON announcements;
+CREATE TRIGGER update_announcements_updated_at
+    BEFORE UPDATE ON announcements
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 사용자별 읽음 확인 (처음 읽은 시각만 기록)
+CREATE TABLE IF NOT EXISTS announcement_reads (
+    announcement_id INTEGER NOT NULL REFERENCES announcements(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    read_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (announcement_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_announcement_reads_user_id ON announcement_reads (user_id);
@@ -0,0 +1,5 @@
+DELETE FROM permissions WHERE code = 'leave.manage';
+DROP TABLE IF EXISTS leave_approvals;
+DROP TABLE IF EXISTS leave_requests;
+DROP TABLE IF EXISTS leave_balances;
+DROP TABLE IF EXISTS leave_types;
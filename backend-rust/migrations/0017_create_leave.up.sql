@@ -0,0 +1,99 @@
+-- 휴가 종류와 적립 규칙
+-- accrual: yearly(매년 1월 1일에 annual_days 전체 부여), monthly(매월 annual_days의 1/12씩 적립), none(잔여 일수 관리 안 함)
+-- 입사 연도에는 입사 월부터 비례 부여 (user_profiles.hire_date 기준)
+CREATE TABLE IF NOT EXISTS leave_types (
+    id SERIAL PRIMARY KEY,
+    code VARCHAR(50) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    annual_days NUMERIC(5, 2) NOT NULL DEFAULT 0,
+    accrual VARCHAR(20) NOT NULL DEFAULT 'yearly',
+    max_carryover_days NUMERIC(5, 2) NOT NULL DEFAULT 0, -- 다음 해로 이월할 수 있는 최대 일수
+    paid BOOLEAN NOT NULL DEFAULT TRUE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CONSTRAINT leave_types_code_key UNIQUE (code),
+    CHECK (accrual IN ('yearly', 'monthly', 'none')),
+    CHECK (annual_days >= 0 AND max_carryover_days >= 0)
+);
+
+DROP TRIGGER IF EXISTS update_leave_types_updated_at ON leave_types;
+CREATE TRIGGER update_leave_types_updated_at
+    BEFORE UPDATE ON leave_types
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+INSERT INTO leave_types (code, name, annual_days, accrual, max_carryover_days, paid) VALUES
+    ('annual', '연차', 15, 'yearly', 5, true),
+    ('sick', '병가', 10, 'yearly', 0, true),
+    ('unpaid', '무급 휴가', 0, 'none', 0, false)
+ON CONFLICT (code) DO NOTHING;
+
+-- 연도별 잔여 일수 조정 (전년도 이월, 인사 담당자의 수동 조정)
+-- 부여 일수는 적립 규칙으로, 사용 일수는 휴가 신청으로 계산하므로 저장하지 않음
+CREATE TABLE IF NOT EXISTS leave_balances (
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    leave_type_id INTEGER NOT NULL REFERENCES leave_types(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    carried_over_days NUMERIC(5, 2) NOT NULL DEFAULT 0,
+    adjustment_days NUMERIC(5, 2) NOT NULL DEFAULT 0,
+    note VARCHAR(200),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    PRIMARY KEY (user_id, leave_type_id, year)
+);
+
+-- 휴가 신청 (직속 상사 승인 -> 인사 승인)
+-- status: pending_manager, pending_hr, approved, rejected, cancelled
+-- days는 주말을 제외한 사용 일수 (반차는 0.5)
+CREATE TABLE IF NOT EXISTS leave_requests (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    leave_type_id INTEGER NOT NULL REFERENCES leave_types(id) ON DELETE RESTRICT,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    half_day VARCHAR(2), -- am/pm (하루짜리 신청만)
+    days NUMERIC(5, 2) NOT NULL,
+    reason VARCHAR(500),
+    status VARCHAR(20) NOT NULL,
+    manager_id INTEGER REFERENCES users(id) ON DELETE SET NULL, -- 1단계 승인자 (신청 시점의 직속 상사)
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CHECK (end_date >= start_date),
+    CHECK (half_day IS NULL OR (half_day IN ('am', 'pm') AND start_date = end_date)),
+    CHECK (status IN ('pending_manager', 'pending_hr', 'approved', 'rejected', 'cancelled'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_leave_requests_user_dates ON leave_requests (user_id, start_date, end_date);
+CREATE INDEX IF NOT EXISTS idx_leave_requests_dates ON leave_requests (start_date, end_date)
+    WHERE status IN ('pending_manager', 'pending_hr', 'approved');
+CREATE INDEX IF NOT EXISTS idx_leave_requests_manager_id ON leave_requests (manager_id)
+    WHERE status = 'pending_manager';
+
+DROP TRIGGER IF EXISTS update_leave_requests_updated_at ON leave_requests;
+CREATE TRIGGER update_leave_requests_updated_at
+    BEFORE UPDATE ON leave_requests
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 단계별 승인/반려 기록
+CREATE TABLE IF NOT EXISTS leave_approvals (
+    id SERIAL PRIMARY KEY,
+    leave_request_id INTEGER NOT NULL REFERENCES leave_requests(id) ON DELETE CASCADE,
+    step VARCHAR(20) NOT NULL,
+    actor_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    decision VARCHAR(20) NOT NULL,
+    comment VARCHAR(500),
+    decided_at TIMESTAMPTZ DEFAULT NOW(),
+    CHECK (step IN ('manager', 'hr')),
+    CHECK (decision IN ('approved', 'rejected'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_leave_approvals_leave_request_id ON leave_approvals (leave_request_id);
+
+INSERT INTO permissions (code, description) VALUES
+    ('leave.manage', '휴가 종류/잔여 일수 관리 및 인사 승인')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id FROM roles r JOIN permissions p ON p.code = 'leave.manage' WHERE r.name = 'admin'
+ON CONFLICT DO NOTHING;
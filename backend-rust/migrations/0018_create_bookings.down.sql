@@ -0,0 +1,3 @@
+DELETE FROM permissions WHERE code = 'resources.manage';
+DROP TABLE IF EXISTS bookings;
+DROP TABLE IF EXISTS resources;
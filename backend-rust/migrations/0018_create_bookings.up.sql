@@ -0,0 +1,68 @@
+-- 겹치는 예약을 막는 배타 제약에서 정수 = 비교를 GiST 인덱스로 처리
+CREATE EXTENSION IF NOT EXISTS btree_gist;
+
+-- 예약 가능한 자원 (회의실, 장비, 차량)
+-- requires_check_in이면 시작 후 유예 시간 안에 체크인하지 않은 예약을 자동 해제
+CREATE TABLE IF NOT EXISTS resources (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    kind VARCHAR(20) NOT NULL,
+    capacity INTEGER, -- 수용 인원 (회의실, 차량)
+    location VARCHAR(200),
+    description VARCHAR(500),
+    requires_check_in BOOLEAN NOT NULL DEFAULT TRUE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CONSTRAINT resources_name_key UNIQUE (name),
+    CHECK (kind IN ('room', 'equipment', 'vehicle')),
+    CHECK (capacity IS NULL OR capacity > 0)
+);
+
+DROP TRIGGER IF EXISTS update_resources_updated_at ON resources;
+CREATE TRIGGER update_resources_updated_at
+    BEFORE UPDATE ON resources
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 자원 예약 (반복 예약은 회차마다 한 행, 같은 series_id로 묶음)
+-- status: confirmed, checked_in, released(체크인하지 않아 자동 해제), cancelled
+-- 확정/체크인 상태의 예약끼리는 같은 자원에서 시간이 겹칠 수 없음 (bookings_no_overlap)
+CREATE TABLE IF NOT EXISTS bookings (
+    id SERIAL PRIMARY KEY,
+    resource_id INTEGER NOT NULL REFERENCES resources(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title VARCHAR(200) NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL,
+    series_id UUID,
+    recurrence VARCHAR(200), -- 반복 규칙 (RRULE 일부)
+    status VARCHAR(20) NOT NULL DEFAULT 'confirmed',
+    checked_in_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CHECK (ends_at > starts_at),
+    CHECK (status IN ('confirmed', 'checked_in', 'released', 'cancelled')),
+    CONSTRAINT bookings_no_overlap EXCLUDE USING gist (
+        resource_id WITH =,
+        tstzrange(starts_at, ends_at) WITH &&
+    ) WHERE (status IN ('confirmed', 'checked_in'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_bookings_user_starts_at ON bookings (user_id, starts_at);
+CREATE INDEX IF NOT EXISTS idx_bookings_series_id ON bookings (series_id) WHERE series_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_bookings_confirmed_starts_at ON bookings (starts_at) WHERE status = 'confirmed';
+
+DROP TRIGGER IF EXISTS update_bookings_updated_at ON bookings;
+CREATE TRIGGER update_bookings_updated_at
+    BEFORE UPDATE ON bookings
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+INSERT INTO permissions (code, description) VALUES
+    ('resources.manage', '예약 자원 관리 및 다른 사용자의 예약 변경/취소')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id FROM roles r JOIN permissions p ON p.code = 'resources.manage' WHERE r.name = 'admin'
+ON CONFLICT DO NOTHING;
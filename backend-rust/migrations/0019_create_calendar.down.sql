@@ -0,0 +1,3 @@
+DELETE FROM permissions WHERE code = 'calendar.manage';
+DROP TABLE IF EXISTS calendar_feeds;
+DROP TABLE IF EXISTS calendar_events;
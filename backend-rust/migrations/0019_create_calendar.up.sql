@@ -0,0 +1,45 @@
+-- 회사 일정과 공휴일 (구독 피드와 일정 목록에 표시)
+-- 하루 종일 일정은 UTC 자정으로 저장하고 ends_at은 마지막 날의 다음 날 (iCalendar DATE와 같은 방식)
+-- source_uid는 .ics 파일에서 가져온 일정의 UID (같은 파일을 다시 가져오면 갱신)
+CREATE TABLE IF NOT EXISTS calendar_events (
+    id SERIAL PRIMARY KEY,
+    kind VARCHAR(20) NOT NULL DEFAULT 'event',
+    title VARCHAR(200) NOT NULL,
+    description VARCHAR(2000),
+    location VARCHAR(200),
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL,
+    all_day BOOLEAN NOT NULL DEFAULT FALSE,
+    source_uid VARCHAR(255),
+    created_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CONSTRAINT calendar_events_source_uid_key UNIQUE (source_uid),
+    CHECK (kind IN ('event', 'holiday')),
+    CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_calendar_events_starts_at ON calendar_events (starts_at);
+
+DROP TRIGGER IF EXISTS update_calendar_events_updated_at ON calendar_events;
+CREATE TRIGGER update_calendar_events_updated_at
+    BEFORE UPDATE ON calendar_events
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 사용자별 달력 구독 토큰 (원본은 발급할 때 한 번만 보여주고 SHA-256 해시만 저장)
+CREATE TABLE IF NOT EXISTS calendar_feeds (
+    user_id INTEGER PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    token_hash VARCHAR(64) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_accessed_at TIMESTAMPTZ,
+    CONSTRAINT calendar_feeds_token_hash_key UNIQUE (token_hash)
+);
+
+INSERT INTO permissions (code, description) VALUES
+    ('calendar.manage', '회사 일정 등록/수정/삭제 및 .ics 공휴일 가져오기')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id FROM roles r JOIN permissions p ON p.code = 'calendar.manage' WHERE r.name = 'admin'
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,6 @@
+DELETE FROM permissions WHERE code = 'documents.manage';
+DROP TABLE IF EXISTS document_quotas;
+DROP TABLE IF EXISTS document_versions;
+DROP TABLE IF EXISTS documents;
+DROP TABLE IF EXISTS document_folder_acl;
+DROP TABLE IF EXISTS document_folders;
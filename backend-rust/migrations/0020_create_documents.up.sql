@@ -0,0 +1,92 @@
+-- 문서함 폴더 (상위 폴더의 접근 권한은 하위 폴더와 문서에 그대로 적용)
+-- 폴더를 만든 사용자(owner_id)는 하위 트리 전체의 관리 권한을 가짐
+CREATE TABLE IF NOT EXISTS document_folders (
+    id SERIAL PRIMARY KEY,
+    parent_id INTEGER REFERENCES document_folders(id) ON DELETE RESTRICT,
+    name VARCHAR(200) NOT NULL,
+    owner_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CHECK (parent_id IS NULL OR parent_id <> id)
+);
+
+-- 같은 상위 폴더 아래 이름 중복 방지 (최상위 폴더끼리도 중복 불가)
+CREATE UNIQUE INDEX IF NOT EXISTS document_folders_parent_name_key
+    ON document_folders (COALESCE(parent_id, 0), name);
+
+DROP TRIGGER IF EXISTS update_document_folders_updated_at ON document_folders;
+CREATE TRIGGER update_document_folders_updated_at
+    BEFORE UPDATE ON document_folders
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 폴더 접근 권한 (사용자 또는 역할 중 하나에 read < write < manage 부여)
+CREATE TABLE IF NOT EXISTS document_folder_acl (
+    id SERIAL PRIMARY KEY,
+    folder_id INTEGER NOT NULL REFERENCES document_folders(id) ON DELETE CASCADE,
+    user_id INTEGER REFERENCES users(id) ON DELETE CASCADE,
+    role_id INTEGER REFERENCES roles(id) ON DELETE CASCADE,
+    access VARCHAR(20) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((user_id IS NULL) <> (role_id IS NULL)),
+    CHECK (access IN ('read', 'write', 'manage'))
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS document_folder_acl_user_key
+    ON document_folder_acl (folder_id, user_id) WHERE user_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS document_folder_acl_role_key
+    ON document_folder_acl (folder_id, role_id) WHERE role_id IS NOT NULL;
+
+-- 문서 (내용은 버전별로 document_versions에 저장, current_version이 최신 버전)
+-- 용량 할당량은 owner_id(처음 올린 사용자) 기준으로 계산
+CREATE TABLE IF NOT EXISTS documents (
+    id SERIAL PRIMARY KEY,
+    folder_id INTEGER NOT NULL REFERENCES document_folders(id) ON DELETE RESTRICT,
+    name VARCHAR(255) NOT NULL,
+    owner_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    current_version INTEGER NOT NULL DEFAULT 1,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    CONSTRAINT documents_folder_name_key UNIQUE (folder_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_documents_owner_id ON documents (owner_id);
+
+DROP TRIGGER IF EXISTS update_documents_updated_at ON documents;
+CREATE TRIGGER update_documents_updated_at
+    BEFORE UPDATE ON documents
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 문서 버전 (파일은 저장소의 documents/<SHA-256> 키에 내용 기반으로 저장)
+CREATE TABLE IF NOT EXISTS document_versions (
+    id SERIAL PRIMARY KEY,
+    document_id INTEGER NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    content_type VARCHAR(255) NOT NULL,
+    checksum_sha256 VARCHAR(64) NOT NULL,
+    uploaded_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    comment VARCHAR(500),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT document_versions_document_version_key UNIQUE (document_id, version),
+    CHECK (size_bytes >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_document_versions_checksum ON document_versions (checksum_sha256);
+
+-- 사용자별 문서 용량 할당량 (없으면 DOCUMENT_QUOTA_BYTES 기본값)
+CREATE TABLE IF NOT EXISTS document_quotas (
+    user_id INTEGER PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    quota_bytes BIGINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (quota_bytes >= 0)
+);
+
+INSERT INTO permissions (code, description) VALUES
+    ('documents.manage', '모든 문서함 폴더 관리 및 사용자별 문서 용량 할당량 설정')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id FROM roles r JOIN permissions p ON p.code = 'documents.manage' WHERE r.name = 'admin'
+ON CONFLICT DO NOTHING;
@@ -1,4 +1,13 @@
-use sqlx::{postgres::PgPoolOptions, PgPool};
+use sqlx::{
+    migrate::{Migrate, Migration, Migrator},
+    postgres::PgPoolOptions,
+    PgConnection, PgPool,
+};
+use std::collections::HashMap;
+
+// 버전 관리되는 마이그레이션 (migrations/ 디렉토리가 컴파일 시 바이너리에 포함됨)
+// 적용 기록과 체크섬은 _sqlx_migrations 테이블에 저장된다
+static MIGRATOR: Migrator = sqlx::migrate!();
 
 // 데이터베이스 연결 풀 생성
 pub async fn create_pool(database_url: &str) -> anyhow::Result<PgPool> {
@@ -6,59 +15,176 @@ pub async fn create_pool(database_url: &str) -> anyhow::Result<PgPool> {
         .max_connections(20)
         .connect(database_url)
         .await?;
-    
+
     tracing::info!("데이터베이스 연결 풀 생성 완료");
     Ok(pool)
 }
 
-// 데이터베이스 마이그레이션 실행
-pub async fn run_migrations(pool: &PgPool) -> anyhow::Result<()> {
-    // 사용자 테이블 생성 (다른 백엔드와 동일한 스키마)
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS users (
-            id SERIAL PRIMARY KEY,
-            email VARCHAR(255) UNIQUE NOT NULL,
-            password VARCHAR(255) NOT NULL,
-            name VARCHAR(255) NOT NULL,
-            role VARCHAR(50) DEFAULT 'user',
-            is_active BOOLEAN DEFAULT true,
-            created_at TIMESTAMPTZ DEFAULT NOW(),
-            updated_at TIMESTAMPTZ DEFAULT NOW()
-        )
-        "#,
-    )
-    .execute(pool)
-    .await?;
-
-    // updated_at 자동 업데이트 트리거 생성
-    sqlx::query(
-        r#"
-        CREATE OR REPLACE FUNCTION update_updated_at_column()
-        RETURNS TRIGGER AS $$
-        BEGIN
-            NEW.updated_at = NOW();
-            RETURN NEW;
-        END;
-        $$ language 'plpgsql';
-        "#,
-    )
-    .execute(pool)
-    .await?;
-
-    // 트리거 적용
-    sqlx::query(
-        r#"
-        DROP TRIGGER IF EXISTS update_users_updated_at ON users;
-        CREATE TRIGGER update_users_updated_at
-            BEFORE UPDATE ON users
-            FOR EACH ROW
-            EXECUTE FUNCTION update_updated_at_column();
-        "#,
-    )
-    .execute(pool)
-    .await?;
-
-    tracing::info!("데이터베이스 마이그레이션 완료");
+// 마이그레이션 명령 (`sample-intranet-rust migrate <up|down> [--target <버전>] [--dry-run]`)
+#[derive(Debug)]
+pub enum MigrateCommand {
+    Up { dry_run: bool },
+    Down { target: Option<i64>, dry_run: bool },
+}
+
+impl MigrateCommand {
+    pub fn parse(args: &[String]) -> anyhow::Result<Self> {
+        let dry_run = args.iter().any(|arg| arg == "--dry-run");
+        let target = match args.iter().position(|arg| arg == "--target") {
+            Some(index) => Some(
+                args.get(index + 1)
+                    .ok_or_else(|| anyhow::anyhow!("--target 뒤에 버전을 입력해주세요"))?
+                    .parse::<i64>()?,
+            ),
+            None => None,
+        };
+
+        match args.first().map(String::as_str) {
+            Some("up") | None => Ok(MigrateCommand::Up { dry_run }),
+            Some("down") => Ok(MigrateCommand::Down { target, dry_run }),
+            Some(other) => anyhow::bail!("알 수 없는 마이그레이션 명령: {}", other),
+        }
+    }
+
+    pub async fn execute(self, pool: &PgPool) -> anyhow::Result<()> {
+        match self {
+            MigrateCommand::Up { dry_run } => run_migrations(pool, dry_run).await,
+            MigrateCommand::Down { target, dry_run } => revert_migrations(pool, target, dry_run).await,
+        }
+    }
+}
+
+// 대기 중인 마이그레이션 적용
+// 여러 인스턴스가 동시에 시작해도 advisory lock으로 한 인스턴스만 적용한다
+pub async fn run_migrations(pool: &PgPool, dry_run: bool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+    conn.lock().await?;
+    let result = apply_pending(&mut conn, dry_run).await;
+    conn.unlock().await?;
+    result
+}
+
+// 마이그레이션 되돌리기 (target보다 큰 버전을 역순으로 down 스크립트 실행)
+// target을 지정하지 않으면 마지막으로 적용된 마이그레이션 하나만 되돌린다
+pub async fn revert_migrations(pool: &PgPool, target: Option<i64>, dry_run: bool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+    conn.lock().await?;
+    let result = revert_applied(&mut conn, target, dry_run).await;
+    conn.unlock().await?;
+    result
+}
+
+async fn apply_pending(conn: &mut PgConnection, dry_run: bool) -> anyhow::Result<()> {
+    let applied = applied_migrations(conn).await?;
+    let pending: Vec<&Migration> = MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .filter(|migration| !applied.contains_key(&migration.version))
+        .collect();
+
+    if pending.is_empty() {
+        tracing::info!("적용할 마이그레이션이 없습니다");
+        return Ok(());
+    }
+
+    if !dry_run {
+        conn.ensure_migrations_table().await?;
+    }
+
+    for migration in &pending {
+        if dry_run {
+            tracing::info!("[dry-run] 마이그레이션 적용 대상: {} {}", migration.version, migration.description);
+            continue;
+        }
+
+        let elapsed = conn.apply(migration).await?;
+        tracing::info!(
+            "마이그레이션 적용: {} {} ({}ms)",
+            migration.version,
+            migration.description,
+            elapsed.as_millis()
+        );
+    }
+
+    Ok(())
+}
+
+async fn revert_applied(conn: &mut PgConnection, target: Option<i64>, dry_run: bool) -> anyhow::Result<()> {
+    let applied = applied_migrations(conn).await?;
+    let latest = match applied.keys().max() {
+        Some(latest) => *latest,
+        None => {
+            tracing::info!("되돌릴 마이그레이션이 없습니다");
+            return Ok(());
+        }
+    };
+    let target = target.unwrap_or_else(|| {
+        applied
+            .keys()
+            .filter(|version| **version < latest)
+            .max()
+            .copied()
+            .unwrap_or(0)
+    });
+
+    for migration in MIGRATOR
+        .iter()
+        .rev()
+        .filter(|migration| migration.migration_type.is_down_migration())
+        .filter(|migration| applied.contains_key(&migration.version) && migration.version > target)
+    {
+        if dry_run {
+            tracing::info!("[dry-run] 마이그레이션 되돌리기 대상: {} {}", migration.version, migration.description);
+            continue;
+        }
+
+        let elapsed = conn.revert(migration).await?;
+        tracing::info!(
+            "마이그레이션 되돌리기: {} {} ({}ms)",
+            migration.version,
+            migration.description,
+            elapsed.as_millis()
+        );
+    }
+
     Ok(())
-} 
\ No newline at end of file
+}
+
+// 적용된 마이그레이션 목록 조회 (버전 → 체크섬)
+// 이미 적용된 마이그레이션 파일이 수정되었으면 체크섬 불일치로 실패한다
+async fn applied_migrations(conn: &mut PgConnection) -> anyhow::Result<HashMap<i64, Vec<u8>>> {
+    let table_exists: bool = sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+        .fetch_one(&mut *conn)
+        .await?;
+    if !table_exists {
+        return Ok(HashMap::new());
+    }
+
+    if let Some(version) = conn.dirty_version().await? {
+        anyhow::bail!("마이그레이션 {}이(가) 실패한 상태로 남아 있습니다. 수동 확인이 필요합니다", version);
+    }
+
+    let applied: HashMap<i64, Vec<u8>> = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| (migration.version, migration.checksum.into_owned()))
+        .collect();
+
+    for migration in MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+    {
+        if let Some(checksum) = applied.get(&migration.version) {
+            if checksum.as_slice() != &*migration.checksum {
+                anyhow::bail!(
+                    "이미 적용된 마이그레이션 {} {}의 내용이 변경되었습니다 (체크섬 불일치)",
+                    migration.version,
+                    migration.description
+                );
+            }
+        }
+    }
+
+    Ok(applied)
+}
@@ -0,0 +1,357 @@
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::collections::BTreeMap;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+use crate::{
+    ical::ICalError,
+    models::{ErrorResponse, FieldError},
+    services::{AvatarError, BookingError, DocumentError, LeaveError},
+};
+
+pub type AppResult<T> = Result<T, AppError>;
+
+// 애플리케이션 에러 (핸들러에서 `?`로 전파하면 에러 응답으로 변환)
+// error 코드는 다른 백엔드와 같은 `{error, message}` 응답의 error 값
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+    #[error("{message}")]
+    BadRequest { code: &'static str, message: String },
+
+    // 필드별 오류 (validator 검사 실패, JSON 역직렬화 실패)
+    #[error("유효성 검사 실패: {0:?}")]
+    Validation(BTreeMap<String, Vec<FieldError>>),
+
+    #[error("{message}")]
+    Unauthorized { code: &'static str, message: String },
+
+    #[error("{message}")]
+    Forbidden { code: &'static str, message: String },
+
+    #[error("{message}")]
+    NotFound { code: &'static str, message: String },
+
+    #[error("{message}")]
+    Conflict { code: &'static str, message: String },
+
+    #[error("요청 본문이 너무 큽니다")]
+    PayloadTooLarge,
+
+    #[error("Content-Type이 application/json이어야 합니다")]
+    UnsupportedMediaType,
+
+    // 지원하지 않는 파일/요청 형식 (415, 업로드)
+    #[error("{message}")]
+    UnsupportedFile { code: &'static str, message: String },
+
+    // 계정 잠금 (423, Retry-After 포함)
+    #[error("{message}")]
+    Locked { code: &'static str, message: String, retry_after: i64 },
+
+    // 요청 횟수 제한 (429, Retry-After 포함)
+    #[error("{message}")]
+    TooManyRequests { code: &'static str, message: String, retry_after: i64 },
+
+    #[error("데이터베이스 오류: {0}")]
+    Database(#[source] sqlx::Error),
+
+    #[error(transparent)]
+    Internal(anyhow::Error),
+}
+
+impl AppError {
+    pub fn bad_request(code: &'static str, message: impl Into<String>) -> Self {
+        Self::BadRequest { code, message: message.into() }
+    }
+
+    pub fn unauthorized(code: &'static str, message: impl Into<String>) -> Self {
+        Self::Unauthorized { code, message: message.into() }
+    }
+
+    pub fn forbidden(code: &'static str, message: impl Into<String>) -> Self {
+        Self::Forbidden { code, message: message.into() }
+    }
+
+    pub fn not_found(code: &'static str, message: impl Into<String>) -> Self {
+        Self::NotFound { code, message: message.into() }
+    }
+
+    pub fn conflict(code: &'static str, message: impl Into<String>) -> Self {
+        Self::Conflict { code, message: message.into() }
+    }
+
+    pub fn unsupported_file(code: &'static str, message: impl Into<String>) -> Self {
+        Self::UnsupportedFile { code, message: message.into() }
+    }
+
+    // 한 필드의 유효성 검사 오류
+    pub fn invalid_field(field: &str, code: &str, message: impl Into<String>) -> Self {
+        let error = FieldError {
+            code: code.to_string(),
+            message: message.into(),
+        };
+        Self::Validation(BTreeMap::from([(field.to_string(), vec![error])]))
+    }
+
+    // 자주 쓰는 404
+    pub fn user_not_found() -> Self {
+        Self::not_found("user_not_found", "사용자를 찾을 수 없습니다")
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::BadRequest { .. } | Self::Validation(_) => StatusCode::BAD_REQUEST,
+            Self::Unauthorized { .. } => StatusCode::UNAUTHORIZED,
+            Self::Forbidden { .. } => StatusCode::FORBIDDEN,
+            Self::NotFound { .. } => StatusCode::NOT_FOUND,
+            Self::Conflict { .. } => StatusCode::CONFLICT,
+            Self::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::UnsupportedMediaType | Self::UnsupportedFile { .. } => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Locked { .. } => StatusCode::LOCKED,
+            Self::TooManyRequests { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::Database(_) | Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::BadRequest { code, .. }
+            | Self::Unauthorized { code, .. }
+            | Self::Forbidden { code, .. }
+            | Self::NotFound { code, .. }
+            | Self::Conflict { code, .. }
+            | Self::UnsupportedFile { code, .. }
+            | Self::Locked { code, .. }
+            | Self::TooManyRequests { code, .. } => code,
+            Self::Validation(_) => "validation_error",
+            Self::PayloadTooLarge => "payload_too_large",
+            Self::UnsupportedMediaType => "unsupported_media_type",
+            Self::Database(_) => "database_error",
+            Self::Internal(_) => "internal_error",
+        }
+    }
+
+    // 응답에 노출하는 메시지 (서버 오류의 상세 내용은 로그에만 남김)
+    pub fn message(&self) -> String {
+        match self {
+            Self::Validation(fields) => fields
+                .values()
+                .flatten()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            Self::Locked { message, retry_after, .. }
+            | Self::TooManyRequests { message, retry_after, .. } => {
+                format!("{} ({}초 후 재시도 가능)", message, retry_after)
+            }
+            Self::Database(_) | Self::Internal(_) => "서버 오류가 발생했습니다".to_string(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        match &self {
+            Self::Database(err) => tracing::error!("데이터베이스 오류: {}", err),
+            Self::Internal(err) => tracing::error!("서버 오류: {:#}", err),
+            _ => {}
+        }
+
+        let mut body = ErrorResponse::new(self.code(), &self.message());
+        if let Self::Validation(fields) = &self {
+            body = body.with_details(fields.clone());
+        }
+
+        // problem+json 변환(error_format_middleware)에서 다시 쓸 수 있도록 응답 확장에도 보관
+        let mut response = match &self {
+            Self::Locked { retry_after, .. } | Self::TooManyRequests { retry_after, .. } => (
+                self.status(),
+                [(header::RETRY_AFTER, retry_after.to_string())],
+                Json(body.clone()),
+            )
+                .into_response(),
+            _ => (self.status(), Json(body.clone())).into_response(),
+        };
+        response.extensions_mut().insert(body);
+        response
+    }
+}
+
+// 유니크/배타 제약 위반은 409로 변환 (제약 이름으로 알려진 경우 전용 코드 사용)
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        if let sqlx::Error::Database(db_err) = &err {
+            // 배타 제약 위반 (exclusion_violation)
+            if db_err.code().as_deref() == Some("23P01") {
+                return match db_err.constraint() {
+                    Some("bookings_no_overlap") => Self::conflict("booking_conflict", "기존 예약과 시간이 겹칩니다"),
+                    _ => Self::conflict("duplicate_entry", "이미 존재하는 데이터입니다"),
+                };
+            }
+            if db_err.is_unique_violation() {
+                return match db_err.constraint() {
+                    Some("users_email_key") => Self::conflict("email_exists", "이미 존재하는 이메일입니다"),
+                    Some("roles_name_key") => Self::conflict("role_exists", "이미 존재하는 역할입니다"),
+                    Some("resources_name_key") => Self::conflict("resource_exists", "이미 존재하는 자원 이름입니다"),
+                    Some("leave_types_code_key") => Self::conflict("leave_type_exists", "이미 존재하는 휴가 종류 코드입니다"),
+                    Some("departments_parent_name_key") => {
+                        Self::conflict("department_exists", "같은 상위 부서에 이미 존재하는 부서 이름입니다")
+                    }
+                    Some("document_folders_parent_name_key") => {
+                        Self::conflict("document_folder_exists", "같은 상위 폴더에 이미 존재하는 폴더 이름입니다")
+                    }
+                    Some("documents_folder_name_key") => {
+                        Self::conflict("document_exists", "같은 폴더에 이미 존재하는 문서 이름입니다 (새 버전으로 올려야 합니다)")
+                    }
+                    _ => Self::conflict("duplicate_entry", "이미 존재하는 데이터입니다"),
+                };
+            }
+        }
+
+        Self::Database(err)
+    }
+}
+
+// 서비스의 anyhow 에러도 원인이 데이터베이스 에러면 같은 규칙으로 변환
+impl From<anyhow::Error> for AppError {
+    fn from(err: anyhow::Error) -> Self {
+        match err.downcast::<sqlx::Error>() {
+            Ok(err) => Self::from(err),
+            Err(err) => Self::Internal(err),
+        }
+    }
+}
+
+impl From<ValidationErrors> for AppError {
+    fn from(errors: ValidationErrors) -> Self {
+        Self::Validation(field_errors(&errors))
+    }
+}
+
+impl From<bcrypt::BcryptError> for AppError {
+    fn from(err: bcrypt::BcryptError) -> Self {
+        Self::Internal(err.into())
+    }
+}
+
+// 필드별 오류 목록 (중첩 구조체는 `parent.child` 형태의 필드 이름 사용)
+fn field_errors(errors: &ValidationErrors) -> BTreeMap<String, Vec<FieldError>> {
+    let mut fields = BTreeMap::new();
+    collect_field_errors(errors, "", &mut fields);
+    fields
+}
+
+fn collect_field_errors(
+    errors: &ValidationErrors,
+    prefix: &str,
+    fields: &mut BTreeMap<String, Vec<FieldError>>,
+) {
+    for (field, kind) in errors.errors() {
+        let name = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                let entries = fields.entry(name).or_default();
+                entries.extend(errors.iter().map(|error| FieldError {
+                    code: error.code.to_string(),
+                    message: error
+                        .message
+                        .as_ref()
+                        .map(|message| message.to_string())
+                        .unwrap_or_else(|| "유효성 검사 실패".to_string()),
+                }));
+            }
+            ValidationErrorsKind::Struct(errors) => collect_field_errors(errors, &name, fields),
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    collect_field_errors(errors, &format!("{}[{}]", name, index), fields);
+                }
+            }
+        }
+    }
+}
+
+// 프로필 사진 처리 오류 (형식은 415, 읽을 수 없거나 너무 큰 이미지는 file 필드 오류)
+impl From<AvatarError> for AppError {
+    fn from(err: AvatarError) -> Self {
+        match err {
+            AvatarError::UnsupportedFormat => Self::unsupported_file("unsupported_image_type", err.to_string()),
+            AvatarError::InvalidImage => Self::invalid_field("file", "invalid_image", err.to_string()),
+            AvatarError::TooLarge => Self::invalid_field("file", "image_too_large", err.to_string()),
+            AvatarError::Storage(err) => Self::from(err),
+        }
+    }
+}
+
+// 휴가 신청 처리 오류 (상태 전이/기간 중복/잔여 일수는 409, 승인 권한은 403)
+impl From<LeaveError> for AppError {
+    fn from(err: LeaveError) -> Self {
+        match err {
+            LeaveError::NotFound => Self::not_found("leave_request_not_found", err.to_string()),
+            LeaveError::InvalidLeaveType => Self::invalid_field("leave_type_id", "invalid_leave_type", err.to_string()),
+            LeaveError::InvalidRange(_) => Self::invalid_field("end_date", "invalid_range", err.to_string()),
+            LeaveError::Overlap(_) => Self::conflict("leave_overlap", err.to_string()),
+            LeaveError::InsufficientBalance { .. } => Self::conflict("insufficient_balance", err.to_string()),
+            LeaveError::InvalidTransition(_) => Self::conflict("invalid_transition", err.to_string()),
+            LeaveError::AlreadyStarted => Self::conflict("leave_already_started", err.to_string()),
+            LeaveError::NotApprover => Self::forbidden("not_approver", err.to_string()),
+            LeaveError::NotRequester => Self::forbidden("not_requester", err.to_string()),
+            LeaveError::Database(err) => Self::from(err),
+        }
+    }
+}
+
+// 예약 처리 오류 (시간 겹침/상태 전이/체크인 가능 시간은 409, 예약자 확인은 403)
+impl From<BookingError> for AppError {
+    fn from(err: BookingError) -> Self {
+        match err {
+            BookingError::NotFound => Self::not_found("booking_not_found", err.to_string()),
+            BookingError::InvalidResource => Self::invalid_field("resource_id", "invalid_resource", err.to_string()),
+            BookingError::InvalidRange(_) => Self::invalid_field("ends_at", "invalid_range", err.to_string()),
+            BookingError::InvalidRecurrence(_) => Self::invalid_field("recurrence", "invalid_recurrence", err.to_string()),
+            BookingError::Conflict(_) => Self::conflict("booking_conflict", err.to_string()),
+            BookingError::InvalidTransition(_) => Self::conflict("invalid_transition", err.to_string()),
+            BookingError::AlreadyStarted => Self::conflict("booking_already_started", err.to_string()),
+            BookingError::CheckInNotOpen(_) => Self::conflict("check_in_not_open", err.to_string()),
+            BookingError::CheckInClosed => Self::conflict("check_in_closed", err.to_string()),
+            BookingError::NotOwner => Self::forbidden("not_booking_owner", err.to_string()),
+            BookingError::Database(err) => Self::from(err),
+        }
+    }
+}
+
+// iCalendar 파일을 읽을 수 없는 오류 (가져오기 요청의 file 필드)
+impl From<ICalError> for AppError {
+    fn from(err: ICalError) -> Self {
+        Self::invalid_field("file", "invalid_ical", err.to_string())
+    }
+}
+
+// 문서함 처리 오류 (권한 부족은 403, 비어 있지 않은 폴더와 할당량 초과는 409)
+impl From<DocumentError> for AppError {
+    fn from(err: DocumentError) -> Self {
+        match err {
+            DocumentError::FolderNotFound => Self::not_found("document_folder_not_found", err.to_string()),
+            DocumentError::DocumentNotFound => Self::not_found("document_not_found", err.to_string()),
+            DocumentError::VersionNotFound => Self::not_found("document_version_not_found", err.to_string()),
+            DocumentError::AccessDenied(_) => Self::forbidden("document_access_denied", err.to_string()),
+            DocumentError::InvalidParent => Self::invalid_field("parent_id", "invalid_parent", err.to_string()),
+            DocumentError::RootNotAllowed => Self::forbidden("root_folder_not_allowed", err.to_string()),
+            DocumentError::InvalidFolder => Self::invalid_field("folder_id", "invalid_folder", err.to_string()),
+            DocumentError::FolderNotEmpty => Self::conflict("folder_not_empty", err.to_string()),
+            DocumentError::InvalidAclEntry(_) => Self::invalid_field("entries", "invalid_acl_entry", err.to_string()),
+            DocumentError::ChecksumMismatch(_) => Self::invalid_field("checksum", "checksum_mismatch", err.to_string()),
+            DocumentError::QuotaExceeded { .. } => Self::conflict("quota_exceeded", err.to_string()),
+            DocumentError::Storage(err) => Self::from(err),
+            DocumentError::Database(err) => Self::from(err),
+        }
+    }
+}
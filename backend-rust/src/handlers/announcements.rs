@@ -0,0 +1,406 @@
+use axum::{
+    extract::{OriginalUri, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json},
+    Extension,
+};
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use validator::Validate;
+
+use super::pagination_headers;
+use crate::{
+    error::{AppError, AppResult},
+    middleware::{AnnouncementsPublish, PermissionCode, RequirePermission, ValidatedJson},
+    models::{
+        AnnouncementFeedQuery, AnnouncementListQuery, AnnouncementReadsResponse, AnnouncementResponse, Claims,
+        ClientInfo, CreateAnnouncementRequest, ErrorResponse, NewAuditEvent, UpdateAnnouncementRequest,
+    },
+    services::{AnnouncementService, AuditService, RoleService},
+    AppState,
+};
+
+// 공지사항 피드 핸들러
+// 게시 기간 안이고 조회자의 역할이 대상에 포함되는 공지만 고정 공지 먼저, 최근 게시 순으로 반환
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements",
+    tag = "announcements",
+    summary = "공지사항 피드",
+    params(AnnouncementFeedQuery),
+    responses(
+        (status = 200, description = "공지 목록 (페이지 정보는 X-Total-Count, Link 헤더)", body = Vec<AnnouncementResponse>),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_announcements(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<AnnouncementFeedQuery>,
+) -> AppResult<impl IntoResponse> {
+    query.validate()?;
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+
+    let (announcements, total) = AnnouncementService::new(state.db_pool)
+        .feed(viewer_id, &query)
+        .await?;
+    let responses: Vec<AnnouncementResponse> = announcements
+        .into_iter()
+        .map(|announcement| announcement.into_response(false))
+        .collect();
+
+    let headers = pagination_headers(uri.path(), &query, query.page, query.per_page, total);
+    Ok((StatusCode::OK, headers, Json(responses)))
+}
+
+// 공지사항 조회 핸들러
+// 게시 전/만료/대상이 아닌 공지는 announcements.publish 권한 보유자만 조회 가능 (그 외에는 404)
+#[utoipa::path(
+    get,
+    path = "/api/v1/announcements/{id}",
+    tag = "announcements",
+    summary = "공지사항 조회",
+    params(("id" = i32, Path, description = "공지 ID")),
+    responses(
+        (status = 200, description = "공지 (게시 권한 보유자에게는 read_count 포함)", body = AnnouncementResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "없거나 볼 수 없는 공지", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_announcement(
+    State(state): State<AppState>,
+    Path(announcement_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<AnnouncementResponse>> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let announcement_service = AnnouncementService::new(state.db_pool.clone());
+
+    let is_publisher = RoleService::new(state.db_pool.clone())
+        .has_permission(viewer_id, AnnouncementsPublish::CODE)
+        .await?;
+    if !is_publisher && !announcement_service.is_visible(announcement_id, viewer_id).await? {
+        return Err(announcement_not_found());
+    }
+
+    let announcement = announcement_service
+        .find_by_id(announcement_id, viewer_id)
+        .await?
+        .ok_or_else(announcement_not_found)?;
+
+    Ok(Json(announcement.into_response(is_publisher)))
+}
+
+// 공지사항 읽음 표시 핸들러 (처음 읽은 시각만 기록하므로 여러 번 호출해도 같은 결과)
+#[utoipa::path(
+    post,
+    path = "/api/v1/announcements/{id}/read",
+    tag = "announcements",
+    summary = "공지사항 읽음 표시",
+    params(("id" = i32, Path, description = "공지 ID")),
+    responses(
+        (status = 204, description = "읽음 표시 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "없거나 볼 수 없는 공지", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn mark_announcement_read(
+    State(state): State<AppState>,
+    Path(announcement_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<StatusCode> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let announcement_service = AnnouncementService::new(state.db_pool);
+
+    if !announcement_service.is_visible(announcement_id, viewer_id).await? {
+        return Err(announcement_not_found());
+    }
+    announcement_service.mark_read(announcement_id, viewer_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 공지사항 관리 목록 핸들러 (게시 예정/만료된 공지 포함, 게시 시각 최근 순)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/announcements",
+    tag = "announcements",
+    summary = "공지사항 관리 목록",
+    params(AnnouncementListQuery),
+    responses(
+        (status = 200, description = "공지 목록 (페이지 정보는 X-Total-Count, Link 헤더)", body = Vec<AnnouncementResponse>),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "announcements.publish 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_admin_announcements(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    RequirePermission(claims, _): RequirePermission<AnnouncementsPublish>,
+    Query(query): Query<AnnouncementListQuery>,
+) -> AppResult<impl IntoResponse> {
+    query.validate()?;
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+
+    let (announcements, total) = AnnouncementService::new(state.db_pool)
+        .find_page(viewer_id, &query)
+        .await?;
+    let responses: Vec<AnnouncementResponse> = announcements
+        .into_iter()
+        .map(|announcement| announcement.into_response(true))
+        .collect();
+
+    let headers = pagination_headers(uri.path(), &query, query.page, query.per_page, total);
+    Ok((StatusCode::OK, headers, Json(responses)))
+}
+
+// 공지사항 작성 핸들러 (작성자는 토큰의 사용자)
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/announcements",
+    tag = "announcements",
+    summary = "공지사항 작성",
+    request_body = CreateAnnouncementRequest,
+    responses(
+        (status = 201, description = "작성된 공지", body = AnnouncementResponse),
+        (status = 400, description = "유효성 검사 실패 (invalid_schedule, invalid_role)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "announcements.publish 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_announcement(
+    State(state): State<AppState>,
+    RequirePermission(claims, _): RequirePermission<AnnouncementsPublish>,
+    client: ClientInfo,
+    ValidatedJson(mut request): ValidatedJson<CreateAnnouncementRequest>,
+) -> AppResult<impl IntoResponse> {
+    let author_id: i32 = claims.sub.parse().unwrap_or(0);
+    check_schedule(request.publish_at.unwrap_or_else(Utc::now), request.expires_at)?;
+    request.audience_roles = check_audience_roles(&state, &request.audience_roles).await?;
+
+    let announcement_service = AnnouncementService::new(state.db_pool.clone());
+    let announcement_id = announcement_service.create(author_id, &request).await?;
+    let announcement = announcement_service
+        .find_by_id(announcement_id, author_id)
+        .await?
+        .ok_or_else(announcement_not_found)?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("announcement.create", &client)
+                .actor_claims(&claims)
+                .target("announcement", announcement.id)
+                .changes(json!({
+                    "title": announcement.title,
+                    "pinned": announcement.pinned,
+                    "publish_at": announcement.publish_at,
+                    "expires_at": announcement.expires_at,
+                    "audience_roles": announcement.audience_roles,
+                })),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(announcement.into_response(true))))
+}
+
+// 공지사항 수정 핸들러
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/announcements/{id}",
+    tag = "announcements",
+    summary = "공지사항 수정",
+    params(("id" = i32, Path, description = "공지 ID")),
+    request_body = UpdateAnnouncementRequest,
+    responses(
+        (status = 200, description = "수정된 공지", body = AnnouncementResponse),
+        (status = 400, description = "유효성 검사 실패 (invalid_schedule, invalid_role)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "announcements.publish 권한 필요", body = ErrorResponse),
+        (status = 404, description = "공지 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_announcement(
+    State(state): State<AppState>,
+    Path(announcement_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<AnnouncementsPublish>,
+    client: ClientInfo,
+    ValidatedJson(mut request): ValidatedJson<UpdateAnnouncementRequest>,
+) -> AppResult<Json<AnnouncementResponse>> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let announcement_service = AnnouncementService::new(state.db_pool.clone());
+
+    // 감사 로그용 변경 전 상태 조회
+    let before = announcement_service
+        .find_by_id(announcement_id, viewer_id)
+        .await?
+        .ok_or_else(announcement_not_found)?;
+
+    check_schedule(
+        request.publish_at.unwrap_or(before.publish_at),
+        request.expires_at.unwrap_or(before.expires_at),
+    )?;
+    if let Some(audience_roles) = &request.audience_roles {
+        request.audience_roles = Some(check_audience_roles(&state, audience_roles).await?);
+    }
+
+    if !announcement_service.update(announcement_id, &request).await? {
+        return Err(announcement_not_found());
+    }
+    let announcement = announcement_service
+        .find_by_id(announcement_id, viewer_id)
+        .await?
+        .ok_or_else(announcement_not_found)?;
+
+    // 본문은 길 수 있으므로 변경 여부만 기록
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("announcement.update", &client)
+                .actor_claims(&claims)
+                .target("announcement", announcement.id)
+                .changes(json!({
+                    "title": { "before": before.title, "after": announcement.title },
+                    "body_changed": before.body != announcement.body,
+                    "pinned": { "before": before.pinned, "after": announcement.pinned },
+                    "publish_at": { "before": before.publish_at, "after": announcement.publish_at },
+                    "expires_at": { "before": before.expires_at, "after": announcement.expires_at },
+                    "audience_roles": { "before": before.audience_roles, "after": announcement.audience_roles },
+                })),
+        )
+        .await;
+
+    Ok(Json(announcement.into_response(true)))
+}
+
+// 공지사항 삭제 핸들러 (읽음 기록도 함께 삭제)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/announcements/{id}",
+    tag = "announcements",
+    summary = "공지사항 삭제",
+    params(("id" = i32, Path, description = "공지 ID")),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "announcements.publish 권한 필요", body = ErrorResponse),
+        (status = 404, description = "공지 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_announcement(
+    State(state): State<AppState>,
+    Path(announcement_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<AnnouncementsPublish>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let announcement_service = AnnouncementService::new(state.db_pool.clone());
+
+    let announcement = announcement_service
+        .find_by_id(announcement_id, viewer_id)
+        .await?
+        .ok_or_else(announcement_not_found)?;
+    if !announcement_service.delete(announcement_id).await? {
+        return Err(announcement_not_found());
+    }
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("announcement.delete", &client)
+                .actor_claims(&claims)
+                .target("announcement", announcement_id)
+                .changes(json!({
+                    "title": announcement.title,
+                    "read_count": announcement.read_count,
+                })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 공지사항 읽음 확인 핸들러 (대상 사용자 수와 읽은 사용자 목록)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/announcements/{id}/reads",
+    tag = "announcements",
+    summary = "공지사항 읽음 확인",
+    params(("id" = i32, Path, description = "공지 ID")),
+    responses(
+        (status = 200, description = "대상 사용자 수와 읽은 사용자", body = AnnouncementReadsResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "announcements.publish 권한 필요", body = ErrorResponse),
+        (status = 404, description = "공지 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_announcement_reads(
+    State(state): State<AppState>,
+    Path(announcement_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<AnnouncementsPublish>,
+) -> AppResult<Json<AnnouncementReadsResponse>> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let announcement_service = AnnouncementService::new(state.db_pool);
+
+    let announcement = announcement_service
+        .find_by_id(announcement_id, viewer_id)
+        .await?
+        .ok_or_else(announcement_not_found)?;
+    let reads = announcement_service.reads(announcement_id).await?;
+    let audience_count = announcement_service
+        .audience_count(&announcement.audience_roles)
+        .await?;
+
+    Ok(Json(AnnouncementReadsResponse {
+        announcement_id,
+        audience_count,
+        read_count: reads.len() as i64,
+        reads,
+    }))
+}
+
+// 만료 시각은 게시 시각 이후여야 함
+fn check_schedule(publish_at: DateTime<Utc>, expires_at: Option<DateTime<Utc>>) -> AppResult<()> {
+    if expires_at.is_some_and(|expires_at| expires_at <= publish_at) {
+        return Err(AppError::invalid_field(
+            "expires_at",
+            "invalid_schedule",
+            "만료 시각은 게시 시각 이후여야 합니다",
+        ));
+    }
+    Ok(())
+}
+
+// 대상 역할 정리 (앞뒤 공백 제거, 중복 제거) 후 존재하는 역할인지 확인
+async fn check_audience_roles(state: &AppState, roles: &[String]) -> AppResult<Vec<String>> {
+    let mut audience_roles: Vec<String> = Vec::new();
+    for role in roles.iter().map(|role| role.trim()) {
+        if !audience_roles.iter().any(|existing| existing == role) {
+            audience_roles.push(role.to_string());
+        }
+    }
+
+    let unknown = RoleService::new(state.db_pool.clone())
+        .unknown_roles(&audience_roles)
+        .await?;
+    if !unknown.is_empty() {
+        return Err(AppError::invalid_field(
+            "audience_roles",
+            "invalid_role",
+            format!("존재하지 않는 역할입니다: {}", unknown.join(", ")),
+        ));
+    }
+
+    Ok(audience_roles)
+}
+
+fn announcement_not_found() -> AppError {
+    AppError::not_found("announcement_not_found", "공지사항을 찾을 수 없습니다")
+}
@@ -0,0 +1,142 @@
+use axum::{
+    extract::{OriginalUri, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json, Response},
+};
+use validator::Validate;
+
+use super::pagination_headers;
+use crate::{
+    error::AppResult,
+    middleware::{AuditRead, RequirePermission},
+    models::{AuditEvent, AuditQuery, ErrorResponse, ExportFormat},
+    services::AuditService,
+    AppState,
+};
+
+// 감사 로그 조회 핸들러 (audit.read 권한 필요)
+// format=json: 페이지 단위 JSON 배열 + 페이지네이션 헤더
+// format=csv: 필터 조건에 맞는 로그 전체를 CSV 파일로 내보내기 (최대 10,000행)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/audit",
+    tag = "audit",
+    summary = "감사 로그 조회",
+    params(AuditQuery),
+    responses(
+        (status = 200, description = "감사 로그 (format=csv이면 text/csv 파일)", body = Vec<AuditEvent>),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "audit.read 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_audit_events(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    _: RequirePermission<AuditRead>,
+    Query(query): Query<AuditQuery>,
+) -> AppResult<Response> {
+    query.validate()?;
+
+    let audit_service = AuditService::new(state.db_pool);
+
+    if query.format == ExportFormat::Csv {
+        let csv = events_to_csv(&audit_service.export(&query).await?)?;
+
+        return Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (header::CONTENT_DISPOSITION, "attachment; filename=\"audit_events.csv\""),
+            ],
+            csv,
+        ).into_response());
+    }
+
+    let (events, total) = audit_service.find_page(&query).await?;
+    let headers = pagination_headers(uri.path(), &query, query.page, query.per_page, total);
+
+    Ok((StatusCode::OK, headers, Json(events)).into_response())
+}
+
+// 감사 로그를 CSV로 변환 (엑셀 호환을 위해 UTF-8 BOM 포함)
+fn events_to_csv(events: &[AuditEvent]) -> anyhow::Result<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(b"\xEF\xBB\xBF".to_vec());
+    writer.write_record([
+        "id",
+        "occurred_at",
+        "actor_id",
+        "actor_email",
+        "action",
+        "target_type",
+        "target_id",
+        "changes",
+        "ip_address",
+        "user_agent",
+    ])?;
+
+    for event in events {
+        writer.write_record([
+            event.id.to_string(),
+            event.occurred_at.to_rfc3339(),
+            event.actor_id.map(|id| id.to_string()).unwrap_or_default(),
+            csv_text(event.actor_email.as_deref().unwrap_or_default()),
+            csv_text(&event.action),
+            csv_text(event.target_type.as_deref().unwrap_or_default()),
+            csv_text(event.target_id.as_deref().unwrap_or_default()),
+            csv_text(&event.changes.as_ref().map(|changes| changes.to_string()).unwrap_or_default()),
+            csv_text(event.ip_address.as_deref().unwrap_or_default()),
+            csv_text(event.user_agent.as_deref().unwrap_or_default()),
+        ])?;
+    }
+
+    Ok(writer.into_inner()?)
+}
+
+// 스프레드시트가 수식으로 실행하지 않도록 수식 시작 문자로 시작하는 값 앞에 '를 붙임
+// (User-Agent, 로그인 실패 이메일 등 외부에서 넣을 수 있는 값이 포함됨)
+fn csv_text(value: &str) -> String {
+    if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn csv_text_escapes_formula_prefixes() {
+        for value in ["=HYPERLINK(\"http://evil\")", "+1", "-1+2", "@SUM(A1)", "\tcmd", "\rcmd"] {
+            assert_eq!(csv_text(value), format!("'{}", value));
+        }
+        assert_eq!(csv_text("Mozilla/5.0"), "Mozilla/5.0");
+        assert_eq!(csv_text("a=b"), "a=b");
+        assert_eq!(csv_text(""), "");
+    }
+
+    #[test]
+    fn events_to_csv_escapes_attacker_controlled_cells() {
+        let event = AuditEvent {
+            id: 1,
+            occurred_at: Utc::now(),
+            actor_id: None,
+            actor_email: Some("=cmd|'/c calc'!A1".to_string()),
+            action: "auth.login.failure".to_string(),
+            target_type: None,
+            target_id: None,
+            changes: None,
+            ip_address: Some("203.0.113.7".to_string()),
+            user_agent: Some("@SUM(1+1)".to_string()),
+        };
+
+        let csv = String::from_utf8(events_to_csv(&[event]).unwrap()).unwrap();
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.contains(",,'=cmd|'/c calc'!A1,auth.login.failure,"), "{}", row);
+        assert!(row.ends_with(",203.0.113.7,'@SUM(1+1)"), "{}", row);
+    }
+}
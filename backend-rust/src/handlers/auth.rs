@@ -1,177 +1,642 @@
 use axum::{
     extract::State,
     http::StatusCode,
-    response::{IntoResponse, Json},
+    response::{IntoResponse, Json, Response},
+    Extension,
 };
-use validator::Validate;
+use serde_json::json;
 
+use super::mfa::{mfa_code, missing_mfa_code};
 use crate::{
-    middleware::create_jwt_token,
-    models::{AuthResponse, ErrorResponse, LoginRequest, RegisterRequest, UserResponse},
-    services::UserService,
+    error::{AppError, AppResult},
+    mail::{send_in_background, Mail},
+    middleware::ValidatedJson,
+    models::{
+        AuthResponse, Claims, ClientInfo, ErrorResponse, ForgotPasswordRequest, LoginRequest,
+        LogoutRequest, MessageResponse, MfaChallengeResponse, MfaVerifyRequest, NewAuditEvent,
+        RefreshRequest, RegisterRequest, ResendVerificationRequest, ResetPasswordRequest, User,
+        UserResponse, VerifyEmailRequest,
+    },
+    services::{
+        AuditService, ChallengeOutcome, EmailVerificationService, IssuedTokens, LoginBlock,
+        LoginThrottleService, MfaCode, MfaService, PasswordResetService,
+        SessionService, TokenService, UserService,
+    },
     AppState,
 };
 
+// 발급된 토큰과 사용자 정보로 인증 응답 생성
+fn auth_response(tokens: IssuedTokens, user: User) -> AuthResponse {
+    AuthResponse {
+        token: tokens.access_token,
+        refresh_token: tokens.refresh_token,
+        token_type: "Bearer".to_string(),
+        expires_in: tokens.expires_in,
+        user: UserResponse::from(user),
+    }
+}
+
+// 로그인 차단 에러 (계정 잠금은 423, 시도 횟수 제한은 429)
+impl From<LoginBlock> for AppError {
+    fn from(block: LoginBlock) -> Self {
+        match block {
+            LoginBlock::AccountLocked { retry_after } => AppError::Locked {
+                code: "account_locked",
+                message: "로그인 실패 횟수를 초과하여 계정이 일시적으로 잠겼습니다".to_string(),
+                retry_after,
+            },
+            LoginBlock::Backoff { retry_after } => AppError::TooManyRequests {
+                code: "too_many_attempts",
+                message: "잠시 후 다시 시도해주세요".to_string(),
+                retry_after,
+            },
+            LoginBlock::IpLimited { retry_after } => AppError::TooManyRequests {
+                code: "too_many_attempts",
+                message: "로그인 시도가 너무 많습니다. 잠시 후 다시 시도해주세요".to_string(),
+                retry_after,
+            },
+        }
+    }
+}
+
+// 현재 이메일 주소로 인증 메일 발송 (회원가입, 이메일 변경, 재발송)
+pub(crate) async fn send_verification_email(state: &AppState, user: &User) -> anyhow::Result<()> {
+    let verification_service = EmailVerificationService::new(
+        state.db_pool.clone(),
+        state.config.email_verification_expires_hours,
+    );
+    let token = verification_service.create_token(user).await?;
+
+    let mail = Mail {
+        to: user.email.clone(),
+        subject: "[Sample Intranet] 이메일 주소 인증 안내".to_string(),
+        body: format!(
+            "{}님, 안녕하세요.\n\n\
+             아래 링크에서 이메일 주소를 인증해주세요. 링크는 {}시간 동안 유효합니다.\n\n\
+             {}?token={}\n\n\
+             본인이 요청하지 않았다면 이 메일을 무시해주세요.",
+            user.name,
+            state.config.email_verification_expires_hours,
+            state.config.email_verification_url,
+            token
+        ),
+    };
+    send_in_background(state.mailer.clone(), mail);
+
+    Ok(())
+}
+
 // 회원가입 핸들러
+// 가입한 사용자는 항상 기본 역할(user)로 생성 (요청 본문의 role은 무시)
+// EMAIL_VERIFICATION_REQUIRED=true이면 토큰 없이 사용자 정보만 반환하고 인증 후 로그인하도록 한다
+// 이메일 중복은 users.email 유니크 제약 위반으로 409 응답
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/register",
+    tag = "auth",
+    summary = "회원가입",
+    request_body = RegisterRequest,
+    responses(
+        (status = 201, description = "가입 완료 (EMAIL_VERIFICATION_REQUIRED=true이면 토큰 없이 UserResponse)", body = AuthResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 409, description = "이미 존재하는 이메일 (email_exists)", body = ErrorResponse),
+        (status = 415, description = "Content-Type이 application/json이 아님", body = ErrorResponse),
+    )
+)]
 pub async fn register(
     State(state): State<AppState>,
-    Json(request): Json<RegisterRequest>,
-) -> impl IntoResponse {
-    // 요청 데이터 유효성 검사
-    if let Err(errors) = request.validate() {
-        let error_messages: Vec<String> = errors
-            .field_errors()
-            .into_iter()
-            .flat_map(|(_, field_errors)| {
-                field_errors.iter().map(|error| {
-                    error.message.as_ref().unwrap_or(&"유효성 검사 실패".into()).to_string()
-                })
-            })
-            .collect();
-        
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("validation_error", &error_messages.join(", "))),
-        ).into_response();
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<RegisterRequest>,
+) -> AppResult<Response> {
+    let user_service = UserService::new(state.db_pool.clone());
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let token_service = TokenService::new(state.db_pool.clone(), state.config.clone());
+
+    // 사용자 생성
+    let user = user_service.create_user(request).await?;
+
+    audit_service
+        .record(
+            NewAuditEvent::new("user.register", &client)
+                .actor(user.id, &user.email)
+                .target("user", user.id),
+        )
+        .await;
+
+    // 인증 메일 발송 실패는 가입을 실패시키지 않음 (재발송 가능)
+    if let Err(err) = send_verification_email(&state, &user).await {
+        tracing::error!("인증 메일 발송 실패: {}", err);
+    }
+
+    if state.config.email_verification_required {
+        return Ok((StatusCode::CREATED, Json(UserResponse::from(user))).into_response());
     }
 
-    let user_service = UserService::new(state.db_pool);
+    // 액세스/리프레시 토큰 발급
+    let tokens = token_service.issue(&user).await?;
+    Ok((StatusCode::CREATED, Json(auth_response(tokens, user))).into_response())
+}
+
+// 로그인 핸들러
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/login",
+    tag = "auth",
+    summary = "로그인",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "로그인 성공 (2단계 인증 등록 계정은 MfaChallengeResponse)", body = AuthResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "이메일 또는 비밀번호 불일치", body = ErrorResponse),
+        (status = 403, description = "비활성 계정 또는 이메일 미인증", body = ErrorResponse),
+        (status = 423, description = "계정 잠금", body = ErrorResponse),
+        (status = 429, description = "로그인 시도 제한", body = ErrorResponse),
+    )
+)]
+pub async fn login(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<LoginRequest>,
+) -> AppResult<Response> {
+    let user_service = UserService::new(state.db_pool.clone());
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let throttle_service = LoginThrottleService::new(state.db_pool.clone(), state.config.login_throttle.clone());
+    let verification_required = state.config.email_verification_required;
+    let challenge_expires_in = state.config.mfa_challenge_expires_minutes * 60;
+    let mfa_service = MfaService::new(state.db_pool.clone(), state.config.clone());
+    let token_service = TokenService::new(state.db_pool, state.config);
+    let ip_address = client.ip_address.as_deref();
 
-    // 이메일 중복 확인
-    match user_service.find_by_email(&request.email).await {
-        Ok(Some(_)) => {
-            return (
-                StatusCode::CONFLICT,
-                Json(ErrorResponse::new("email_exists", "이미 존재하는 이메일입니다")),
-            ).into_response();
+    // 로그인 실패 기록 (시도한 이메일과 사유)
+    let record_failure = |user_id: Option<i32>, reason: &str| {
+        let mut event = NewAuditEvent::new("auth.login.failure", &client)
+            .changes(json!({ "email": request.email, "reason": reason }));
+        if let Some(user_id) = user_id {
+            event = event.target("user", user_id);
         }
-        Ok(None) => {
-            // 이메일이 존재하지 않음 (정상)
+        audit_service.record(event)
+    };
+
+    // 비밀번호 실패 집계 후 에러 반환 (이번 실패로 잠기면 423)
+    let failed_attempt = || async {
+        match throttle_service.record_failure(&request.email, ip_address).await {
+            Ok(Some(block)) => return AppError::from(block),
+            Ok(None) => {}
+            Err(err) => tracing::error!("로그인 실패 기록 실패: {}", err),
         }
-        Err(err) => {
-            tracing::error!("데이터베이스 오류: {}", err);
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new("database_error", "서버 오류가 발생했습니다")),
-            ).into_response();
+        AppError::unauthorized("invalid_credentials", "이메일 또는 비밀번호가 잘못되었습니다")
+    };
+
+    // 잠금/대기 시간 확인 (비밀번호 검증 전)
+    if let Some(block) = throttle_service.check(&request.email, ip_address).await? {
+        let reason = match block {
+            LoginBlock::AccountLocked { .. } => "account_locked",
+            LoginBlock::Backoff { .. } | LoginBlock::IpLimited { .. } => "rate_limited",
+        };
+        record_failure(None, reason).await;
+        return Err(block.into());
+    }
+
+    // 사용자 조회
+    let user = match user_service.find_by_email(&request.email).await? {
+        Some(user) => user,
+        None => {
+            record_failure(None, "unknown_email").await;
+            return Err(failed_attempt().await);
         }
+    };
+
+    // 비밀번호 검증
+    if !user_service.verify_password(&request.password, &user.password)? {
+        record_failure(Some(user.id), "invalid_password").await;
+        return Err(failed_attempt().await);
     }
 
-    // 사용자 생성
-    match user_service.create_user(request).await {
-        Ok(user) => {
-            // JWT 토큰 생성
-            match create_jwt_token(user.id, &user.email, &user.role) {
-                Ok(token) => {
-                    let response = AuthResponse {
-                        token,
-                        user: UserResponse::from(user),
-                    };
-                    (StatusCode::CREATED, Json(response)).into_response()
-                }
-                Err(err) => {
-                    tracing::error!("JWT 토큰 생성 실패: {}", err);
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ErrorResponse::new("token_error", "토큰 생성에 실패했습니다")),
-                    ).into_response()
+    // 활성 사용자 확인
+    if !user.is_active {
+        record_failure(Some(user.id), "account_inactive").await;
+        return Err(AppError::forbidden("account_inactive", "비활성화된 계정입니다"));
+    }
+
+    // 이메일 인증 확인 (설정으로 활성화한 경우)
+    if verification_required && user.email_verified_at.is_none() {
+        record_failure(Some(user.id), "email_not_verified").await;
+        return Err(AppError::forbidden("email_not_verified", "이메일 인증이 필요합니다"));
+    }
+
+    // 2단계 인증이 등록된 계정은 토큰 대신 2단계 로그인 토큰 발급
+    // (실패 횟수는 2단계 인증까지 마친 뒤 초기화)
+    if mfa_service.is_enabled(user.id).await? {
+        let mfa_token = mfa_service.create_challenge(user.id).await?;
+        return Ok(Json(MfaChallengeResponse {
+            mfa_required: true,
+            mfa_token,
+            expires_in: challenge_expires_in,
+        }).into_response());
+    }
+
+    // 액세스/리프레시 토큰 발급
+    let tokens = token_service.issue(&user).await?;
+
+    if let Err(err) = throttle_service.record_success(&request.email).await {
+        tracing::error!("로그인 실패 횟수 초기화 실패: {}", err);
+    }
+    audit_service
+        .record(
+            NewAuditEvent::new("auth.login.success", &client)
+                .actor(user.id, &user.email)
+                .target("user", user.id),
+        )
+        .await;
+
+    Ok(Json(auth_response(tokens, user)).into_response())
+}
+
+// 2단계 로그인 핸들러 (로그인 1단계의 토큰과 인증 앱 코드 또는 복구 코드로 토큰 발급)
+// 코드가 틀리면 비밀번호 실패와 같이 로그인 실패 횟수에 집계
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/mfa/verify",
+    tag = "auth",
+    summary = "2단계 로그인",
+    request_body = MfaVerifyRequest,
+    responses(
+        (status = 200, description = "로그인 성공", body = AuthResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 코드 또는 2단계 인증 토큰 오류", body = ErrorResponse),
+        (status = 423, description = "계정 잠금", body = ErrorResponse),
+        (status = 429, description = "로그인 시도 제한", body = ErrorResponse),
+    )
+)]
+pub async fn verify_mfa(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<MfaVerifyRequest>,
+) -> AppResult<Json<AuthResponse>> {
+    let code = mfa_code(&request.code, &request.recovery_code).ok_or_else(missing_mfa_code)?;
+
+    let user_service = UserService::new(state.db_pool.clone());
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let throttle_service = LoginThrottleService::new(state.db_pool.clone(), state.config.login_throttle.clone());
+    let mfa_service = MfaService::new(state.db_pool.clone(), state.config.clone());
+    let token_service = TokenService::new(state.db_pool, state.config);
+    let ip_address = client.ip_address.as_deref();
+    let method = match code {
+        MfaCode::Totp(_) => "totp",
+        MfaCode::Recovery(_) => "recovery_code",
+    };
+
+    let user_id = match mfa_service.verify_challenge(&request.mfa_token, code).await? {
+        ChallengeOutcome::Verified(user_id) => user_id,
+        ChallengeOutcome::InvalidCode(user_id) => {
+            audit_service
+                .record(
+                    NewAuditEvent::new("auth.mfa.failure", &client)
+                        .target("user", user_id)
+                        .changes(json!({ "method": method })),
+                )
+                .await;
+
+            // 2단계 코드 실패도 계정 잠금에 반영
+            if let Some(user) = user_service.find_by_id(user_id).await? {
+                match throttle_service.record_failure(&user.email, ip_address).await {
+                    Ok(Some(block)) => return Err(block.into()),
+                    Ok(None) => {}
+                    Err(err) => tracing::error!("로그인 실패 기록 실패: {}", err),
                 }
             }
+
+            return Err(AppError::unauthorized("invalid_mfa_code", "인증 코드가 올바르지 않습니다"));
         }
-        Err(err) => {
-            tracing::error!("사용자 생성 실패: {}", err);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new("creation_error", "사용자 생성에 실패했습니다")),
-            ).into_response()
+        ChallengeOutcome::InvalidChallenge => {
+            return Err(AppError::unauthorized(
+                "invalid_mfa_token",
+                "유효하지 않거나 만료된 2단계 인증 토큰입니다. 다시 로그인해주세요",
+            ));
         }
+    };
+
+    // 1단계 이후 비활성화된 계정은 거부
+    let user = match user_service.find_by_id(user_id).await? {
+        Some(user) if user.is_active => user,
+        _ => return Err(AppError::forbidden("account_inactive", "비활성화된 계정입니다")),
+    };
+
+    let tokens = token_service.issue(&user).await?;
+
+    if let Err(err) = throttle_service.record_success(&user.email).await {
+        tracing::error!("로그인 실패 횟수 초기화 실패: {}", err);
     }
+    audit_service
+        .record(
+            NewAuditEvent::new("auth.login.success", &client)
+                .actor(user.id, &user.email)
+                .target("user", user.id)
+                .changes(json!({ "mfa": method })),
+        )
+        .await;
+
+    Ok(Json(auth_response(tokens, user)))
 }
 
-// 로그인 핸들러
-pub async fn login(
+// 토큰 갱신 핸들러 (리프레시 토큰은 사용할 때마다 교체)
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/refresh",
+    tag = "auth",
+    summary = "토큰 갱신",
+    request_body = RefreshRequest,
+    responses(
+        (status = 200, description = "새 토큰 발급", body = AuthResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "유효하지 않거나 만료된 리프레시 토큰", body = ErrorResponse),
+    )
+)]
+pub async fn refresh(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<RefreshRequest>,
+) -> AppResult<Json<AuthResponse>> {
+    let token_service = TokenService::new(state.db_pool, state.config);
+
+    let (user, tokens) = token_service
+        .rotate(&request.refresh_token)
+        .await?
+        .ok_or_else(|| {
+            AppError::unauthorized("invalid_refresh_token", "유효하지 않거나 만료된 리프레시 토큰입니다")
+        })?;
+
+    Ok(Json(auth_response(tokens, user)))
+}
+
+// 로그아웃 핸들러 (현재 액세스 토큰 폐기)
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/logout",
+    tag = "auth",
+    summary = "로그아웃",
+    request_body = Option<LogoutRequest>,
+    responses(
+        (status = 204, description = "현재 세션 폐기"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn logout(
     State(state): State<AppState>,
-    Json(request): Json<LoginRequest>,
-) -> impl IntoResponse {
-    // 요청 데이터 유효성 검사
-    if let Err(errors) = request.validate() {
-        let error_messages: Vec<String> = errors
-            .field_errors()
-            .into_iter()
-            .flat_map(|(_, field_errors)| {
-                field_errors.iter().map(|error| {
-                    error.message.as_ref().unwrap_or(&"유효성 검사 실패".into()).to_string()
-                })
-            })
-            .collect();
-        
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("validation_error", &error_messages.join(", "))),
-        ).into_response();
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    request: Option<Json<LogoutRequest>>,
+) -> AppResult<StatusCode> {
+    let request = request.map(|Json(request)| request).unwrap_or_default();
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let session_service = SessionService::new(state.db_pool.clone(), state.session_cache);
+    let token_service = TokenService::new(state.db_pool, state.config);
+
+    session_service.revoke_token(&claims).await?;
+
+    // 리프레시 토큰이 함께 전달되면 해당 세션의 토큰 갱신도 차단
+    if let Some(refresh_token) = request.refresh_token {
+        let user_id: i32 = claims.sub.parse().unwrap_or(0);
+        token_service.revoke(user_id, &refresh_token).await?;
     }
 
-    let user_service = UserService::new(state.db_pool);
+    audit_service
+        .record(NewAuditEvent::new("auth.logout", &client).actor_claims(&claims))
+        .await;
 
-    // 사용자 조회
-    let user = match user_service.find_by_email(&request.email).await {
-        Ok(Some(user)) => user,
-        Ok(None) => {
-            return (
-                StatusCode::UNAUTHORIZED,
-                Json(ErrorResponse::new("invalid_credentials", "이메일 또는 비밀번호가 잘못되었습니다")),
-            ).into_response();
-        }
-        Err(err) => {
-            tracing::error!("데이터베이스 오류: {}", err);
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new("database_error", "서버 오류가 발생했습니다")),
-            ).into_response();
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 전체 로그아웃 핸들러 (모든 기기의 세션 폐기)
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/logout-all",
+    tag = "auth",
+    summary = "전체 로그아웃",
+    responses(
+        (status = 204, description = "모든 세션 폐기"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn logout_all(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let session_service = SessionService::new(state.db_pool.clone(), state.session_cache);
+    let token_service = TokenService::new(state.db_pool, state.config);
+
+    session_service.revoke_all(user_id).await?;
+    token_service.revoke_all_for_user(user_id).await?;
+
+    audit_service
+        .record(NewAuditEvent::new("auth.logout_all", &client).actor_claims(&claims))
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 비밀번호 재설정 메일 요청 핸들러
+// 가입 여부를 알 수 없도록 이메일 존재 여부와 관계없이 항상 같은 응답을 반환
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/password/forgot",
+    tag = "auth",
+    summary = "비밀번호 재설정 메일 요청",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 202, description = "요청 접수 (가입 여부와 관계없이 같은 응답)", body = MessageResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+    )
+)]
+pub async fn forgot_password(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<ForgotPasswordRequest>,
+) -> AppResult<impl IntoResponse> {
+    // 응답 시간으로 가입 여부가 드러나지 않도록 사용자 조회부터 메일 발송까지 모두 백그라운드에서 처리
+    tokio::spawn(async move {
+        if let Err(err) = send_password_reset_email(&state, &client, &request.email).await {
+            tracing::error!("비밀번호 재설정 메일 처리 실패: {}", err);
         }
+    });
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(MessageResponse::new("등록된 이메일이면 비밀번호 재설정 안내 메일이 발송됩니다")),
+    ))
+}
+
+// 활성 사용자이면 재설정 토큰을 만들어 메일 발송 (forgot_password의 백그라운드 작업)
+async fn send_password_reset_email(state: &AppState, client: &ClientInfo, email: &str) -> anyhow::Result<()> {
+    let user_service = UserService::new(state.db_pool.clone());
+    let reset_service = PasswordResetService::new(
+        state.db_pool.clone(),
+        state.config.password_reset_expires_minutes,
+    );
+
+    let user = match user_service.find_by_email(email).await? {
+        Some(user) if user.is_active => user,
+        _ => return Ok(()),
     };
 
-    // 비밀번호 검증
-    match user_service.verify_password(&request.password, &user.password) {
-        Ok(true) => {
-            // 활성 사용자 확인
-            if !user.is_active {
-                return (
-                    StatusCode::FORBIDDEN,
-                    Json(ErrorResponse::new("account_inactive", "비활성화된 계정입니다")),
-                ).into_response();
-            }
+    let token = reset_service.create_token(user.id).await?;
 
-            // JWT 토큰 생성
-            match create_jwt_token(user.id, &user.email, &user.role) {
-                Ok(token) => {
-                    let response = AuthResponse {
-                        token,
-                        user: UserResponse::from(user),
-                    };
-                    (StatusCode::OK, Json(response)).into_response()
-                }
-                Err(err) => {
-                    tracing::error!("JWT 토큰 생성 실패: {}", err);
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ErrorResponse::new("token_error", "토큰 생성에 실패했습니다")),
-                    ).into_response()
-                }
-            }
-        }
-        Ok(false) => {
-            (
-                StatusCode::UNAUTHORIZED,
-                Json(ErrorResponse::new("invalid_credentials", "이메일 또는 비밀번호가 잘못되었습니다")),
-            ).into_response()
+    AuditService::new(state.db_pool.clone())
+        .record(
+            NewAuditEvent::new("auth.password.forgot", client)
+                .actor(user.id, &user.email)
+                .target("user", user.id),
+        )
+        .await;
+
+    send_in_background(state.mailer.clone(), Mail {
+        to: user.email,
+        subject: "[Sample Intranet] 비밀번호 재설정 안내".to_string(),
+        body: format!(
+            "{}님, 안녕하세요.\n\n\
+             아래 링크에서 새 비밀번호를 설정해주세요. 링크는 {}분 동안 한 번만 사용할 수 있습니다.\n\n\
+             {}?token={}\n\n\
+             본인이 요청하지 않았다면 이 메일을 무시해주세요.",
+            user.name,
+            state.config.password_reset_expires_minutes,
+            state.config.password_reset_url,
+            token
+        ),
+    });
+
+    Ok(())
+}
+
+// 비밀번호 재설정 핸들러
+// 재설정 후에는 기존 세션(액세스/리프레시 토큰)을 모두 폐기
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/password/reset",
+    tag = "auth",
+    summary = "비밀번호 재설정",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 204, description = "비밀번호 변경 완료"),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+    )
+)]
+pub async fn reset_password(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<ResetPasswordRequest>,
+) -> AppResult<StatusCode> {
+    let reset_service = PasswordResetService::new(
+        state.db_pool.clone(),
+        state.config.password_reset_expires_minutes,
+    );
+    let session_service = SessionService::new(state.db_pool.clone(), state.session_cache);
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let token_service = TokenService::new(state.db_pool, state.config);
+
+    let user = reset_service
+        .reset_password(&request.token, &request.password)
+        .await?
+        .ok_or_else(|| {
+            AppError::bad_request("invalid_reset_token", "유효하지 않거나 만료된 재설정 토큰입니다")
+        })?;
+
+    session_service.revoke_all(user.id).await?;
+    token_service.revoke_all_for_user(user.id).await?;
+
+    audit_service
+        .record(
+            NewAuditEvent::new("auth.password.reset", &client)
+                .actor(user.id, &user.email)
+                .target("user", user.id)
+                .changes(json!({ "password": { "changed": true } })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 이메일 인증 핸들러
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/email/verify",
+    tag = "auth",
+    summary = "이메일 인증",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "인증 완료", body = UserResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+    )
+)]
+pub async fn verify_email(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<VerifyEmailRequest>,
+) -> AppResult<Json<UserResponse>> {
+    let verification_service = EmailVerificationService::new(
+        state.db_pool.clone(),
+        state.config.email_verification_expires_hours,
+    );
+
+    let user = verification_service
+        .verify(&request.token)
+        .await?
+        .ok_or_else(|| {
+            AppError::bad_request("invalid_verification_token", "유효하지 않거나 만료된 인증 토큰입니다")
+        })?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("auth.email.verify", &client)
+                .actor(user.id, &user.email)
+                .target("user", user.id),
+        )
+        .await;
+
+    Ok(Json(UserResponse::from(user)))
+}
+
+// 인증 메일 재발송 핸들러
+// 가입 여부를 알 수 없도록 이메일 존재 여부와 관계없이 항상 같은 응답을 반환
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/email/resend",
+    tag = "auth",
+    summary = "인증 메일 재발송",
+    request_body = ResendVerificationRequest,
+    responses(
+        (status = 202, description = "요청 접수 (가입 여부와 관계없이 같은 응답)", body = MessageResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+    )
+)]
+pub async fn resend_verification(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<ResendVerificationRequest>,
+) -> AppResult<impl IntoResponse> {
+    // 응답 시간으로 가입/인증 여부가 드러나지 않도록 사용자 조회부터 메일 발송까지 모두 백그라운드에서 처리
+    tokio::spawn(async move {
+        if let Err(err) = resend_verification_email(&state, &request.email).await {
+            tracing::error!("인증 메일 재발송 처리 실패: {}", err);
         }
-        Err(err) => {
-            tracing::error!("비밀번호 검증 실패: {}", err);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new("verification_error", "비밀번호 검증에 실패했습니다")),
-            ).into_response()
+    });
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(MessageResponse::new("인증이 필요한 이메일이면 인증 메일이 발송됩니다")),
+    ))
+}
+
+// 활성 상태이고 아직 인증하지 않은 사용자에게만 발송 (resend_verification의 백그라운드 작업)
+async fn resend_verification_email(state: &AppState, email: &str) -> anyhow::Result<()> {
+    let user_service = UserService::new(state.db_pool.clone());
+
+    if let Some(user) = user_service.find_by_email(email).await? {
+        if user.is_active && user.email_verified_at.is_none() {
+            send_verification_email(state, &user).await?;
         }
     }
-} 
\ No newline at end of file
+    Ok(())
+}
@@ -0,0 +1,203 @@
+use axum::{
+    extract::{multipart::MultipartRejection, Multipart, Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use serde_json::json;
+
+use super::{profiles::with_profiles, read_file_field};
+use crate::{
+    error::{AppError, AppResult},
+    middleware::{PermissionCode, UsersUpdate},
+    models::{AvatarUploadRequest, Claims, ClientInfo, ErrorResponse, NewAuditEvent, User, UserResponse, AVATAR_SIZES},
+    services::{AuditService, AvatarService, RoleService, UserService},
+    AppState,
+};
+
+// 프로필 사진 업로드 핸들러 (본인 또는 users.update 권한 보유자)
+// multipart/form-data의 file 필드로 받은 이미지를 크기별 PNG 썸네일로 변환해 저장
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/{id}/avatar",
+    tag = "users",
+    summary = "프로필 사진 업로드",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    request_body(content = AvatarUploadRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "avatar_url이 바뀐 사용자 정보", body = UserResponse),
+        (status = 400, description = "file 필드 없음, 읽을 수 없거나 너무 큰 이미지", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "수정 권한 없음", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+        (status = 413, description = "AVATAR_MAX_BYTES 초과", body = ErrorResponse),
+        (status = 415, description = "multipart 요청이 아니거나 지원하지 않는 이미지 형식 (unsupported_image_type)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn upload_avatar(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    multipart: Result<Multipart, MultipartRejection>,
+) -> AppResult<Json<UserResponse>> {
+    let before = find_editable_user(&state, &claims, user_id).await?;
+
+    let multipart = multipart.map_err(|_| {
+        AppError::unsupported_file("unsupported_media_type", "Content-Type이 multipart/form-data여야 합니다")
+    })?;
+    let data = read_file_field(multipart, state.config.avatar_max_bytes, "업로드할 이미지 파일(file)이 필요합니다").await?;
+
+    // 변환은 트랜잭션 밖에서 먼저 하고, 저장과 사용자 갱신만 같은 사진의 잠금 안에서 처리
+    let avatar = AvatarService::render(data).await?;
+    let avatar_service = AvatarService::new(state.blob_store.clone());
+    let mut tx = state.db_pool.begin().await?;
+    let hash = avatar_service.save(&mut tx, avatar).await?;
+    let user = UserService::new(state.db_pool.clone())
+        .set_avatar(&mut *tx, user_id, Some(&hash))
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+    tx.commit().await?;
+
+    if let Some(previous) = before.avatar_hash.as_deref().filter(|previous| *previous != hash) {
+        delete_if_unused(&state, previous).await;
+    }
+
+    AuditService::new(state.db_pool.clone())
+        .record(
+            NewAuditEvent::new("user.avatar.update", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(json!({ "avatar_hash": { "before": before.avatar_hash, "after": hash } })),
+        )
+        .await;
+
+    let mut responses = with_profiles(&state, &claims, vec![user]).await?;
+    Ok(Json(responses.remove(0)))
+}
+
+// 프로필 사진 삭제 핸들러 (본인 또는 users.update 권한 보유자)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/users/{id}/avatar",
+    tag = "users",
+    summary = "프로필 사진 삭제",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "수정 권한 없음", body = ErrorResponse),
+        (status = 404, description = "사용자 또는 프로필 사진 없음 (avatar_not_found)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_avatar(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let before = find_editable_user(&state, &claims, user_id).await?;
+    let previous = before.avatar_hash.ok_or_else(avatar_not_found)?;
+
+    UserService::new(state.db_pool.clone())
+        .set_avatar(&state.db_pool, user_id, None)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+    delete_if_unused(&state, &previous).await;
+
+    AuditService::new(state.db_pool.clone())
+        .record(
+            NewAuditEvent::new("user.avatar.delete", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(json!({ "avatar_hash": { "before": previous, "after": null } })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 프로필 사진 조회 핸들러 (인증 없이 <img>에서 사용)
+// 주소가 내용 해시라 바뀌지 않으므로 오래 캐시하도록 응답
+#[utoipa::path(
+    get,
+    path = "/api/v1/avatars/{hash}/{size}",
+    tag = "users",
+    summary = "프로필 사진 조회",
+    params(
+        ("hash" = String, Path, description = "원본 이미지의 SHA-256 (avatar_url에 포함)"),
+        ("size" = u32, Path, description = "크기 (64, 128, 256)"),
+    ),
+    responses(
+        (status = 200, description = "PNG 이미지", content_type = "image/png"),
+        (status = 304, description = "If-None-Match와 같은 이미지"),
+        (status = 404, description = "없는 프로필 사진 (avatar_not_found)", body = ErrorResponse),
+    )
+)]
+pub async fn get_avatar(
+    State(state): State<AppState>,
+    Path((hash, size)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    let size: u32 = size.parse().map_err(|_| avatar_not_found())?;
+    let valid_hash = hash.len() == 64 && hash.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'));
+    if !valid_hash || !AVATAR_SIZES.contains(&size) {
+        return Err(avatar_not_found());
+    }
+
+    let etag = format!("\"{}-{}\"", hash, size);
+    let cache_headers = [
+        (header::ETAG, etag.clone()),
+        (header::CACHE_CONTROL, "public, max-age=31536000, immutable".to_string()),
+    ];
+    if headers.get(header::IF_NONE_MATCH).and_then(|value| value.to_str().ok()) == Some(etag.as_str()) {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+
+    let data = AvatarService::new(state.blob_store)
+        .get(&hash, size)
+        .await?
+        .ok_or_else(avatar_not_found)?;
+
+    Ok((
+        [(header::CONTENT_TYPE, HeaderValue::from_static("image/png"))],
+        cache_headers,
+        data,
+    )
+        .into_response())
+}
+
+// 프로필 사진을 바꿀 수 있는 사용자인지 확인 후 대상 사용자 조회
+// 본인 프로필 사진은 허용, 다른 사용자는 users.update 권한 필요
+async fn find_editable_user(state: &AppState, claims: &Claims, user_id: i32) -> AppResult<User> {
+    let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
+    if current_user_id != user_id
+        && !RoleService::new(state.db_pool.clone())
+            .has_permission(current_user_id, UsersUpdate::CODE)
+            .await?
+    {
+        return Err(AppError::forbidden("permission_denied", "수정 권한이 없습니다"));
+    }
+
+    UserService::new(state.db_pool.clone())
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)
+}
+
+// 더 이상 쓰는 사용자가 없으면 프로필 사진 파일 삭제 (실패는 로그로만 남김)
+async fn delete_if_unused(state: &AppState, hash: &str) {
+    let result = AvatarService::new(state.blob_store.clone())
+        .delete_if_unused(&state.db_pool, hash)
+        .await;
+
+    if let Err(err) = result {
+        tracing::error!("프로필 사진 삭제 실패 ({}): {}", hash, err);
+    }
+}
+
+fn avatar_not_found() -> AppError {
+    AppError::not_found("avatar_not_found", "프로필 사진을 찾을 수 없습니다")
+}
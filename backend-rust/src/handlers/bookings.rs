@@ -0,0 +1,522 @@
+use axum::{
+    extract::{OriginalUri, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json},
+    Extension,
+};
+use serde_json::json;
+use validator::Validate;
+
+use super::pagination_headers;
+use crate::{
+    error::{AppError, AppResult},
+    middleware::{PermissionCode, RequirePermission, ResourcesManage, ValidatedJson},
+    models::{
+        Booking, BookingListQuery, CancelBookingQuery, Claims, ClientInfo, CreateBookingRequest, CreateBookingResponse,
+        CreateResourceRequest, ErrorResponse, NewAuditEvent, Resource, ResourceAvailability, ResourceAvailabilityQuery,
+        ResourceListQuery, UpdateBookingRequest, UpdateResourceRequest,
+    },
+    services::{AuditService, BookingService, RoleService},
+    AppState,
+};
+
+// 예약 가능 여부 최대 조회 기간 (일)
+const MAX_AVAILABILITY_DAYS: i64 = 31;
+
+// 자원 관리자(resources.manage 권한 보유자)인지 확인
+async fn is_resource_manager(state: &AppState, user_id: i32) -> AppResult<bool> {
+    Ok(RoleService::new(state.db_pool.clone())
+        .has_permission(user_id, ResourcesManage::CODE)
+        .await?)
+}
+
+// 예약 자원 목록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/resources",
+    tag = "bookings",
+    summary = "예약 자원 목록 조회",
+    params(ResourceListQuery),
+    responses(
+        (status = 200, description = "예약 자원 목록 (종류, 이름 순)", body = Vec<Resource>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "include_inactive는 resources.manage 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_resources(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<ResourceListQuery>,
+) -> AppResult<Json<Vec<Resource>>> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    if query.include_inactive && !is_resource_manager(&state, viewer_id).await? {
+        return Err(AppError::forbidden(
+            "permission_denied",
+            format!("사용 중지된 자원 조회에는 '{}' 권한이 필요합니다", ResourcesManage::CODE),
+        ));
+    }
+
+    let booking_service = BookingService::new(state.db_pool);
+    Ok(Json(booking_service.find_resources(&query).await?))
+}
+
+// 예약 가능 여부 조회 핸들러 (사용 중인 자원별로 기간과 겹치는 예약 시간대)
+#[utoipa::path(
+    get,
+    path = "/api/v1/resources/availability",
+    tag = "bookings",
+    summary = "예약 가능 여부 조회",
+    params(ResourceAvailabilityQuery),
+    responses(
+        (status = 200, description = "자원별 예약 가능 여부와 예약된 시간대", body = Vec<ResourceAvailability>),
+        (status = 400, description = "유효성 검사 실패 (기간)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_resource_availability(
+    State(state): State<AppState>,
+    Query(query): Query<ResourceAvailabilityQuery>,
+) -> AppResult<Json<Vec<ResourceAvailability>>> {
+    if query.to <= query.from {
+        return Err(AppError::invalid_field("to", "invalid_range", "종료 시각은 시작 시각 이후여야 합니다"));
+    }
+    if (query.to - query.from).num_days() >= MAX_AVAILABILITY_DAYS {
+        return Err(AppError::invalid_field(
+            "to",
+            "invalid_range",
+            format!("조회 기간은 {}일 미만이어야 합니다", MAX_AVAILABILITY_DAYS),
+        ));
+    }
+
+    let booking_service = BookingService::new(state.db_pool);
+    Ok(Json(booking_service.availability(&query).await?))
+}
+
+// 예약 자원 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/resources/{id}",
+    tag = "bookings",
+    summary = "예약 자원 조회",
+    params(("id" = i32, Path, description = "자원 ID")),
+    responses(
+        (status = 200, description = "예약 자원", body = Resource),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "자원 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_resource(
+    State(state): State<AppState>,
+    Path(resource_id): Path<i32>,
+) -> AppResult<Json<Resource>> {
+    let booking_service = BookingService::new(state.db_pool);
+    let resource = booking_service
+        .find_resource(resource_id)
+        .await?
+        .ok_or_else(resource_not_found)?;
+
+    Ok(Json(resource))
+}
+
+// 예약 자원 생성 핸들러
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/resources",
+    tag = "bookings",
+    summary = "예약 자원 생성",
+    request_body = CreateResourceRequest,
+    responses(
+        (status = 201, description = "생성된 자원", body = Resource),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "resources.manage 권한 필요", body = ErrorResponse),
+        (status = 409, description = "이미 존재하는 이름", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_resource(
+    State(state): State<AppState>,
+    RequirePermission(claims, _): RequirePermission<ResourcesManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<CreateResourceRequest>,
+) -> AppResult<impl IntoResponse> {
+    let resource = BookingService::new(state.db_pool.clone())
+        .create_resource(&request)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("resource.create", &client)
+                .actor_claims(&claims)
+                .target("resource", resource.id)
+                .changes(json!({
+                    "name": resource.name,
+                    "kind": resource.kind,
+                    "capacity": resource.capacity,
+                    "location": resource.location,
+                    "requires_check_in": resource.requires_check_in,
+                })),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(resource)))
+}
+
+// 예약 자원 수정 핸들러 (사용 중지하면 새 예약과 예약 가능 여부 조회에서 제외, 기존 예약은 유지)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/resources/{id}",
+    tag = "bookings",
+    summary = "예약 자원 수정",
+    params(("id" = i32, Path, description = "자원 ID")),
+    request_body = UpdateResourceRequest,
+    responses(
+        (status = 200, description = "수정된 자원", body = Resource),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "resources.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "자원 없음", body = ErrorResponse),
+        (status = 409, description = "이미 존재하는 이름", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_resource(
+    State(state): State<AppState>,
+    Path(resource_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<ResourcesManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateResourceRequest>,
+) -> AppResult<Json<Resource>> {
+    let booking_service = BookingService::new(state.db_pool.clone());
+
+    // 감사 로그용 변경 전 상태 조회
+    let before = booking_service
+        .find_resource(resource_id)
+        .await?
+        .ok_or_else(resource_not_found)?;
+    let resource = booking_service
+        .update_resource(resource_id, &request)
+        .await?
+        .ok_or_else(resource_not_found)?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("resource.update", &client)
+                .actor_claims(&claims)
+                .target("resource", resource.id)
+                .changes(json!({
+                    "name": { "before": before.name, "after": resource.name },
+                    "kind": { "before": before.kind, "after": resource.kind },
+                    "capacity": { "before": before.capacity, "after": resource.capacity },
+                    "location": { "before": before.location, "after": resource.location },
+                    "requires_check_in": { "before": before.requires_check_in, "after": resource.requires_check_in },
+                    "is_active": { "before": before.is_active, "after": resource.is_active },
+                })),
+        )
+        .await;
+
+    Ok(Json(resource))
+}
+
+// 예약 자원 삭제 핸들러 (끝나지 않은 예약이 있으면 거부, 지난 예약은 함께 삭제)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/resources/{id}",
+    tag = "bookings",
+    summary = "예약 자원 삭제",
+    params(("id" = i32, Path, description = "자원 ID")),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "resources.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "자원 없음", body = ErrorResponse),
+        (status = 409, description = "끝나지 않은 예약이 있음 (resource_in_use)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_resource(
+    State(state): State<AppState>,
+    Path(resource_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<ResourcesManage>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let booking_service = BookingService::new(state.db_pool.clone());
+
+    let resource = booking_service
+        .find_resource(resource_id)
+        .await?
+        .ok_or_else(resource_not_found)?;
+    if booking_service.has_upcoming_bookings(resource_id).await? {
+        return Err(AppError::conflict(
+            "resource_in_use",
+            "끝나지 않은 예약이 있는 자원은 삭제할 수 없습니다 (사용 중지 후 예약을 정리하세요)",
+        ));
+    }
+    if !booking_service.delete_resource(resource_id).await? {
+        return Err(resource_not_found());
+    }
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("resource.delete", &client)
+                .actor_claims(&claims)
+                .target("resource", resource_id)
+                .changes(json!({ "name": resource.name, "kind": resource.kind })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 예약 핸들러 (반복 규칙이 있으면 모든 회차를 한 번에 예약)
+#[utoipa::path(
+    post,
+    path = "/api/v1/bookings",
+    tag = "bookings",
+    summary = "자원 예약",
+    request_body = CreateBookingRequest,
+    responses(
+        (status = 201, description = "예약된 회차 (시작 순)", body = CreateBookingResponse),
+        (status = 400, description = "유효성 검사 실패 (invalid_range, invalid_resource, invalid_recurrence)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 409, description = "기존 예약과 시간이 겹침 (booking_conflict)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_booking(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<CreateBookingRequest>,
+) -> AppResult<impl IntoResponse> {
+    let user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let (series_id, bookings) = BookingService::new(state.db_pool.clone())
+        .create(user_id, &request)
+        .await?;
+
+    let audit_service = AuditService::new(state.db_pool);
+    for booking in &bookings {
+        audit_service
+            .record(
+                NewAuditEvent::new("booking.create", &client)
+                    .actor_claims(&claims)
+                    .target("booking", booking.id)
+                    .changes(json!({
+                        "resource_id": booking.resource_id,
+                        "title": booking.title,
+                        "starts_at": booking.starts_at,
+                        "ends_at": booking.ends_at,
+                        "series_id": booking.series_id,
+                    })),
+            )
+            .await;
+    }
+
+    Ok((StatusCode::CREATED, Json(CreateBookingResponse { series_id, bookings })))
+}
+
+// 예약 목록 조회 핸들러 (시작 시각 순)
+#[utoipa::path(
+    get,
+    path = "/api/v1/bookings",
+    tag = "bookings",
+    summary = "예약 목록 조회",
+    params(BookingListQuery),
+    responses(
+        (status = 200, description = "예약 목록 (페이지 정보는 X-Total-Count, Link 헤더)", body = Vec<Booking>),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_bookings(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<BookingListQuery>,
+) -> AppResult<impl IntoResponse> {
+    query.validate()?;
+
+    let (bookings, total) = BookingService::new(state.db_pool)
+        .find_page(&query)
+        .await?;
+
+    let headers = pagination_headers(uri.path(), &query, query.page, query.per_page, total);
+    Ok((StatusCode::OK, headers, Json(bookings)))
+}
+
+// 예약 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/bookings/{id}",
+    tag = "bookings",
+    summary = "예약 조회",
+    params(("id" = i32, Path, description = "예약 ID")),
+    responses(
+        (status = 200, description = "예약", body = Booking),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "예약 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_booking(
+    State(state): State<AppState>,
+    Path(booking_id): Path<i32>,
+) -> AppResult<Json<Booking>> {
+    let booking_service = BookingService::new(state.db_pool);
+    let booking = booking_service
+        .find_by_id(booking_id)
+        .await?
+        .ok_or_else(booking_not_found)?;
+
+    Ok(Json(booking))
+}
+
+// 예약 변경 핸들러 (한 회차만, 예약자나 resources.manage 권한 보유자가 시작 전에만 가능)
+#[utoipa::path(
+    put,
+    path = "/api/v1/bookings/{id}",
+    tag = "bookings",
+    summary = "예약 변경",
+    params(("id" = i32, Path, description = "예약 ID")),
+    request_body = UpdateBookingRequest,
+    responses(
+        (status = 200, description = "변경된 예약", body = Booking),
+        (status = 400, description = "유효성 검사 실패 (invalid_range, invalid_resource)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "본인 예약이 아님 (not_booking_owner)", body = ErrorResponse),
+        (status = 404, description = "예약 없음", body = ErrorResponse),
+        (status = 409, description = "시간 겹침 (booking_conflict), 변경할 수 없는 상태 (invalid_transition, booking_already_started)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_booking(
+    State(state): State<AppState>,
+    Path(booking_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateBookingRequest>,
+) -> AppResult<Json<Booking>> {
+    let actor_id: i32 = claims.sub.parse().unwrap_or(0);
+    let is_manager = is_resource_manager(&state, actor_id).await?;
+    let booking_service = BookingService::new(state.db_pool.clone());
+
+    // 감사 로그용 변경 전 상태 조회
+    let before = booking_service
+        .find_by_id(booking_id)
+        .await?
+        .ok_or_else(booking_not_found)?;
+    let booking = booking_service
+        .update(booking_id, actor_id, is_manager, &request)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("booking.update", &client)
+                .actor_claims(&claims)
+                .target("booking", booking.id)
+                .changes(json!({
+                    "resource_id": { "before": before.resource_id, "after": booking.resource_id },
+                    "title": { "before": before.title, "after": booking.title },
+                    "starts_at": { "before": before.starts_at, "after": booking.starts_at },
+                    "ends_at": { "before": before.ends_at, "after": booking.ends_at },
+                })),
+        )
+        .await;
+
+    Ok(Json(booking))
+}
+
+// 예약 취소 핸들러 (예약자나 resources.manage 권한 보유자, series면 이후 회차도 함께 취소)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/bookings/{id}",
+    tag = "bookings",
+    summary = "예약 취소",
+    params(("id" = i32, Path, description = "예약 ID"), CancelBookingQuery),
+    responses(
+        (status = 204, description = "취소 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "본인 예약이 아님 (not_booking_owner)", body = ErrorResponse),
+        (status = 404, description = "예약 없음", body = ErrorResponse),
+        (status = 409, description = "취소할 수 없는 상태 (invalid_transition)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn cancel_booking(
+    State(state): State<AppState>,
+    Path(booking_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    Query(query): Query<CancelBookingQuery>,
+) -> AppResult<StatusCode> {
+    let actor_id: i32 = claims.sub.parse().unwrap_or(0);
+    let is_manager = is_resource_manager(&state, actor_id).await?;
+
+    let cancelled = BookingService::new(state.db_pool.clone())
+        .cancel(booking_id, actor_id, is_manager, query.series)
+        .await?;
+
+    let audit_service = AuditService::new(state.db_pool);
+    for id in cancelled {
+        audit_service
+            .record(
+                NewAuditEvent::new("booking.cancel", &client)
+                    .actor_claims(&claims)
+                    .target("booking", id)
+                    .changes(json!({ "series": query.series })),
+            )
+            .await;
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 체크인 핸들러 (예약자만, 시작 10분 전부터 시작 후 BOOKING_CHECK_IN_GRACE_MINUTES분까지)
+#[utoipa::path(
+    post,
+    path = "/api/v1/bookings/{id}/check-in",
+    tag = "bookings",
+    summary = "예약 체크인",
+    params(("id" = i32, Path, description = "예약 ID")),
+    responses(
+        (status = 200, description = "체크인한 예약", body = Booking),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "본인 예약이 아님 (not_booking_owner)", body = ErrorResponse),
+        (status = 404, description = "예약 없음", body = ErrorResponse),
+        (status = 409, description = "체크인 가능 시간이 아님 (check_in_not_open, check_in_closed) 또는 확정 상태가 아님 (invalid_transition)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn check_in_booking(
+    State(state): State<AppState>,
+    Path(booking_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+) -> AppResult<Json<Booking>> {
+    let actor_id: i32 = claims.sub.parse().unwrap_or(0);
+    let booking = BookingService::new(state.db_pool.clone())
+        .check_in(booking_id, actor_id, state.config.booking_check_in_grace_minutes)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("booking.check_in", &client)
+                .actor_claims(&claims)
+                .target("booking", booking.id)
+                .changes(json!({ "resource_id": booking.resource_id, "checked_in_at": booking.checked_in_at })),
+        )
+        .await;
+
+    Ok(Json(booking))
+}
+
+fn resource_not_found() -> AppError {
+    AppError::not_found("resource_not_found", "자원을 찾을 수 없습니다")
+}
+
+fn booking_not_found() -> AppError {
+    AppError::not_found("booking_not_found", "예약을 찾을 수 없습니다")
+}
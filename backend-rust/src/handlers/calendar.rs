@@ -0,0 +1,437 @@
+use axum::{
+    extract::{multipart::MultipartRejection, Multipart, Path, Query, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use chrono::{DateTime, Timelike, Utc};
+use serde_json::json;
+
+use super::read_file_field;
+use crate::{
+    error::{AppError, AppResult},
+    ical,
+    middleware::{CalendarManage, RequirePermission, ValidatedJson},
+    models::{
+        calendar_feed_url, CalendarEvent, CalendarEventKind, CalendarEventQuery, CalendarFeed, CalendarFeedResponse,
+        CalendarImportQuery, CalendarImportRequest, CalendarImportResponse, Claims, ClientInfo,
+        CreateCalendarEventRequest, ErrorResponse, NewAuditEvent, UpdateCalendarEventRequest,
+    },
+    services::{AuditService, CalendarService},
+    AppState,
+};
+
+// 회사 일정 최대 조회 기간 (일)
+const MAX_EVENT_RANGE_DAYS: i64 = 366;
+
+// 구독 피드의 달력 이름 (X-WR-CALNAME)
+const FEED_CALENDAR_NAME: &str = "사내 인트라넷";
+
+// 회사 일정 조회 핸들러 (기간과 겹치는 일정)
+#[utoipa::path(
+    get,
+    path = "/api/v1/calendar/events",
+    tag = "calendar",
+    summary = "회사 일정 조회",
+    params(CalendarEventQuery),
+    responses(
+        (status = 200, description = "기간과 겹치는 회사 일정과 공휴일 (시작 시각 순)", body = Vec<CalendarEvent>),
+        (status = 400, description = "조회 기간이 잘못됨 (invalid_range)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_calendar_events(
+    State(state): State<AppState>,
+    Query(query): Query<CalendarEventQuery>,
+) -> AppResult<Json<Vec<CalendarEvent>>> {
+    if query.to <= query.from {
+        return Err(AppError::invalid_field("to", "invalid_range", "종료 시각은 시작 시각 이후여야 합니다"));
+    }
+    if (query.to - query.from).num_days() > MAX_EVENT_RANGE_DAYS {
+        return Err(AppError::invalid_field(
+            "to",
+            "invalid_range",
+            format!("조회 기간은 {}일 이하여야 합니다", MAX_EVENT_RANGE_DAYS),
+        ));
+    }
+
+    let calendar_service = CalendarService::new(state.db_pool);
+    Ok(Json(calendar_service.find_events(&query).await?))
+}
+
+// 회사 일정 등록 핸들러
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/calendar/events",
+    tag = "calendar",
+    summary = "회사 일정 등록",
+    request_body = CreateCalendarEventRequest,
+    responses(
+        (status = 201, description = "등록된 일정", body = CalendarEvent),
+        (status = 400, description = "유효성 검사 실패, 종료 시각이 시작 시각 이전, 하루 종일 일정이 UTC 자정이 아님", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "calendar.manage 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_calendar_event(
+    State(state): State<AppState>,
+    RequirePermission(claims, _): RequirePermission<CalendarManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<CreateCalendarEventRequest>,
+) -> AppResult<impl IntoResponse> {
+    check_event_times(request.starts_at, request.ends_at, request.all_day)?;
+
+    let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let event = CalendarService::new(state.db_pool.clone())
+        .create_event(current_user_id, &request)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("calendar.event.create", &client)
+                .actor_claims(&claims)
+                .target("calendar_event", event.id)
+                .changes(json!({
+                    "kind": event.kind,
+                    "title": event.title,
+                    "starts_at": event.starts_at,
+                    "ends_at": event.ends_at,
+                    "all_day": event.all_day,
+                })),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(event)))
+}
+
+// 회사 일정 수정 핸들러 (가져온 일정도 수정할 수 있지만 같은 파일을 다시 가져오면 덮어씀)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/calendar/events/{id}",
+    tag = "calendar",
+    summary = "회사 일정 수정",
+    params(("id" = i32, Path, description = "일정 ID")),
+    request_body = UpdateCalendarEventRequest,
+    responses(
+        (status = 200, description = "수정된 일정", body = CalendarEvent),
+        (status = 400, description = "유효성 검사 실패, 종료 시각이 시작 시각 이전, 하루 종일 일정이 UTC 자정이 아님", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "calendar.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "일정 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_calendar_event(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<CalendarManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateCalendarEventRequest>,
+) -> AppResult<Json<CalendarEvent>> {
+    let calendar_service = CalendarService::new(state.db_pool.clone());
+
+    // 생략한 시각은 기존 값과 합쳐 확인 (감사 로그용 변경 전 상태)
+    let before = calendar_service
+        .find_event(event_id)
+        .await?
+        .ok_or_else(calendar_event_not_found)?;
+    check_event_times(
+        request.starts_at.unwrap_or(before.starts_at),
+        request.ends_at.unwrap_or(before.ends_at),
+        request.all_day.unwrap_or(before.all_day),
+    )?;
+    let event = calendar_service
+        .update_event(event_id, &request)
+        .await?
+        .ok_or_else(calendar_event_not_found)?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("calendar.event.update", &client)
+                .actor_claims(&claims)
+                .target("calendar_event", event.id)
+                .changes(json!({
+                    "kind": { "before": before.kind, "after": event.kind },
+                    "title": { "before": before.title, "after": event.title },
+                    "location": { "before": before.location, "after": event.location },
+                    "starts_at": { "before": before.starts_at, "after": event.starts_at },
+                    "ends_at": { "before": before.ends_at, "after": event.ends_at },
+                    "all_day": { "before": before.all_day, "after": event.all_day },
+                })),
+        )
+        .await;
+
+    Ok(Json(event))
+}
+
+// 회사 일정 삭제 핸들러
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/calendar/events/{id}",
+    tag = "calendar",
+    summary = "회사 일정 삭제",
+    params(("id" = i32, Path, description = "일정 ID")),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "calendar.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "일정 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_calendar_event(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<CalendarManage>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let calendar_service = CalendarService::new(state.db_pool.clone());
+
+    let event = calendar_service
+        .find_event(event_id)
+        .await?
+        .ok_or_else(calendar_event_not_found)?;
+    if !calendar_service.delete_event(event_id).await? {
+        return Err(calendar_event_not_found());
+    }
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("calendar.event.delete", &client)
+                .actor_claims(&claims)
+                .target("calendar_event", event_id)
+                .changes(json!({ "kind": event.kind, "title": event.title, "source_uid": event.source_uid })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// .ics 파일 가져오기 핸들러 (공휴일 달력 등)
+// multipart/form-data의 file 필드로 받은 VCALENDAR의 VEVENT를 UID 기준으로 추가/갱신
+// 반복 일정, 취소된 일정, 시각을 해석할 수 없는 일정은 제외하고 사유를 응답에 포함
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/calendar/import",
+    tag = "calendar",
+    summary = ".ics 일정 가져오기",
+    params(CalendarImportQuery),
+    request_body(content = CalendarImportRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "추가/갱신/제외한 일정 수", body = CalendarImportResponse),
+        (status = 400, description = "file 필드 없음, 읽을 수 없는 iCalendar 파일 (invalid_ical)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "calendar.manage 권한 필요", body = ErrorResponse),
+        (status = 413, description = "CALENDAR_IMPORT_MAX_BYTES 초과", body = ErrorResponse),
+        (status = 415, description = "multipart 요청이 아님", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn import_calendar_events(
+    State(state): State<AppState>,
+    RequirePermission(claims, _): RequirePermission<CalendarManage>,
+    client: ClientInfo,
+    Query(query): Query<CalendarImportQuery>,
+    multipart: Result<Multipart, MultipartRejection>,
+) -> AppResult<Json<CalendarImportResponse>> {
+    let multipart = multipart.map_err(|_| {
+        AppError::unsupported_file("unsupported_media_type", "Content-Type이 multipart/form-data여야 합니다")
+    })?;
+    let data = read_file_field(
+        multipart,
+        state.config.calendar_import_max_bytes,
+        "가져올 iCalendar 파일(file)이 필요합니다",
+    )
+    .await?;
+    let text = String::from_utf8(data)
+        .map_err(|_| AppError::invalid_field("file", "invalid_ical", "iCalendar 파일은 UTF-8이어야 합니다"))?;
+    let parsed = ical::parse_calendar(&text)?;
+
+    let kind = query.kind.unwrap_or(CalendarEventKind::Holiday);
+    let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let result = CalendarService::new(state.db_pool.clone())
+        .import(current_user_id, kind, parsed)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("calendar.event.import", &client)
+                .actor_claims(&claims)
+                .changes(json!({
+                    "kind": kind,
+                    "created": result.created,
+                    "updated": result.updated,
+                    "skipped": result.skipped.len(),
+                })),
+        )
+        .await;
+
+    Ok(Json(result))
+}
+
+// 달력 구독 정보 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/calendar/feed",
+    tag = "calendar",
+    summary = "내 달력 구독 정보",
+    responses(
+        (status = 200, description = "구독 발급/마지막 접근 시각 (토큰은 발급할 때만 확인 가능)", body = CalendarFeed),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "발급한 구독 없음 (calendar_feed_not_found)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_calendar_feed(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<CalendarFeed>> {
+    let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let feed = CalendarService::new(state.db_pool)
+        .find_feed(current_user_id)
+        .await?
+        .ok_or_else(calendar_feed_not_found)?;
+
+    Ok(Json(feed))
+}
+
+// 달력 구독 발급 핸들러 (이미 있으면 새 주소로 교체)
+#[utoipa::path(
+    post,
+    path = "/api/v1/calendar/feed",
+    tag = "calendar",
+    summary = "내 달력 구독 주소 발급",
+    responses(
+        (status = 201, description = "구독 토큰과 주소 (이 응답에서만 확인 가능)", body = CalendarFeedResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn issue_calendar_feed(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+) -> AppResult<impl IntoResponse> {
+    let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let (token, feed) = CalendarService::new(state.db_pool.clone())
+        .issue_feed(current_user_id)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("calendar.feed.issue", &client)
+                .actor_claims(&claims)
+                .target("user", current_user_id),
+        )
+        .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CalendarFeedResponse {
+            url: calendar_feed_url(&token),
+            token,
+            created_at: feed.created_at,
+        }),
+    ))
+}
+
+// 달력 구독 해지 핸들러 (구독 주소가 더 이상 동작하지 않음)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/calendar/feed",
+    tag = "calendar",
+    summary = "내 달력 구독 해지",
+    responses(
+        (status = 204, description = "해지 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "발급한 구독 없음 (calendar_feed_not_found)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn revoke_calendar_feed(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
+    if !CalendarService::new(state.db_pool.clone())
+        .revoke_feed(current_user_id)
+        .await?
+    {
+        return Err(calendar_feed_not_found());
+    }
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("calendar.feed.revoke", &client)
+                .actor_claims(&claims)
+                .target("user", current_user_id),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 달력 구독 피드 핸들러 (인증 없이 캘린더 앱에서 주기적으로 조회)
+// 회사 일정/공휴일, 본인 휴가(승인 대기는 TENTATIVE)와 예약, 소속 팀의 승인된 휴가
+#[utoipa::path(
+    get,
+    path = "/api/v1/calendar/{token}",
+    tag = "calendar",
+    summary = "달력 구독 피드 (iCalendar)",
+    params(("token" = String, Path, description = "구독 토큰 뒤에 .ics (발급 응답의 url)")),
+    responses(
+        (status = 200, description = "RFC 5545 VCALENDAR", content_type = "text/calendar"),
+        (status = 404, description = "없거나 해지된 구독 (calendar_feed_not_found)", body = ErrorResponse),
+    )
+)]
+pub async fn get_calendar_ics(State(state): State<AppState>, Path(file): Path<String>) -> AppResult<Response> {
+    let token = file.strip_suffix(".ics").ok_or_else(calendar_feed_not_found)?;
+
+    let calendar_service = CalendarService::new(state.db_pool);
+    let user_id = calendar_service
+        .find_feed_user(token)
+        .await?
+        .ok_or_else(calendar_feed_not_found)?;
+    let now = Utc::now();
+    let events = calendar_service.feed_events(user_id, now).await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static("text/calendar; charset=utf-8")),
+            (header::CONTENT_DISPOSITION, HeaderValue::from_static("inline; filename=\"calendar.ics\"")),
+            (header::CACHE_CONTROL, HeaderValue::from_static("private, max-age=300")),
+        ],
+        ical::write_calendar(FEED_CALENDAR_NAME, &events, now),
+    )
+        .into_response())
+}
+
+// 일정 시각 확인 (종료 시각은 시작 시각 이후, 하루 종일 일정은 UTC 자정)
+fn check_event_times(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>, all_day: bool) -> AppResult<()> {
+    if ends_at <= starts_at {
+        return Err(AppError::invalid_field("ends_at", "invalid_range", "종료 시각은 시작 시각 이후여야 합니다"));
+    }
+    if all_day {
+        for (field, time) in [("starts_at", starts_at), ("ends_at", ends_at)] {
+            if time.num_seconds_from_midnight() != 0 || time.nanosecond() != 0 {
+                return Err(AppError::invalid_field(
+                    field,
+                    "invalid_all_day",
+                    "하루 종일 일정의 시각은 UTC 자정(00:00:00Z)이어야 합니다",
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn calendar_event_not_found() -> AppError {
+    AppError::not_found("calendar_event_not_found", "일정을 찾을 수 없습니다")
+}
+
+fn calendar_feed_not_found() -> AppError {
+    AppError::not_found("calendar_feed_not_found", "달력 구독을 찾을 수 없습니다")
+}
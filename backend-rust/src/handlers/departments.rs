@@ -0,0 +1,417 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json},
+};
+use serde_json::json;
+
+use crate::{
+    error::{AppError, AppResult},
+    middleware::{DepartmentsManage, RequirePermission, ValidatedJson},
+    models::{
+        ClientInfo, CreateDepartmentRequest, DepartmentDetailResponse, DepartmentMemberResponse,
+        DepartmentResponse, ErrorResponse, NewAuditEvent, OrgChartNode, OrgChartQuery,
+        SetDepartmentMemberRequest, UpdateDepartmentRequest,
+    },
+    services::{AuditService, DepartmentService, UserService},
+    AppState,
+};
+
+// 부서 목록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/departments",
+    tag = "departments",
+    summary = "부서 목록 조회",
+    responses(
+        (status = 200, description = "부서 목록 (상위 부서, 정렬 순서, 이름 순)", body = Vec<DepartmentResponse>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_departments(State(state): State<AppState>) -> AppResult<Json<Vec<DepartmentResponse>>> {
+    let department_service = DepartmentService::new(state.db_pool);
+
+    Ok(Json(department_service.find_all().await?))
+}
+
+// 부서 상세 조회 핸들러 (소속 사용자 포함)
+#[utoipa::path(
+    get,
+    path = "/api/v1/departments/{id}",
+    tag = "departments",
+    summary = "부서 상세 조회",
+    params(("id" = i32, Path, description = "부서 ID")),
+    responses(
+        (status = 200, description = "부서와 소속 사용자", body = DepartmentDetailResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "부서 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_department(
+    State(state): State<AppState>,
+    Path(department_id): Path<i32>,
+) -> AppResult<Json<DepartmentDetailResponse>> {
+    let department_service = DepartmentService::new(state.db_pool);
+
+    let department = department_service
+        .find_by_id(department_id)
+        .await?
+        .ok_or_else(department_not_found)?;
+    let members = department_service.members(department_id).await?;
+
+    Ok(Json(DepartmentDetailResponse { department, members }))
+}
+
+// 조직도 조회 핸들러
+// root_id를 지정하면 해당 부서 아래 트리만, 없으면 최상위 부서 전체
+#[utoipa::path(
+    get,
+    path = "/api/v1/org-chart",
+    tag = "departments",
+    summary = "조직도 조회",
+    params(OrgChartQuery),
+    responses(
+        (status = 200, description = "부서 트리 (부서장, 소속 사용자, 하위 부서)", body = Vec<OrgChartNode>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "root_id 부서 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_org_chart(
+    State(state): State<AppState>,
+    Query(query): Query<OrgChartQuery>,
+) -> AppResult<Json<Vec<OrgChartNode>>> {
+    let department_service = DepartmentService::new(state.db_pool);
+
+    let chart = department_service.org_chart(query.root_id).await?;
+    if query.root_id.is_some() && chart.is_empty() {
+        return Err(department_not_found());
+    }
+
+    Ok(Json(chart))
+}
+
+// 부서 생성 핸들러
+// 같은 상위 부서 아래 이름 중복은 409 department_exists
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/departments",
+    tag = "departments",
+    summary = "부서 생성",
+    request_body = CreateDepartmentRequest,
+    responses(
+        (status = 201, description = "생성된 부서", body = DepartmentResponse),
+        (status = 400, description = "유효성 검사 실패 (invalid_parent, invalid_head)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "departments.manage 권한 필요", body = ErrorResponse),
+        (status = 409, description = "같은 상위 부서에 이미 존재하는 이름 (department_exists)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_department(
+    State(state): State<AppState>,
+    RequirePermission(claims, _): RequirePermission<DepartmentsManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<CreateDepartmentRequest>,
+) -> AppResult<impl IntoResponse> {
+    let department_service = DepartmentService::new(state.db_pool.clone());
+
+    if let Some(parent_id) = request.parent_id {
+        check_parent_exists(&department_service, parent_id).await?;
+    }
+    if let Some(head_user_id) = request.head_user_id {
+        check_user_exists(&state, head_user_id, "head_user_id", "invalid_head").await?;
+    }
+
+    let department = department_service.create(&request).await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("department.create", &client)
+                .actor_claims(&claims)
+                .target("department", department.id)
+                .changes(json!({
+                    "name": department.name,
+                    "parent_id": department.parent_id,
+                    "head_user_id": department.head_user_id,
+                })),
+        )
+        .await;
+
+    let response = department_service
+        .find_by_id(department.id)
+        .await?
+        .ok_or_else(department_not_found)?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+// 부서 수정 핸들러
+// 상위 부서를 자기 자신이나 하위 부서로 바꾸면 순환이 생기므로 400 invalid_parent
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/departments/{id}",
+    tag = "departments",
+    summary = "부서 수정",
+    params(("id" = i32, Path, description = "부서 ID")),
+    request_body = UpdateDepartmentRequest,
+    responses(
+        (status = 200, description = "수정된 부서", body = DepartmentResponse),
+        (status = 400, description = "유효성 검사 실패 (invalid_parent, invalid_head)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "departments.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "부서 없음", body = ErrorResponse),
+        (status = 409, description = "같은 상위 부서에 이미 존재하는 이름 (department_exists)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_department(
+    State(state): State<AppState>,
+    Path(department_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<DepartmentsManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateDepartmentRequest>,
+) -> AppResult<Json<DepartmentResponse>> {
+    let department_service = DepartmentService::new(state.db_pool.clone());
+
+    // 감사 로그용 변경 전 상태 조회
+    let before = department_service
+        .find_by_id(department_id)
+        .await?
+        .ok_or_else(department_not_found)?;
+
+    if let Some(Some(parent_id)) = request.parent_id {
+        check_parent_exists(&department_service, parent_id).await?;
+        if department_service.is_descendant(department_id, parent_id).await? {
+            return Err(AppError::invalid_field(
+                "parent_id",
+                "invalid_parent",
+                "자기 자신이나 하위 부서를 상위 부서로 지정할 수 없습니다",
+            ));
+        }
+    }
+    if let Some(Some(head_user_id)) = request.head_user_id {
+        check_user_exists(&state, head_user_id, "head_user_id", "invalid_head").await?;
+    }
+
+    let department = department_service
+        .update(department_id, &request)
+        .await?
+        .ok_or_else(department_not_found)?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("department.update", &client)
+                .actor_claims(&claims)
+                .target("department", department.id)
+                .changes(json!({
+                    "name": { "before": before.name, "after": department.name },
+                    "parent_id": { "before": before.parent_id, "after": department.parent_id },
+                    "head_user_id": { "before": before.head_user_id, "after": department.head_user_id },
+                    "sort_order": { "before": before.sort_order, "after": department.sort_order },
+                })),
+        )
+        .await;
+
+    let response = department_service
+        .find_by_id(department.id)
+        .await?
+        .ok_or_else(department_not_found)?;
+
+    Ok(Json(response))
+}
+
+// 부서 삭제 핸들러 (하위 부서나 소속 사용자가 있으면 409 department_not_empty)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/departments/{id}",
+    tag = "departments",
+    summary = "부서 삭제",
+    params(("id" = i32, Path, description = "부서 ID")),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "departments.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "부서 없음", body = ErrorResponse),
+        (status = 409, description = "하위 부서나 소속 사용자가 있음 (department_not_empty)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_department(
+    State(state): State<AppState>,
+    Path(department_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<DepartmentsManage>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let department_service = DepartmentService::new(state.db_pool.clone());
+
+    let department = department_service
+        .find_by_id(department_id)
+        .await?
+        .ok_or_else(department_not_found)?;
+    if department_service.has_dependents(department_id).await? {
+        return Err(AppError::conflict(
+            "department_not_empty",
+            "하위 부서나 소속 사용자가 있는 부서는 삭제할 수 없습니다",
+        ));
+    }
+
+    if !department_service.delete(department_id).await? {
+        return Err(department_not_found());
+    }
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("department.delete", &client)
+                .actor_claims(&claims)
+                .target("department", department_id)
+                .changes(json!({
+                    "name": department.name,
+                    "parent_id": department.parent_id,
+                })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 사용자 소속 설정 핸들러 (이미 다른 부서에 소속되어 있으면 이동)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/users/{id}/department",
+    tag = "departments",
+    summary = "사용자 소속 설정",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    request_body = SetDepartmentMemberRequest,
+    responses(
+        (status = 200, description = "설정된 소속", body = DepartmentMemberResponse),
+        (status = 400, description = "유효성 검사 실패 (invalid_department, invalid_manager)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "departments.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn set_user_department(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<DepartmentsManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<SetDepartmentMemberRequest>,
+) -> AppResult<Json<DepartmentMemberResponse>> {
+    let department_service = DepartmentService::new(state.db_pool.clone());
+
+    let user = UserService::new(state.db_pool.clone())
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    if department_service.find_by_id(request.department_id).await?.is_none() {
+        return Err(AppError::invalid_field(
+            "department_id",
+            "invalid_department",
+            "존재하지 않는 부서입니다",
+        ));
+    }
+    if let Some(manager_id) = request.manager_id {
+        if manager_id == user_id {
+            return Err(AppError::invalid_field(
+                "manager_id",
+                "invalid_manager",
+                "자기 자신을 관리자로 지정할 수 없습니다",
+            ));
+        }
+        check_user_exists(&state, manager_id, "manager_id", "invalid_manager").await?;
+    }
+
+    let before = department_service.find_member(user_id).await?;
+    let member = department_service.set_member(user_id, &request).await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("department.member.set", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(json!({ "before": before, "after": member })),
+        )
+        .await;
+
+    Ok(Json(DepartmentMemberResponse {
+        user_id,
+        department_id: member.department_id,
+        name: user.name,
+        email: user.email,
+        title: member.title,
+        manager_id: member.manager_id,
+    }))
+}
+
+// 사용자 소속 해제 핸들러
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/users/{id}/department",
+    tag = "departments",
+    summary = "사용자 소속 해제",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    responses(
+        (status = 204, description = "해제 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "departments.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "소속 없음 (department_member_not_found)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn remove_user_department(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<DepartmentsManage>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let department_service = DepartmentService::new(state.db_pool.clone());
+
+    let member = department_service
+        .find_member(user_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("department_member_not_found", "소속된 부서가 없습니다"))?;
+    department_service.remove_member(user_id).await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("department.member.remove", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(json!({ "before": member })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 상위 부서로 지정할 부서가 존재하는지 확인
+async fn check_parent_exists(department_service: &DepartmentService, parent_id: i32) -> AppResult<()> {
+    if department_service.find_by_id(parent_id).await?.is_none() {
+        return Err(AppError::invalid_field(
+            "parent_id",
+            "invalid_parent",
+            "존재하지 않는 상위 부서입니다",
+        ));
+    }
+
+    Ok(())
+}
+
+// 부서장/관리자로 지정할 사용자가 존재하는지 확인 (삭제된 사용자 제외)
+async fn check_user_exists(state: &AppState, user_id: i32, field: &str, code: &str) -> AppResult<()> {
+    let user = UserService::new(state.db_pool.clone()).find_by_id(user_id).await?;
+    if user.is_none() {
+        return Err(AppError::invalid_field(field, code, "존재하지 않는 사용자입니다"));
+    }
+
+    Ok(())
+}
+
+fn department_not_found() -> AppError {
+    AppError::not_found("department_not_found", "부서를 찾을 수 없습니다")
+}
@@ -0,0 +1,851 @@
+use axum::{
+    extract::{multipart::MultipartRejection, Multipart, Path, Query, State},
+    http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode},
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::json;
+
+use super::multipart_error;
+use crate::{
+    error::{AppError, AppResult},
+    middleware::{DocumentsManage, PermissionCode, RequirePermission, ValidatedJson},
+    models::{
+        validate_document_name, Claims, ClientInfo, CreateDocumentFolderRequest, Document, DocumentAccess,
+        DocumentDownloadQuery, DocumentFolder, DocumentFolderAclEntry, DocumentFolderQuery, DocumentQuota,
+        DocumentUploadRequest, DocumentVersion, ErrorResponse, NewAuditEvent, SetDocumentQuotaRequest,
+        UpdateDocumentFolderAclRequest, UpdateDocumentFolderRequest, UpdateDocumentRequest,
+    },
+    services::{
+        content_disposition, parse_range, AuditService, DocumentService, DocumentUpload, RangeRequest, RoleService,
+        UserService,
+    },
+    AppState,
+};
+
+// 파일 형식을 알 수 없을 때의 Content-Type
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+// 문서 이름 최대 길이 (documents.name 컬럼)
+const MAX_NAME_CHARS: usize = 255;
+
+// 버전 설명 최대 길이 (document_versions.comment 컬럼)
+const MAX_COMMENT_CHARS: usize = 500;
+
+fn document_service(state: &AppState) -> DocumentService {
+    DocumentService::new(
+        state.db_pool.clone(),
+        state.blob_store.clone(),
+        state.config.document_quota_bytes,
+    )
+}
+
+// 요청한 사용자 ID와 문서함 관리자(documents.manage 권한 보유자) 여부
+async fn document_actor(state: &AppState, claims: &Claims) -> AppResult<(i32, bool)> {
+    let actor_id: i32 = claims.sub.parse().unwrap_or(0);
+    let is_manager = RoleService::new(state.db_pool.clone())
+        .has_permission(actor_id, DocumentsManage::CODE)
+        .await?;
+    Ok((actor_id, is_manager))
+}
+
+// 폴더 목록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents/folders",
+    tag = "documents",
+    summary = "문서함 폴더 목록",
+    params(DocumentFolderQuery),
+    responses(
+        (status = 200, description = "하위 폴더 또는 접근할 수 있는 최상위/공유 폴더 (이름 순)", body = Vec<DocumentFolder>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 상위 폴더 (document_folder_not_found)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_document_folders(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<DocumentFolderQuery>,
+) -> AppResult<Json<Vec<DocumentFolder>>> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let folders = document_service(&state)
+        .find_folders(actor_id, is_manager, query.parent_id)
+        .await?;
+
+    Ok(Json(folders))
+}
+
+// 폴더 생성 핸들러 (하위 폴더는 상위 폴더의 write 권한 필요)
+#[utoipa::path(
+    post,
+    path = "/api/v1/documents/folders",
+    tag = "documents",
+    summary = "문서함 폴더 생성",
+    request_body = CreateDocumentFolderRequest,
+    responses(
+        (status = 201, description = "생성된 폴더 (만든 사용자가 소유자)", body = DocumentFolder),
+        (status = 400, description = "유효성 검사 실패 (invalid_name, invalid_parent)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "상위 폴더의 write 권한 필요 (document_access_denied)", body = ErrorResponse),
+        (status = 409, description = "같은 상위 폴더에 같은 이름 (document_folder_exists)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_document_folder(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<CreateDocumentFolderRequest>,
+) -> AppResult<impl IntoResponse> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let folder = document_service(&state)
+        .create_folder(actor_id, is_manager, &request)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("document.folder.create", &client)
+                .actor_claims(&claims)
+                .target("document_folder", folder.id)
+                .changes(json!({ "name": folder.name, "parent_id": folder.parent_id })),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(folder)))
+}
+
+// 폴더 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents/folders/{id}",
+    tag = "documents",
+    summary = "문서함 폴더 조회",
+    params(("id" = i32, Path, description = "폴더 ID")),
+    responses(
+        (status = 200, description = "폴더와 요청한 사용자의 접근 권한", body = DocumentFolder),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 폴더 (document_folder_not_found)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_document_folder(
+    State(state): State<AppState>,
+    Path(folder_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<DocumentFolder>> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let folder = document_service(&state)
+        .find_folder(actor_id, is_manager, folder_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("document_folder_not_found", "폴더를 찾을 수 없습니다"))?;
+
+    Ok(Json(folder))
+}
+
+// 폴더 수정 핸들러 (이름 변경, 이동, 폴더의 manage 권한 필요)
+#[utoipa::path(
+    put,
+    path = "/api/v1/documents/folders/{id}",
+    tag = "documents",
+    summary = "문서함 폴더 수정",
+    params(("id" = i32, Path, description = "폴더 ID")),
+    request_body = UpdateDocumentFolderRequest,
+    responses(
+        (status = 200, description = "수정된 폴더", body = DocumentFolder),
+        (status = 400, description = "유효성 검사 실패 (invalid_name, invalid_parent: 자신이나 하위 폴더 아래로 이동)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "manage/대상 폴더의 write 권한 필요 (document_access_denied, root_folder_not_allowed)", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 폴더", body = ErrorResponse),
+        (status = 409, description = "같은 상위 폴더에 같은 이름 (document_folder_exists)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_document_folder(
+    State(state): State<AppState>,
+    Path(folder_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateDocumentFolderRequest>,
+) -> AppResult<Json<DocumentFolder>> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let (before, folder) = document_service(&state)
+        .update_folder(actor_id, is_manager, folder_id, &request)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("document.folder.update", &client)
+                .actor_claims(&claims)
+                .target("document_folder", folder.id)
+                .changes(json!({
+                    "name": { "before": before.name, "after": folder.name },
+                    "parent_id": { "before": before.parent_id, "after": folder.parent_id },
+                })),
+        )
+        .await;
+
+    Ok(Json(folder))
+}
+
+// 폴더 삭제 핸들러 (빈 폴더만, 폴더의 manage 권한 필요)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/documents/folders/{id}",
+    tag = "documents",
+    summary = "문서함 폴더 삭제",
+    params(("id" = i32, Path, description = "폴더 ID")),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "폴더의 manage 권한 필요 (document_access_denied)", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 폴더", body = ErrorResponse),
+        (status = 409, description = "하위 폴더나 문서가 있음 (folder_not_empty)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_document_folder(
+    State(state): State<AppState>,
+    Path(folder_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let folder = document_service(&state)
+        .delete_folder(actor_id, is_manager, folder_id)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("document.folder.delete", &client)
+                .actor_claims(&claims)
+                .target("document_folder", folder_id)
+                .changes(json!({ "name": folder.name, "parent_id": folder.parent_id })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 폴더 접근 권한 조회 핸들러 (폴더에 직접 부여한 항목, 폴더의 manage 권한 필요)
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents/folders/{id}/acl",
+    tag = "documents",
+    summary = "문서함 폴더 접근 권한 조회",
+    params(("id" = i32, Path, description = "폴더 ID")),
+    responses(
+        (status = 200, description = "역할, 사용자 순의 접근 권한 항목", body = Vec<DocumentFolderAclEntry>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "폴더의 manage 권한 필요 (document_access_denied)", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 폴더", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_document_folder_acl(
+    State(state): State<AppState>,
+    Path(folder_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<Vec<DocumentFolderAclEntry>>> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let entries = document_service(&state)
+        .folder_acl(actor_id, is_manager, folder_id)
+        .await?;
+
+    Ok(Json(entries))
+}
+
+// 폴더 접근 권한 교체 핸들러 (하위 폴더와 문서에도 적용, 폴더의 manage 권한 필요)
+#[utoipa::path(
+    put,
+    path = "/api/v1/documents/folders/{id}/acl",
+    tag = "documents",
+    summary = "문서함 폴더 접근 권한 설정",
+    params(("id" = i32, Path, description = "폴더 ID")),
+    request_body = UpdateDocumentFolderAclRequest,
+    responses(
+        (status = 200, description = "교체한 접근 권한 항목", body = Vec<DocumentFolderAclEntry>),
+        (status = 400, description = "유효성 검사 실패 (invalid_acl_entry: 사용자/역할 중복 또는 없음)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "폴더의 manage 권한 필요 (document_access_denied)", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 폴더", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_document_folder_acl(
+    State(state): State<AppState>,
+    Path(folder_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateDocumentFolderAclRequest>,
+) -> AppResult<Json<Vec<DocumentFolderAclEntry>>> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let (before, entries) = document_service(&state)
+        .set_folder_acl(actor_id, is_manager, folder_id, &request.entries)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("document.folder.acl.update", &client)
+                .actor_claims(&claims)
+                .target("document_folder", folder_id)
+                .changes(json!({ "entries": { "before": before, "after": entries } })),
+        )
+        .await;
+
+    Ok(Json(entries))
+}
+
+// 폴더의 문서 목록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents/folders/{id}/documents",
+    tag = "documents",
+    summary = "폴더의 문서 목록",
+    params(("id" = i32, Path, description = "폴더 ID")),
+    responses(
+        (status = 200, description = "문서 목록 (이름 순, 크기/형식/체크섬은 현재 버전)", body = Vec<Document>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 폴더", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_folder_documents(
+    State(state): State<AppState>,
+    Path(folder_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<Vec<Document>>> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let documents = document_service(&state)
+        .find_documents(actor_id, is_manager, folder_id)
+        .await?;
+
+    Ok(Json(documents))
+}
+
+// 문서 업로드 핸들러 (폴더의 write 권한 필요, 올린 사용자의 용량 할당량에서 계산)
+// multipart/form-data의 file 필드와 선택 필드 name(생략하면 파일 이름), checksum, comment
+#[utoipa::path(
+    post,
+    path = "/api/v1/documents/folders/{id}/documents",
+    tag = "documents",
+    summary = "문서 업로드",
+    params(("id" = i32, Path, description = "폴더 ID")),
+    request_body(content = DocumentUploadRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "생성된 문서 (버전 1)", body = Document),
+        (status = 400, description = "file 필드 없음, 잘못된 이름이나 checksum (invalid_name, invalid_checksum, checksum_mismatch)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "폴더의 write 권한 필요 (document_access_denied)", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 폴더", body = ErrorResponse),
+        (status = 409, description = "같은 이름의 문서가 있음 (document_exists), 용량 할당량 초과 (quota_exceeded)", body = ErrorResponse),
+        (status = 413, description = "DOCUMENT_MAX_BYTES 초과", body = ErrorResponse),
+        (status = 415, description = "multipart 요청이 아님", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn upload_document(
+    State(state): State<AppState>,
+    Path(folder_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    multipart: Result<Multipart, MultipartRejection>,
+) -> AppResult<impl IntoResponse> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let (upload, name) = read_upload(multipart, state.config.document_max_bytes).await?;
+    let name = name.ok_or_else(|| {
+        AppError::invalid_field("name", "required", "문서 이름(name)이나 업로드 파일 이름이 필요합니다")
+    })?;
+
+    let document = document_service(&state)
+        .create_document(actor_id, is_manager, folder_id, &name, upload)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("document.create", &client)
+                .actor_claims(&claims)
+                .target("document", document.id)
+                .changes(json!({
+                    "folder_id": document.folder_id,
+                    "name": document.name,
+                    "version": document.current_version,
+                    "size_bytes": document.size_bytes,
+                    "checksum_sha256": document.checksum_sha256,
+                })),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(document)))
+}
+
+// 문서 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents/{id}",
+    tag = "documents",
+    summary = "문서 조회",
+    params(("id" = i32, Path, description = "문서 ID")),
+    responses(
+        (status = 200, description = "문서 (크기/형식/체크섬은 현재 버전)", body = Document),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 문서 (document_not_found)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_document(
+    State(state): State<AppState>,
+    Path(document_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<Document>> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let document = document_service(&state)
+        .document_with_access(actor_id, is_manager, document_id, DocumentAccess::Read)
+        .await?;
+
+    Ok(Json(document))
+}
+
+// 문서 수정 핸들러 (이름 변경, 다른 폴더로 이동, 폴더의 write 권한 필요)
+#[utoipa::path(
+    put,
+    path = "/api/v1/documents/{id}",
+    tag = "documents",
+    summary = "문서 수정",
+    params(("id" = i32, Path, description = "문서 ID")),
+    request_body = UpdateDocumentRequest,
+    responses(
+        (status = 200, description = "수정된 문서", body = Document),
+        (status = 400, description = "유효성 검사 실패 (invalid_name, invalid_folder)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "폴더/대상 폴더의 write 권한 필요 (document_access_denied)", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 문서", body = ErrorResponse),
+        (status = 409, description = "대상 폴더에 같은 이름의 문서가 있음 (document_exists)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_document(
+    State(state): State<AppState>,
+    Path(document_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateDocumentRequest>,
+) -> AppResult<Json<Document>> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let (before, document) = document_service(&state)
+        .update_document(actor_id, is_manager, document_id, &request)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("document.update", &client)
+                .actor_claims(&claims)
+                .target("document", document.id)
+                .changes(json!({
+                    "name": { "before": before.name, "after": document.name },
+                    "folder_id": { "before": before.folder_id, "after": document.folder_id },
+                })),
+        )
+        .await;
+
+    Ok(Json(document))
+}
+
+// 문서 삭제 핸들러 (모든 버전 포함, 폴더의 write 권한 필요)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/documents/{id}",
+    tag = "documents",
+    summary = "문서 삭제",
+    params(("id" = i32, Path, description = "문서 ID")),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "폴더의 write 권한 필요 (document_access_denied)", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 문서", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_document(
+    State(state): State<AppState>,
+    Path(document_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let document = document_service(&state)
+        .delete_document(actor_id, is_manager, document_id)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("document.delete", &client)
+                .actor_claims(&claims)
+                .target("document", document_id)
+                .changes(json!({
+                    "folder_id": document.folder_id,
+                    "name": document.name,
+                    "versions": document.current_version,
+                })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 문서 버전 목록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents/{id}/versions",
+    tag = "documents",
+    summary = "문서 버전 목록",
+    params(("id" = i32, Path, description = "문서 ID")),
+    responses(
+        (status = 200, description = "버전 목록 (최신 버전부터)", body = Vec<DocumentVersion>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 문서", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_document_versions(
+    State(state): State<AppState>,
+    Path(document_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<Vec<DocumentVersion>>> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let versions = document_service(&state)
+        .versions(actor_id, is_manager, document_id)
+        .await?;
+
+    Ok(Json(versions))
+}
+
+// 새 버전 업로드 핸들러 (폴더의 write 권한 필요, 문서 소유자의 용량 할당량에서 계산)
+// multipart/form-data의 file 필드와 선택 필드 checksum, comment (name은 무시)
+#[utoipa::path(
+    post,
+    path = "/api/v1/documents/{id}/versions",
+    tag = "documents",
+    summary = "문서 새 버전 업로드",
+    params(("id" = i32, Path, description = "문서 ID")),
+    request_body(content = DocumentUploadRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "새 버전이 현재 버전인 문서", body = Document),
+        (status = 400, description = "file 필드 없음, 잘못된 checksum (invalid_checksum, checksum_mismatch)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "폴더의 write 권한 필요 (document_access_denied)", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 문서", body = ErrorResponse),
+        (status = 409, description = "용량 할당량 초과 (quota_exceeded)", body = ErrorResponse),
+        (status = 413, description = "DOCUMENT_MAX_BYTES 초과", body = ErrorResponse),
+        (status = 415, description = "multipart 요청이 아님", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn upload_document_version(
+    State(state): State<AppState>,
+    Path(document_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    multipart: Result<Multipart, MultipartRejection>,
+) -> AppResult<impl IntoResponse> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let (upload, _) = read_upload(multipart, state.config.document_max_bytes).await?;
+    let comment = upload.comment.clone();
+
+    let document = document_service(&state)
+        .add_version(actor_id, is_manager, document_id, upload)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("document.version.create", &client)
+                .actor_claims(&claims)
+                .target("document", document.id)
+                .changes(json!({
+                    "version": document.current_version,
+                    "size_bytes": document.size_bytes,
+                    "checksum_sha256": document.checksum_sha256,
+                    "comment": comment,
+                })),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(document)))
+}
+
+// 문서 내려받기 핸들러
+// Range 헤더의 단일 바이트 범위는 206으로 일부만 응답 (If-Range의 ETag가 다르면 전체)
+// ETag는 버전 내용의 SHA-256이고 Repr-Digest에 전체 내용의 SHA-256(base64)을 함께 보냄
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents/{id}/download",
+    tag = "documents",
+    summary = "문서 내려받기",
+    params(("id" = i32, Path, description = "문서 ID"), DocumentDownloadQuery),
+    responses(
+        (status = 200, description = "파일 내용", content_type = "application/octet-stream"),
+        (status = 206, description = "Range로 요청한 일부 (Content-Range 포함)", content_type = "application/octet-stream"),
+        (status = 304, description = "If-None-Match와 같은 버전"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "없거나 접근할 수 없는 문서, 없는 버전 (document_version_not_found)", body = ErrorResponse),
+        (status = 416, description = "파일 크기를 벗어난 범위 (Content-Range: bytes */<크기>)"),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn download_document(
+    State(state): State<AppState>,
+    Path(document_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<DocumentDownloadQuery>,
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    let (actor_id, is_manager) = document_actor(&state, &claims).await?;
+    let document_service = document_service(&state);
+    let (document, version) = document_service
+        .find_version(actor_id, is_manager, document_id, query.version)
+        .await?;
+
+    let size = version.size_bytes as u64;
+    let etag = format!("\"{}\"", version.checksum_sha256);
+    let digest = hex::decode(&version.checksum_sha256).map_err(|err| AppError::Internal(err.into()))?;
+    let mut response_headers = HeaderMap::new();
+    response_headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    response_headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("private"));
+    response_headers.insert(header::ETAG, header_value(&etag)?);
+    response_headers.insert(
+        HeaderName::from_static("repr-digest"),
+        header_value(&format!("sha-256=:{}:", STANDARD.encode(digest)))?,
+    );
+
+    let header_text = |name: header::HeaderName| headers.get(name).and_then(|value| value.to_str().ok());
+    if header_text(header::IF_NONE_MATCH).is_some_and(|value| value == etag || value == "*") {
+        return Ok((StatusCode::NOT_MODIFIED, response_headers).into_response());
+    }
+
+    response_headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_str(&version.content_type).unwrap_or(HeaderValue::from_static(DEFAULT_CONTENT_TYPE)),
+    );
+    response_headers.insert(header::CONTENT_DISPOSITION, header_value(&content_disposition(&document.name))?);
+
+    // If-Range가 현재 ETag와 다르면 Range를 무시하고 전체 응답
+    let range = match header_text(header::RANGE) {
+        Some(range) if header_text(header::IF_RANGE).is_none_or(|value| value == etag) => parse_range(range, size),
+        _ => RangeRequest::Full,
+    };
+
+    match range {
+        RangeRequest::Full => {
+            let data = document_service.read(&version.checksum_sha256, None).await?;
+            Ok((response_headers, data).into_response())
+        }
+        RangeRequest::Partial(range) => {
+            let data = document_service.read(&version.checksum_sha256, Some(range)).await?;
+            response_headers.insert(
+                header::CONTENT_RANGE,
+                header_value(&format!("bytes {}-{}/{}", range.start, range.end, size))?,
+            );
+            Ok((StatusCode::PARTIAL_CONTENT, response_headers, data).into_response())
+        }
+        RangeRequest::Unsatisfiable => {
+            response_headers.remove(header::CONTENT_DISPOSITION);
+            response_headers.insert(header::CONTENT_RANGE, header_value(&format!("bytes */{}", size))?);
+            Ok((StatusCode::RANGE_NOT_SATISFIABLE, response_headers).into_response())
+        }
+    }
+}
+
+// 내 문서 용량 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/documents/quota",
+    tag = "documents",
+    summary = "내 문서 용량",
+    responses(
+        (status = 200, description = "소유한 문서의 모든 버전 크기 합계와 할당량", body = DocumentQuota),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_document_quota(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<DocumentQuota>> {
+    let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
+    Ok(Json(document_service(&state).quota(current_user_id).await?))
+}
+
+// 사용자 문서 용량 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/users/{id}/document-quota",
+    tag = "documents",
+    summary = "사용자 문서 용량 조회",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    responses(
+        (status = 200, description = "사용량과 할당량", body = DocumentQuota),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "documents.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_user_document_quota(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    RequirePermission(_claims, _): RequirePermission<DocumentsManage>,
+) -> AppResult<Json<DocumentQuota>> {
+    UserService::new(state.db_pool.clone())
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    Ok(Json(document_service(&state).quota(user_id).await?))
+}
+
+// 사용자 문서 용량 할당량 설정 핸들러 (이미 쓰고 있는 용량보다 작게 설정하면 이후 업로드만 거부)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/users/{id}/document-quota",
+    tag = "documents",
+    summary = "사용자 문서 용량 할당량 설정",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    request_body = SetDocumentQuotaRequest,
+    responses(
+        (status = 200, description = "변경 후 사용량과 할당량", body = DocumentQuota),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "documents.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn set_user_document_quota(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<DocumentsManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<SetDocumentQuotaRequest>,
+) -> AppResult<Json<DocumentQuota>> {
+    UserService::new(state.db_pool.clone())
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    let document_service = document_service(&state);
+    let before = document_service.quota(user_id).await?;
+    let quota = document_service.set_quota(user_id, request.quota_bytes).await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("document.quota.update", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(json!({
+                    "quota_bytes": { "before": before.quota_bytes, "after": quota.quota_bytes },
+                    "is_default": quota.is_default,
+                })),
+        )
+        .await;
+
+    Ok(Json(quota))
+}
+
+// multipart 업로드 본문 읽기 (file 필드와 선택 필드 name, checksum, comment)
+// 문서 이름은 name 필드, 없으면 파일 이름
+async fn read_upload(
+    multipart: Result<Multipart, MultipartRejection>,
+    max_bytes: usize,
+) -> AppResult<(DocumentUpload, Option<String>)> {
+    let mut multipart = multipart.map_err(|_| {
+        AppError::unsupported_file("unsupported_media_type", "Content-Type이 multipart/form-data여야 합니다")
+    })?;
+
+    let mut file: Option<(Vec<u8>, Option<String>, Option<String>)> = None;
+    let mut name = None;
+    let mut checksum = None;
+    let mut comment = None;
+    while let Some(field) = multipart.next_field().await.map_err(multipart_error)? {
+        match field.name() {
+            Some("file") => {
+                let file_name = field.file_name().map(str::to_string);
+                let content_type = field.content_type().map(str::to_string);
+                let data = field.bytes().await.map_err(multipart_error)?;
+                if data.len() > max_bytes {
+                    return Err(AppError::PayloadTooLarge);
+                }
+                if !data.is_empty() {
+                    file = Some((data.to_vec(), file_name, content_type));
+                }
+            }
+            Some("name") => name = Some(field.text().await.map_err(multipart_error)?),
+            Some("checksum") => checksum = Some(field.text().await.map_err(multipart_error)?),
+            Some("comment") => comment = Some(field.text().await.map_err(multipart_error)?),
+            _ => {}
+        }
+    }
+
+    let Some((data, file_name, content_type)) = file else {
+        return Err(AppError::invalid_field("file", "required", "업로드할 파일(file)이 필요합니다"));
+    };
+
+    // 브라우저에 따라 파일 이름에 경로가 포함되므로 마지막 부분만 사용
+    let name = name
+        .filter(|name| !name.is_empty())
+        .or_else(|| file_name.and_then(|file_name| file_name.rsplit(['/', '\\']).next().map(str::to_string)))
+        .filter(|name| !name.is_empty());
+    if let Some(name) = &name {
+        if name.chars().count() > MAX_NAME_CHARS {
+            return Err(AppError::invalid_field(
+                "name",
+                "length",
+                format!("문서 이름은 {}자 이하여야 합니다", MAX_NAME_CHARS),
+            ));
+        }
+        validate_document_name(name).map_err(|err| {
+            AppError::invalid_field("name", &err.code, err.message.unwrap_or_default().to_string())
+        })?;
+    }
+
+    let checksum = checksum.map(|checksum| checksum.trim().to_ascii_lowercase()).filter(|checksum| !checksum.is_empty());
+    if let Some(checksum) = &checksum {
+        if checksum.len() != 64 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(AppError::invalid_field(
+                "checksum",
+                "invalid_checksum",
+                "checksum은 16진수 64자리 SHA-256이어야 합니다",
+            ));
+        }
+    }
+
+    let comment = comment.filter(|comment| !comment.trim().is_empty());
+    if comment.as_ref().is_some_and(|comment| comment.chars().count() > MAX_COMMENT_CHARS) {
+        return Err(AppError::invalid_field(
+            "comment",
+            "length",
+            format!("버전 설명은 {}자 이하여야 합니다", MAX_COMMENT_CHARS),
+        ));
+    }
+
+    // 헤더로 보낼 수 없거나 형식이 잘못된 Content-Type은 알 수 없는 형식으로 저장
+    let content_type = content_type
+        .filter(|content_type| {
+            content_type.len() <= 255 && content_type.contains('/') && HeaderValue::from_str(content_type).is_ok()
+        })
+        .unwrap_or_else(|| DEFAULT_CONTENT_TYPE.to_string());
+
+    Ok((
+        DocumentUpload {
+            data,
+            content_type,
+            checksum,
+            comment,
+        },
+        name,
+    ))
+}
+
+fn header_value(value: &str) -> AppResult<HeaderValue> {
+    HeaderValue::from_str(value).map_err(|err| AppError::Internal(err.into()))
+}
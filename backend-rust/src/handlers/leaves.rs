@@ -0,0 +1,619 @@
+use axum::{
+    extract::{OriginalUri, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json},
+    Extension,
+};
+use chrono::{Datelike, Utc};
+use serde_json::json;
+use validator::Validate;
+
+use super::pagination_headers;
+use crate::{
+    error::{AppError, AppResult},
+    middleware::{LeaveManage, PermissionCode, RequirePermission, ValidatedJson},
+    models::{
+        Claims, ClientInfo, CreateLeaveRequest, CreateLeaveTypeRequest, ErrorResponse, LeaveBalance,
+        LeaveBalanceQuery, LeaveCalendarEntry, LeaveCalendarQuery, LeaveDecision, LeaveDecisionRequest,
+        LeaveRequest, LeaveRequestDetailResponse, LeaveRequestListQuery, LeaveType, LeaveTypeQuery,
+        NewAuditEvent, SetLeaveBalanceRequest, UpdateLeaveTypeRequest,
+    },
+    services::{AuditService, DepartmentService, LeaveService, RoleService, UserService},
+    AppState,
+};
+
+// 팀 휴가 달력 최대 조회 기간 (일)
+const MAX_CALENDAR_DAYS: i64 = 93;
+
+// 인사 담당자(leave.manage 권한 보유자)인지 확인
+async fn is_leave_manager(state: &AppState, user_id: i32) -> AppResult<bool> {
+    Ok(RoleService::new(state.db_pool.clone())
+        .has_permission(user_id, LeaveManage::CODE)
+        .await?)
+}
+
+// 다른 사용자의 휴가 정보 조회 권한 확인
+async fn check_other_user(state: &AppState, viewer_id: i32, user_id: i32) -> AppResult<()> {
+    if user_id != viewer_id && !is_leave_manager(state, viewer_id).await? {
+        return Err(AppError::forbidden(
+            "permission_denied",
+            format!("다른 사용자의 휴가 조회에는 '{}' 권한이 필요합니다", LeaveManage::CODE),
+        ));
+    }
+    Ok(())
+}
+
+// 휴가 종류 목록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/leave-types",
+    tag = "leave",
+    summary = "휴가 종류 목록 조회",
+    params(LeaveTypeQuery),
+    responses(
+        (status = 200, description = "휴가 종류 목록", body = Vec<LeaveType>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "include_inactive는 leave.manage 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_leave_types(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<LeaveTypeQuery>,
+) -> AppResult<Json<Vec<LeaveType>>> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    if query.include_inactive && !is_leave_manager(&state, viewer_id).await? {
+        return Err(AppError::forbidden(
+            "permission_denied",
+            format!("사용 중지된 휴가 종류 조회에는 '{}' 권한이 필요합니다", LeaveManage::CODE),
+        ));
+    }
+
+    let leave_service = LeaveService::new(state.db_pool);
+    Ok(Json(leave_service.find_types(query.include_inactive).await?))
+}
+
+// 휴가 종류 생성 핸들러
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/leave-types",
+    tag = "leave",
+    summary = "휴가 종류 생성",
+    request_body = CreateLeaveTypeRequest,
+    responses(
+        (status = 201, description = "생성된 휴가 종류", body = LeaveType),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "leave.manage 권한 필요", body = ErrorResponse),
+        (status = 409, description = "이미 존재하는 코드", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_leave_type(
+    State(state): State<AppState>,
+    RequirePermission(claims, _): RequirePermission<LeaveManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<CreateLeaveTypeRequest>,
+) -> AppResult<impl IntoResponse> {
+    let leave_type = LeaveService::new(state.db_pool.clone())
+        .create_type(&request)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("leave.type.create", &client)
+                .actor_claims(&claims)
+                .target("leave_type", leave_type.id)
+                .changes(json!({
+                    "code": leave_type.code,
+                    "name": leave_type.name,
+                    "annual_days": leave_type.annual_days,
+                    "accrual": leave_type.accrual,
+                    "max_carryover_days": leave_type.max_carryover_days,
+                    "paid": leave_type.paid,
+                })),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(leave_type)))
+}
+
+// 휴가 종류 수정 핸들러 (사용 중지하면 새 신청과 잔여 일수 목록에서 제외, 기존 신청은 유지)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/leave-types/{id}",
+    tag = "leave",
+    summary = "휴가 종류 수정",
+    params(("id" = i32, Path, description = "휴가 종류 ID")),
+    request_body = UpdateLeaveTypeRequest,
+    responses(
+        (status = 200, description = "수정된 휴가 종류", body = LeaveType),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "leave.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "휴가 종류 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_leave_type(
+    State(state): State<AppState>,
+    Path(leave_type_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<LeaveManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateLeaveTypeRequest>,
+) -> AppResult<Json<LeaveType>> {
+    let leave_service = LeaveService::new(state.db_pool.clone());
+
+    // 감사 로그용 변경 전 상태 조회
+    let before = leave_service
+        .find_type(leave_type_id)
+        .await?
+        .ok_or_else(leave_type_not_found)?;
+    let leave_type = leave_service
+        .update_type(leave_type_id, &request)
+        .await?
+        .ok_or_else(leave_type_not_found)?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("leave.type.update", &client)
+                .actor_claims(&claims)
+                .target("leave_type", leave_type.id)
+                .changes(json!({
+                    "name": { "before": before.name, "after": leave_type.name },
+                    "annual_days": { "before": before.annual_days, "after": leave_type.annual_days },
+                    "accrual": { "before": before.accrual, "after": leave_type.accrual },
+                    "max_carryover_days": { "before": before.max_carryover_days, "after": leave_type.max_carryover_days },
+                    "paid": { "before": before.paid, "after": leave_type.paid },
+                    "is_active": { "before": before.is_active, "after": leave_type.is_active },
+                })),
+        )
+        .await;
+
+    Ok(Json(leave_type))
+}
+
+// 휴가 잔여 일수 조회 핸들러 (기본은 본인, 올해)
+#[utoipa::path(
+    get,
+    path = "/api/v1/leave-balances",
+    tag = "leave",
+    summary = "휴가 잔여 일수 조회",
+    params(LeaveBalanceQuery),
+    responses(
+        (status = 200, description = "휴가 종류별 잔여 일수 (잔여 일수를 관리하지 않는 종류 제외)", body = Vec<LeaveBalance>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "다른 사용자 조회는 leave.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_leave_balances(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<LeaveBalanceQuery>,
+) -> AppResult<Json<Vec<LeaveBalance>>> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let user_id = query.user_id.unwrap_or(viewer_id);
+    check_other_user(&state, viewer_id, user_id).await?;
+    if user_id != viewer_id && UserService::new(state.db_pool.clone()).find_by_id(user_id).await?.is_none() {
+        return Err(AppError::user_not_found());
+    }
+
+    let year = query.year.unwrap_or_else(|| Utc::now().year());
+    let leave_service = LeaveService::new(state.db_pool);
+    Ok(Json(leave_service.balances(user_id, year).await?))
+}
+
+// 휴가 잔여 일수 조정 핸들러 (해당 연도의 이월/조정 일수를 교체)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/users/{id}/leave-balances",
+    tag = "leave",
+    summary = "휴가 잔여 일수 조정",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    request_body = SetLeaveBalanceRequest,
+    responses(
+        (status = 200, description = "조정 후 해당 연도의 잔여 일수", body = Vec<LeaveBalance>),
+        (status = 400, description = "유효성 검사 실패 (carryover_exceeded, invalid_leave_type)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "leave.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn set_leave_balance(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<LeaveManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<SetLeaveBalanceRequest>,
+) -> AppResult<Json<Vec<LeaveBalance>>> {
+    let leave_service = LeaveService::new(state.db_pool.clone());
+
+    UserService::new(state.db_pool.clone())
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+    let leave_type = leave_service
+        .find_type(request.leave_type_id)
+        .await?
+        .ok_or_else(|| AppError::invalid_field("leave_type_id", "invalid_leave_type", "존재하지 않는 휴가 종류입니다"))?;
+    if request.carried_over_days > leave_type.max_carryover_days {
+        return Err(AppError::invalid_field(
+            "carried_over_days",
+            "carryover_exceeded",
+            format!("{}은(는) 최대 {}일까지 이월할 수 있습니다", leave_type.name, leave_type.max_carryover_days),
+        ));
+    }
+
+    leave_service.set_balance(user_id, &request).await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("leave.balance.update", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(json!({
+                    "leave_type": leave_type.code,
+                    "year": request.year,
+                    "carried_over_days": request.carried_over_days,
+                    "adjustment_days": request.adjustment_days,
+                    "note": request.note,
+                })),
+        )
+        .await;
+
+    Ok(Json(leave_service.balances(user_id, request.year).await?))
+}
+
+// 휴가 신청 핸들러
+#[utoipa::path(
+    post,
+    path = "/api/v1/leave-requests",
+    tag = "leave",
+    summary = "휴가 신청",
+    request_body = CreateLeaveRequest,
+    responses(
+        (status = 201, description = "신청된 휴가 (직속 상사가 없으면 바로 인사 승인 대기)", body = LeaveRequest),
+        (status = 400, description = "유효성 검사 실패 (invalid_range, invalid_leave_type)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 409, description = "기간 중복 (leave_overlap) 또는 잔여 일수 부족 (insufficient_balance)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_leave_request(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<CreateLeaveRequest>,
+) -> AppResult<impl IntoResponse> {
+    let user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let leave_request = LeaveService::new(state.db_pool.clone())
+        .submit(user_id, &request)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("leave.request.create", &client)
+                .actor_claims(&claims)
+                .target("leave_request", leave_request.id)
+                .changes(json!({
+                    "leave_type": leave_request.leave_type_code,
+                    "start_date": leave_request.start_date,
+                    "end_date": leave_request.end_date,
+                    "half_day": leave_request.half_day,
+                    "days": leave_request.days,
+                    "status": leave_request.status,
+                    "manager_id": leave_request.manager_id,
+                })),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(leave_request)))
+}
+
+// 휴가 신청 목록 조회 핸들러 (기본은 본인 신청, 시작일 최근 순)
+#[utoipa::path(
+    get,
+    path = "/api/v1/leave-requests",
+    tag = "leave",
+    summary = "휴가 신청 목록 조회",
+    params(LeaveRequestListQuery),
+    responses(
+        (status = 200, description = "휴가 신청 목록 (페이지 정보는 X-Total-Count, Link 헤더)", body = Vec<LeaveRequest>),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "다른 사용자 조회는 leave.manage 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_leave_requests(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<LeaveRequestListQuery>,
+) -> AppResult<impl IntoResponse> {
+    query.validate()?;
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let user_id = query.user_id.unwrap_or(viewer_id);
+    check_other_user(&state, viewer_id, user_id).await?;
+
+    let (requests, total) = LeaveService::new(state.db_pool)
+        .find_page(user_id, &query)
+        .await?;
+
+    let headers = pagination_headers(uri.path(), &query, query.page, query.per_page, total);
+    Ok((StatusCode::OK, headers, Json(requests)))
+}
+
+// 승인 대기 목록 조회 핸들러 (조회자의 결정을 기다리는 신청, 오래된 순)
+#[utoipa::path(
+    get,
+    path = "/api/v1/leave-requests/pending",
+    tag = "leave",
+    summary = "승인 대기 휴가 목록 조회",
+    params(LeaveRequestListQuery),
+    responses(
+        (status = 200, description = "직속 상사로 지정된 신청과 (leave.manage 권한 보유 시) 인사 승인 대기 신청", body = Vec<LeaveRequest>),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_pending_leave_requests(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<LeaveRequestListQuery>,
+) -> AppResult<impl IntoResponse> {
+    query.validate()?;
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let is_hr = is_leave_manager(&state, viewer_id).await?;
+
+    let (requests, total) = LeaveService::new(state.db_pool)
+        .find_pending(viewer_id, is_hr, &query)
+        .await?;
+
+    let headers = pagination_headers(uri.path(), &query, query.page, query.per_page, total);
+    Ok((StatusCode::OK, headers, Json(requests)))
+}
+
+// 휴가 신청 조회 핸들러 (신청자, 직속 상사, leave.manage 권한 보유자만 조회 가능)
+#[utoipa::path(
+    get,
+    path = "/api/v1/leave-requests/{id}",
+    tag = "leave",
+    summary = "휴가 신청 조회",
+    params(("id" = i32, Path, description = "휴가 신청 ID")),
+    responses(
+        (status = 200, description = "휴가 신청과 승인/반려 기록", body = LeaveRequestDetailResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "없거나 볼 수 없는 신청", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_leave_request(
+    State(state): State<AppState>,
+    Path(leave_request_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<LeaveRequestDetailResponse>> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let leave_service = LeaveService::new(state.db_pool.clone());
+
+    let request = leave_service
+        .find_by_id(leave_request_id)
+        .await?
+        .ok_or_else(leave_request_not_found)?;
+    let visible = request.user_id == viewer_id
+        || request.manager_id == Some(viewer_id)
+        || is_leave_manager(&state, viewer_id).await?;
+    if !visible {
+        return Err(leave_request_not_found());
+    }
+
+    let approvals = leave_service.approvals(leave_request_id).await?;
+    Ok(Json(LeaveRequestDetailResponse { request, approvals }))
+}
+
+// 휴가 승인 핸들러 (직속 상사 승인 대기 -> 인사 승인 대기 -> 승인)
+#[utoipa::path(
+    post,
+    path = "/api/v1/leave-requests/{id}/approve",
+    tag = "leave",
+    summary = "휴가 승인",
+    params(("id" = i32, Path, description = "휴가 신청 ID")),
+    request_body = LeaveDecisionRequest,
+    responses(
+        (status = 200, description = "다음 단계로 넘어간 신청", body = LeaveRequest),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "현재 단계의 승인자가 아님 (not_approver)", body = ErrorResponse),
+        (status = 404, description = "신청 없음", body = ErrorResponse),
+        (status = 409, description = "승인할 수 없는 상태 (invalid_transition)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn approve_leave_request(
+    State(state): State<AppState>,
+    Path(leave_request_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<LeaveDecisionRequest>,
+) -> AppResult<Json<LeaveRequest>> {
+    decide(state, leave_request_id, claims, client, LeaveDecision::Approved, request).await
+}
+
+// 휴가 반려 핸들러 (승인 대기 중인 단계의 승인자만 가능)
+#[utoipa::path(
+    post,
+    path = "/api/v1/leave-requests/{id}/reject",
+    tag = "leave",
+    summary = "휴가 반려",
+    params(("id" = i32, Path, description = "휴가 신청 ID")),
+    request_body = LeaveDecisionRequest,
+    responses(
+        (status = 200, description = "반려된 신청", body = LeaveRequest),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "현재 단계의 승인자가 아님 (not_approver)", body = ErrorResponse),
+        (status = 404, description = "신청 없음", body = ErrorResponse),
+        (status = 409, description = "반려할 수 없는 상태 (invalid_transition)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn reject_leave_request(
+    State(state): State<AppState>,
+    Path(leave_request_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<LeaveDecisionRequest>,
+) -> AppResult<Json<LeaveRequest>> {
+    decide(state, leave_request_id, claims, client, LeaveDecision::Rejected, request).await
+}
+
+// 승인/반려 공통 처리 (상태 전이는 LeaveService에서 확인)
+async fn decide(
+    state: AppState,
+    leave_request_id: i32,
+    claims: Claims,
+    client: ClientInfo,
+    decision: LeaveDecision,
+    request: LeaveDecisionRequest,
+) -> AppResult<Json<LeaveRequest>> {
+    let actor_id: i32 = claims.sub.parse().unwrap_or(0);
+    let is_hr = is_leave_manager(&state, actor_id).await?;
+
+    let leave_request = LeaveService::new(state.db_pool.clone())
+        .decide(leave_request_id, actor_id, is_hr, decision, request.comment.as_deref())
+        .await?;
+
+    let action = match decision {
+        LeaveDecision::Approved => "leave.request.approve",
+        LeaveDecision::Rejected => "leave.request.reject",
+    };
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new(action, &client)
+                .actor_claims(&claims)
+                .target("leave_request", leave_request.id)
+                .changes(json!({
+                    "status": leave_request.status,
+                    "comment": request.comment,
+                })),
+        )
+        .await;
+
+    Ok(Json(leave_request))
+}
+
+// 휴가 취소 핸들러 (신청자만 가능, 승인된 휴가는 시작일 전까지만)
+#[utoipa::path(
+    post,
+    path = "/api/v1/leave-requests/{id}/cancel",
+    tag = "leave",
+    summary = "휴가 취소",
+    params(("id" = i32, Path, description = "휴가 신청 ID")),
+    responses(
+        (status = 200, description = "취소된 신청", body = LeaveRequest),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "본인 신청이 아님 (not_requester)", body = ErrorResponse),
+        (status = 404, description = "신청 없음", body = ErrorResponse),
+        (status = 409, description = "취소할 수 없는 상태 (invalid_transition, leave_already_started)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn cancel_leave_request(
+    State(state): State<AppState>,
+    Path(leave_request_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+) -> AppResult<Json<LeaveRequest>> {
+    let actor_id: i32 = claims.sub.parse().unwrap_or(0);
+    let leave_request = LeaveService::new(state.db_pool.clone())
+        .cancel(leave_request_id, actor_id)
+        .await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("leave.request.cancel", &client)
+                .actor_claims(&claims)
+                .target("leave_request", leave_request.id)
+                .changes(json!({
+                    "start_date": leave_request.start_date,
+                    "end_date": leave_request.end_date,
+                    "days": leave_request.days,
+                })),
+        )
+        .await;
+
+    Ok(Json(leave_request))
+}
+
+// 팀 휴가 달력 조회 핸들러
+// 부서와 하위 부서 소속 사용자의 승인된 휴가 (include_pending이면 승인 대기 포함)
+#[utoipa::path(
+    get,
+    path = "/api/v1/leave-calendar",
+    tag = "leave",
+    summary = "팀 휴가 달력 조회",
+    params(LeaveCalendarQuery),
+    responses(
+        (status = 200, description = "기간이 겹치는 휴가 (시작일 순, 사유 제외)", body = Vec<LeaveCalendarEntry>),
+        (status = 400, description = "유효성 검사 실패 (기간, 소속 부서 없음)", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 404, description = "부서 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_leave_calendar(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<LeaveCalendarQuery>,
+) -> AppResult<Json<Vec<LeaveCalendarEntry>>> {
+    if query.to < query.from {
+        return Err(AppError::invalid_field("to", "invalid_range", "종료일은 시작일 이후여야 합니다"));
+    }
+    if (query.to - query.from).num_days() >= MAX_CALENDAR_DAYS {
+        return Err(AppError::invalid_field(
+            "to",
+            "invalid_range",
+            format!("조회 기간은 {}일 이하여야 합니다", MAX_CALENDAR_DAYS),
+        ));
+    }
+
+    let department_service = DepartmentService::new(state.db_pool.clone());
+    let department_id = match query.department_id {
+        Some(department_id) => {
+            department_service
+                .find_by_id(department_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("department_not_found", "부서를 찾을 수 없습니다"))?
+                .id
+        }
+        None => {
+            let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+            department_service
+                .find_member(viewer_id)
+                .await?
+                .ok_or_else(|| {
+                    AppError::invalid_field(
+                        "department_id",
+                        "department_required",
+                        "소속 부서가 없으므로 department_id를 지정해야 합니다",
+                    )
+                })?
+                .department_id
+        }
+    };
+
+    let leave_service = LeaveService::new(state.db_pool);
+    Ok(Json(leave_service.calendar(department_id, &query).await?))
+}
+
+fn leave_type_not_found() -> AppError {
+    AppError::not_found("leave_type_not_found", "휴가 종류를 찾을 수 없습니다")
+}
+
+fn leave_request_not_found() -> AppError {
+    AppError::not_found("leave_request_not_found", "휴가 신청을 찾을 수 없습니다")
+}
@@ -0,0 +1,246 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+
+use crate::{
+    error::{AppError, AppResult},
+    middleware::ValidatedJson,
+    models::{
+        Claims, ClientInfo, ErrorResponse, MfaCodeRequest, MfaEnrollResponse,
+        MfaRecoveryCodesResponse, MfaStatusResponse, NewAuditEvent, User,
+    },
+    services::{AuditService, MfaCode, MfaService, RoleService, UserService},
+    AppState,
+};
+
+// 요청에서 인증 코드 선택 (인증 앱 코드와 복구 코드 중 정확히 하나)
+pub(crate) fn mfa_code<'a>(
+    code: &'a Option<String>,
+    recovery_code: &'a Option<String>,
+) -> Option<MfaCode<'a>> {
+    match (code, recovery_code) {
+        (Some(code), None) => Some(MfaCode::Totp(code)),
+        (None, Some(recovery_code)) => Some(MfaCode::Recovery(recovery_code)),
+        _ => None,
+    }
+}
+
+// 인증 코드 누락 에러
+pub(crate) fn missing_mfa_code() -> AppError {
+    AppError::invalid_field(
+        "code",
+        "required",
+        "인증 코드(code) 또는 복구 코드(recovery_code) 중 하나를 입력해주세요",
+    )
+}
+
+// 토큰의 사용자 조회
+async fn current_user(state: &AppState, claims: &Claims) -> AppResult<User> {
+    let user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let user_service = UserService::new(state.db_pool.clone());
+
+    user_service
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)
+}
+
+// 등록된 2단계 인증 코드 확인 (해제, 복구 코드 재발급 전 본인 확인)
+async fn verify_current_code(
+    mfa_service: &MfaService,
+    user: &User,
+    request: &MfaCodeRequest,
+) -> AppResult<()> {
+    let code = mfa_code(&request.code, &request.recovery_code).ok_or_else(missing_mfa_code)?;
+
+    if !mfa_service.verify(user, code).await? {
+        return Err(AppError::unauthorized("invalid_mfa_code", "인증 코드가 올바르지 않습니다"));
+    }
+
+    Ok(())
+}
+
+// 2단계 인증 상태 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/auth/mfa",
+    tag = "mfa",
+    summary = "2단계 인증 상태 조회",
+    responses(
+        (status = 200, description = "2단계 인증 상태", body = MfaStatusResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_mfa_status(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> AppResult<Json<MfaStatusResponse>> {
+    let user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let mfa_service = MfaService::new(state.db_pool.clone(), state.config);
+    let role_service = RoleService::new(state.db_pool);
+
+    Ok(Json(MfaStatusResponse {
+        enabled: mfa_service.is_enabled(user_id).await?,
+        required: role_service.user_requires_mfa(user_id).await?,
+    }))
+}
+
+// 2단계 인증 등록 시작 핸들러 (비밀 키와 QR 코드용 otpauth URI 발급)
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/mfa/enroll",
+    tag = "mfa",
+    summary = "2단계 인증 등록 시작",
+    responses(
+        (status = 200, description = "비밀 키와 otpauth URI", body = MfaEnrollResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 409, description = "이미 등록됨 (mfa_already_enabled)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn enroll_mfa(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+) -> AppResult<Json<MfaEnrollResponse>> {
+    let user = current_user(&state, &claims).await?;
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let mfa_service = MfaService::new(state.db_pool, state.config);
+
+    let enrollment = mfa_service.start_enrollment(&user).await?.ok_or_else(|| {
+        AppError::conflict("mfa_already_enabled", "이미 2단계 인증이 등록되어 있습니다")
+    })?;
+
+    audit_service
+        .record(NewAuditEvent::new("auth.mfa.enroll", &client).actor_claims(&claims))
+        .await;
+
+    Ok(Json(MfaEnrollResponse {
+        secret: enrollment.secret,
+        otpauth_uri: enrollment.otpauth_uri,
+    }))
+}
+
+// 2단계 인증 등록 확인 핸들러 (인증 앱의 코드 확인 후 복구 코드 발급)
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/mfa/confirm",
+    tag = "mfa",
+    summary = "2단계 인증 등록 확인",
+    request_body = MfaCodeRequest,
+    responses(
+        (status = 200, description = "등록 완료 및 복구 코드 발급", body = MfaRecoveryCodesResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 코드 불일치", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn confirm_mfa(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<MfaCodeRequest>,
+) -> AppResult<Json<MfaRecoveryCodesResponse>> {
+    let code = request.code.as_deref().ok_or_else(|| {
+        AppError::invalid_field("code", "required", "인증 앱의 코드(code)를 입력해주세요")
+    })?;
+
+    let user = current_user(&state, &claims).await?;
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let mfa_service = MfaService::new(state.db_pool, state.config);
+
+    let recovery_codes = mfa_service.confirm(&user, code).await?.ok_or_else(|| {
+        AppError::bad_request(
+            "invalid_mfa_code",
+            "인증 코드가 올바르지 않거나 진행 중인 등록이 없습니다",
+        )
+    })?;
+
+    audit_service
+        .record(NewAuditEvent::new("auth.mfa.confirm", &client).actor_claims(&claims))
+        .await;
+
+    Ok(Json(MfaRecoveryCodesResponse { recovery_codes }))
+}
+
+// 복구 코드 재발급 핸들러 (기존 복구 코드는 모두 무효화)
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/mfa/recovery-codes",
+    tag = "mfa",
+    summary = "복구 코드 재발급",
+    request_body = MfaCodeRequest,
+    responses(
+        (status = 200, description = "새 복구 코드", body = MfaRecoveryCodesResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 코드 불일치", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn regenerate_recovery_codes(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<MfaCodeRequest>,
+) -> AppResult<Json<MfaRecoveryCodesResponse>> {
+    let user = current_user(&state, &claims).await?;
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let mfa_service = MfaService::new(state.db_pool, state.config);
+
+    verify_current_code(&mfa_service, &user, &request).await?;
+    let recovery_codes = mfa_service.regenerate_recovery_codes(user.id).await?;
+
+    audit_service
+        .record(NewAuditEvent::new("auth.mfa.recovery_codes", &client).actor_claims(&claims))
+        .await;
+
+    Ok(Json(MfaRecoveryCodesResponse { recovery_codes }))
+}
+
+// 2단계 인증 해제 핸들러
+// 역할 정책상 2단계 인증이 필요한 사용자는 해제할 수 없음
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/mfa/disable",
+    tag = "mfa",
+    summary = "2단계 인증 해제",
+    request_body = MfaCodeRequest,
+    responses(
+        (status = 204, description = "해제 완료"),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 코드 불일치", body = ErrorResponse),
+        (status = 403, description = "역할 정책상 해제 불가", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn disable_mfa(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<MfaCodeRequest>,
+) -> AppResult<StatusCode> {
+    let user = current_user(&state, &claims).await?;
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let role_service = RoleService::new(state.db_pool.clone());
+    let mfa_service = MfaService::new(state.db_pool, state.config);
+
+    if role_service.user_requires_mfa(user.id).await? {
+        return Err(AppError::forbidden(
+            "mfa_required_by_role",
+            "역할 정책상 2단계 인증을 해제할 수 없습니다",
+        ));
+    }
+
+    verify_current_code(&mfa_service, &user, &request).await?;
+    mfa_service.disable(user.id).await?;
+
+    audit_service
+        .record(NewAuditEvent::new("auth.mfa.disable", &client).actor_claims(&claims))
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
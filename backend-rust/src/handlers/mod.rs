@@ -1,2 +1,101 @@
+use axum::{
+    extract::Multipart,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+};
+use serde::Serialize;
+
+use crate::error::{AppError, AppResult};
+
+pub mod announcements;
+pub mod audit;
 pub mod auth;
-pub mod users; 
\ No newline at end of file
+pub mod avatars;
+pub mod bookings;
+pub mod calendar;
+pub mod departments;
+pub mod documents;
+pub mod leaves;
+pub mod mfa;
+pub mod profiles;
+pub mod roles;
+pub mod search;
+pub mod users;
+
+// 페이지네이션 응답 헤더 생성 (X-Total-Count, Link: first/prev/next/last)
+// 링크에는 현재 쿼리 파라미터를 유지하고 page 값만 바꾼다
+pub(crate) fn pagination_headers<Q: Serialize>(
+    path: &str,
+    query: &Q,
+    page: i64,
+    per_page: i64,
+    total: i64,
+) -> HeaderMap {
+    let params: Vec<(String, String)> = serde_urlencoded::to_string(query)
+        .ok()
+        .and_then(|encoded| serde_urlencoded::from_str(&encoded).ok())
+        .unwrap_or_default();
+    let last_page = ((total + per_page - 1) / per_page).max(1);
+    let page_link = |target_page: i64, rel: &str| {
+        let page_params: Vec<(String, String)> = params
+            .iter()
+            .map(|(key, value)| {
+                if key == "page" {
+                    (key.clone(), target_page.to_string())
+                } else {
+                    (key.clone(), value.clone())
+                }
+            })
+            .collect();
+        let query_string = serde_urlencoded::to_string(&page_params).unwrap_or_default();
+        format!("<{}?{}>; rel=\"{}\"", path, query_string, rel)
+    };
+
+    let mut links = vec![page_link(1, "first")];
+    if page > 1 {
+        links.push(page_link((page - 1).min(last_page), "prev"));
+    }
+    if page < last_page {
+        links.push(page_link(page + 1, "next"));
+    }
+    links.push(page_link(last_page, "last"));
+
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Total-Count", HeaderValue::from(total));
+    if let Ok(link) = HeaderValue::from_str(&links.join(", ")) {
+        headers.insert(header::LINK, link);
+    }
+    headers
+}
+
+// multipart 본문에서 file 필드 읽기 (크기 제한 초과는 413)
+pub(crate) async fn read_file_field(
+    mut multipart: Multipart,
+    max_bytes: usize,
+    missing_message: &str,
+) -> AppResult<Vec<u8>> {
+    while let Some(field) = multipart.next_field().await.map_err(multipart_error)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let data = field.bytes().await.map_err(multipart_error)?;
+        if data.len() > max_bytes {
+            return Err(AppError::PayloadTooLarge);
+        }
+        if data.is_empty() {
+            break;
+        }
+        return Ok(data.to_vec());
+    }
+
+    Err(AppError::invalid_field("file", "required", missing_message))
+}
+
+// multipart 읽기 오류 변환 (본문 크기 제한 초과는 413)
+pub(crate) fn multipart_error(err: axum::extract::multipart::MultipartError) -> AppError {
+    if err.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        AppError::PayloadTooLarge
+    } else {
+        AppError::bad_request("invalid_multipart", err.body_text())
+    }
+}
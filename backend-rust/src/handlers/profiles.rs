@@ -0,0 +1,169 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+    Extension,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::{
+    error::{AppError, AppResult},
+    middleware::{PermissionCode, RequirePermission, UsersRead, UsersUpdate, ValidatedJson},
+    models::{
+        Claims, ClientInfo, ErrorResponse, NewAuditEvent, ProfileVisibility, UpdateProfileRequest,
+        User, UserProfile, UserProfileResponse, UserResponse,
+    },
+    services::{AuditService, ProfileService, RoleService, UserService},
+    AppState,
+};
+
+// 사용자 프로필 조회 핸들러 (조회자에게 공개된 항목만 응답)
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}/profile",
+    tag = "users",
+    summary = "사용자 프로필 조회",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    responses(
+        (status = 200, description = "공개 범위에 따라 걸러진 프로필 (visibility는 본인과 관리자에게만 표시)", body = UserProfileResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "users.read 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_profile(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<UsersRead>,
+) -> AppResult<Json<UserProfileResponse>> {
+    UserService::new(state.db_pool.clone())
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    let profile_service = ProfileService::new(state.db_pool.clone());
+    let profile = profile_service
+        .find(user_id)
+        .await?
+        .unwrap_or_else(|| UserProfile::empty(user_id));
+    let access = viewer_access(&state, &claims, &[user_id])
+        .await?
+        .remove(&user_id)
+        .unwrap_or(ProfileVisibility::Public);
+
+    Ok(Json(profile.visible_to(access)))
+}
+
+// 사용자 프로필 수정 핸들러 (본인 또는 users.update 권한 보유자)
+// 프로필 전체를 교체하며, visibility에서 생략한 항목은 기본 공개 범위로 저장
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/{id}/profile",
+    tag = "users",
+    summary = "사용자 프로필 수정",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    request_body = UpdateProfileRequest,
+    responses(
+        (status = 200, description = "저장된 프로필", body = UserProfileResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "수정 권한 없음", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_profile(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    Extension(claims): Extension<Claims>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateProfileRequest>,
+) -> AppResult<Json<UserProfileResponse>> {
+    // 권한 확인: 본인 프로필 수정은 허용, 다른 사용자 프로필 수정은 users.update 권한 필요
+    let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
+    if current_user_id != user_id
+        && !RoleService::new(state.db_pool.clone())
+            .has_permission(current_user_id, UsersUpdate::CODE)
+            .await?
+    {
+        return Err(AppError::forbidden("permission_denied", "수정 권한이 없습니다"));
+    }
+
+    UserService::new(state.db_pool.clone())
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    let profile_service = ProfileService::new(state.db_pool.clone());
+    let before = profile_service
+        .find(user_id)
+        .await?
+        .unwrap_or_else(|| UserProfile::empty(user_id));
+    let profile = profile_service.upsert(user_id, &request).await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("user.profile.update", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(json!({
+                    "before": before.visible_to(ProfileVisibility::Admin),
+                    "after": profile.visible_to(ProfileVisibility::Admin),
+                })),
+        )
+        .await;
+
+    Ok(Json(profile.visible_to(ProfileVisibility::Admin)))
+}
+
+// 사용자 응답에 조회자에게 공개된 프로필 항목을 붙임 (프로필을 작성하지 않은 사용자는 생략)
+pub(crate) async fn with_profiles(
+    state: &AppState,
+    claims: &Claims,
+    users: Vec<User>,
+) -> AppResult<Vec<UserResponse>> {
+    let user_ids: Vec<i32> = users.iter().map(|user| user.id).collect();
+    let profile_service = ProfileService::new(state.db_pool.clone());
+
+    let mut profiles: HashMap<i32, UserProfile> = profile_service
+        .find_many(&user_ids)
+        .await?
+        .into_iter()
+        .map(|profile| (profile.user_id, profile))
+        .collect();
+    let access = if profiles.is_empty() {
+        Default::default()
+    } else {
+        viewer_access(state, claims, &user_ids).await?
+    };
+
+    Ok(users
+        .into_iter()
+        .map(|user| {
+            let profile = profiles.remove(&user.id).map(|profile| {
+                profile.visible_to(access.get(&user.id).copied().unwrap_or(ProfileVisibility::Public))
+            });
+            UserResponse {
+                profile,
+                ..UserResponse::from(user)
+            }
+        })
+        .collect())
+}
+
+// 조회자가 각 사용자의 프로필을 볼 수 있는 수준 (users.update 권한 보유자는 관리자 수준)
+async fn viewer_access(
+    state: &AppState,
+    claims: &Claims,
+    user_ids: &[i32],
+) -> AppResult<HashMap<i32, ProfileVisibility>> {
+    let viewer_id: i32 = claims.sub.parse().unwrap_or(0);
+    let viewer_is_admin = RoleService::new(state.db_pool.clone())
+        .has_permission(viewer_id, UsersUpdate::CODE)
+        .await?;
+
+    Ok(ProfileService::new(state.db_pool.clone())
+        .access_levels(viewer_id, viewer_is_admin, user_ids)
+        .await?)
+}
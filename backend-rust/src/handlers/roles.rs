@@ -0,0 +1,332 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Json},
+};
+use serde_json::json;
+
+use crate::{
+    error::{AppError, AppResult},
+    middleware::{RequirePermission, RolesManage, ValidatedJson},
+    models::{
+        AssignRolesRequest, ClientInfo, CreateRoleRequest, ErrorResponse, NewAuditEvent,
+        Permission, RoleResponse, UpdateRoleRequest, UserRolesResponse,
+    },
+    services::{AuditService, RoleService, UserService},
+    AppState,
+};
+
+// 역할 목록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/roles",
+    tag = "roles",
+    summary = "역할 목록 조회",
+    responses(
+        (status = 200, description = "역할 목록", body = Vec<RoleResponse>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "roles.manage 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_roles(
+    State(state): State<AppState>,
+    _: RequirePermission<RolesManage>,
+) -> AppResult<Json<Vec<RoleResponse>>> {
+    let role_service = RoleService::new(state.db_pool);
+
+    let role_responses: Vec<RoleResponse> = role_service
+        .find_all()
+        .await?
+        .into_iter()
+        .map(|(role, permissions)| RoleResponse::new(role, permissions))
+        .collect();
+
+    Ok(Json(role_responses))
+}
+
+// 역할 생성 핸들러
+// 이름 중복은 roles.name 유니크 제약 위반으로 409 응답
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/roles",
+    tag = "roles",
+    summary = "역할 생성",
+    request_body = CreateRoleRequest,
+    responses(
+        (status = 201, description = "생성된 역할", body = RoleResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "roles.manage 권한 필요", body = ErrorResponse),
+        (status = 409, description = "이미 존재하는 역할 (role_exists)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_role(
+    State(state): State<AppState>,
+    RequirePermission(claims, _): RequirePermission<RolesManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<CreateRoleRequest>,
+) -> AppResult<impl IntoResponse> {
+    let role_service = RoleService::new(state.db_pool.clone());
+    check_permissions_exist(&role_service, &request.permissions).await?;
+
+    let (role, permissions) = role_service.create_role(request).await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("role.create", &client)
+                .actor_claims(&claims)
+                .target("role", role.id)
+                .changes(json!({
+                    "name": role.name,
+                    "description": role.description,
+                    "permissions": permissions,
+                })),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(RoleResponse::new(role, permissions))))
+}
+
+// 역할 수정 핸들러
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/roles/{id}",
+    tag = "roles",
+    summary = "역할 수정",
+    params(("id" = i32, Path, description = "역할 ID")),
+    request_body = UpdateRoleRequest,
+    responses(
+        (status = 200, description = "수정된 역할", body = RoleResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "roles.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "역할 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_role(
+    State(state): State<AppState>,
+    Path(role_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<RolesManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateRoleRequest>,
+) -> AppResult<Json<RoleResponse>> {
+    let role_service = RoleService::new(state.db_pool.clone());
+
+    if let Some(ref permissions) = request.permissions {
+        check_permissions_exist(&role_service, permissions).await?;
+    }
+
+    // 감사 로그용 변경 전 상태 조회
+    let before = role_service.find_by_id(role_id).await?;
+
+    let (role, permissions) = role_service
+        .update_role(role_id, request)
+        .await?
+        .ok_or_else(role_not_found)?;
+
+    let (before_description, before_permissions) = before
+        .map(|(role, permissions)| (role.description, permissions))
+        .unwrap_or_default();
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("role.update", &client)
+                .actor_claims(&claims)
+                .target("role", role.id)
+                .changes(json!({
+                    "description": { "before": before_description, "after": role.description },
+                    "permissions": { "before": before_permissions, "after": permissions },
+                })),
+        )
+        .await;
+
+    Ok(Json(RoleResponse::new(role, permissions)))
+}
+
+// 역할 삭제 핸들러 (기본 역할 user/admin은 삭제 불가)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/roles/{id}",
+    tag = "roles",
+    summary = "역할 삭제",
+    params(("id" = i32, Path, description = "역할 ID")),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 400, description = "기본 역할은 삭제 불가", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "roles.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "역할 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_role(
+    State(state): State<AppState>,
+    Path(role_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<RolesManage>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let role_service = RoleService::new(state.db_pool.clone());
+
+    let (role, permissions) = role_service
+        .find_by_id(role_id)
+        .await?
+        .ok_or_else(role_not_found)?;
+    if role.is_system {
+        return Err(AppError::bad_request("system_role", "기본 역할은 삭제할 수 없습니다"));
+    }
+
+    if !role_service.delete_role(role_id).await? {
+        return Err(role_not_found());
+    }
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("role.delete", &client)
+                .actor_claims(&claims)
+                .target("role", role_id)
+                .changes(json!({
+                    "name": role.name,
+                    "description": role.description,
+                    "permissions": permissions,
+                })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 권한 목록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/permissions",
+    tag = "roles",
+    summary = "권한 목록 조회",
+    responses(
+        (status = 200, description = "권한 목록", body = Vec<Permission>),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "roles.manage 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_permissions(
+    State(state): State<AppState>,
+    _: RequirePermission<RolesManage>,
+) -> AppResult<Json<Vec<Permission>>> {
+    let role_service = RoleService::new(state.db_pool);
+
+    Ok(Json(role_service.find_all_permissions().await?))
+}
+
+// 사용자 역할/권한 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/users/{id}/roles",
+    tag = "roles",
+    summary = "사용자 역할/권한 조회",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    responses(
+        (status = 200, description = "사용자 역할/권한", body = UserRolesResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "roles.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_user_roles(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    _: RequirePermission<RolesManage>,
+) -> AppResult<Json<UserRolesResponse>> {
+    user_roles_response(&state, user_id).await
+}
+
+// 사용자 추가 역할 설정 핸들러 (기본 역할은 PUT /users/:id 의 role로 변경)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/users/{id}/roles",
+    tag = "roles",
+    summary = "사용자 추가 역할 설정",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    request_body = AssignRolesRequest,
+    responses(
+        (status = 200, description = "변경된 사용자 역할/권한", body = UserRolesResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "roles.manage 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn set_user_roles(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<RolesManage>,
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<AssignRolesRequest>,
+) -> AppResult<Json<UserRolesResponse>> {
+    let role_service = RoleService::new(state.db_pool.clone());
+
+    let unknown = role_service.unknown_roles(&request.roles).await?;
+    if !unknown.is_empty() {
+        return Err(AppError::bad_request(
+            "invalid_role",
+            format!("존재하지 않는 역할입니다: {}", unknown.join(", ")),
+        ));
+    }
+
+    UserService::new(state.db_pool.clone())
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    let before = role_service.user_roles(user_id).await?;
+    role_service.set_user_roles(user_id, &request.roles).await?;
+
+    AuditService::new(state.db_pool.clone())
+        .record(
+            NewAuditEvent::new("user.roles.update", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(json!({ "roles": { "before": before, "after": request.roles } })),
+        )
+        .await;
+
+    user_roles_response(&state, user_id).await
+}
+
+// 사용자의 기본 역할, 추가 역할, 유효 권한 응답 생성
+async fn user_roles_response(state: &AppState, user_id: i32) -> AppResult<Json<UserRolesResponse>> {
+    let user_service = UserService::new(state.db_pool.clone());
+    let role_service = RoleService::new(state.db_pool.clone());
+
+    let user = user_service
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    Ok(Json(UserRolesResponse {
+        user_id,
+        primary_role: user.role,
+        roles: role_service.user_roles(user_id).await?,
+        permissions: role_service.permissions_for_user(user_id).await?,
+    }))
+}
+
+// 요청된 권한 코드가 모두 존재하는지 확인
+async fn check_permissions_exist(role_service: &RoleService, permissions: &[String]) -> AppResult<()> {
+    let unknown = role_service.unknown_permissions(permissions).await?;
+    if !unknown.is_empty() {
+        return Err(AppError::bad_request(
+            "invalid_permission",
+            format!("존재하지 않는 권한입니다: {}", unknown.join(", ")),
+        ));
+    }
+
+    Ok(())
+}
+
+fn role_not_found() -> AppError {
+    AppError::not_found("role_not_found", "역할을 찾을 수 없습니다")
+}
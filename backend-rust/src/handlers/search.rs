@@ -0,0 +1,58 @@
+use axum::{
+    extract::{OriginalUri, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json},
+};
+use validator::Validate;
+
+use super::{pagination_headers, profiles::with_profiles};
+use crate::{
+    error::AppResult,
+    middleware::{RequirePermission, UsersRead},
+    models::{ErrorResponse, UserSearchHit, UserSearchQuery},
+    services::{highlight_user, SearchService},
+    AppState,
+};
+
+// 사용자 검색 핸들러
+// 이름/이메일/역할의 앞부분·부분 일치, 한글 초성("ㅎㄱㄷ"), 유사한 이름을 관련도 순으로 반환
+// 페이지 정보는 사용자 목록과 같이 헤더로 전달 (X-Total-Count, Link)
+#[utoipa::path(
+    get,
+    path = "/api/v1/search/users",
+    tag = "users",
+    summary = "사용자 검색",
+    params(UserSearchQuery),
+    responses(
+        (status = 200, description = "관련도 순 검색 결과 (페이지 정보는 X-Total-Count, Link 헤더)", body = Vec<UserSearchHit>),
+        (status = 400, description = "검색어 없음 또는 유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "users.read 권한 필요", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn search_users(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    RequirePermission(claims, _): RequirePermission<UsersRead>,
+    Query(query): Query<UserSearchQuery>,
+) -> AppResult<impl IntoResponse> {
+    query.validate()?;
+
+    let (results, total) = SearchService::new(state.db_pool.clone())
+        .search_users(&query)
+        .await?;
+
+    let (users, scores): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+    let highlights: Vec<_> = users.iter().map(|user| highlight_user(user, &query.q)).collect();
+    let hits: Vec<UserSearchHit> = with_profiles(&state, &claims, users)
+        .await?
+        .into_iter()
+        .zip(scores)
+        .zip(highlights)
+        .map(|((user, score), highlight)| UserSearchHit { user, score, highlight })
+        .collect();
+
+    let headers = pagination_headers(uri.path(), &query, query.page, query.per_page, total);
+    Ok((StatusCode::OK, headers, Json(hits)))
+}
@@ -1,194 +1,351 @@
 use axum::{
-    extract::{Path, State},
+    extract::{OriginalUri, Path, Query, State},
     http::StatusCode,
     response::{IntoResponse, Json},
     Extension,
 };
 use validator::Validate;
 
+use super::{pagination_headers, profiles::with_profiles};
 use crate::{
-    models::{Claims, ErrorResponse, UpdateUserRequest, UserResponse},
-    services::UserService,
+    error::{AppError, AppResult},
+    middleware::{
+        PermissionCode, RequirePermission, UsersDelete, UsersRead, UsersUpdate, ValidatedJson,
+    },
+    models::{
+        Claims, ClientInfo, ErrorResponse, NewAuditEvent, UpdateUserRequest, UserListQuery,
+        User, UserLookupQuery, UserResponse,
+    },
+    services::{
+        user_changes, AuditService, LoginThrottleService, RoleService, SessionService,
+        TokenService, UserService,
+    },
     AppState,
 };
 
+// 삭제된 사용자 조회 권한 확인 (include_deleted=true 요청은 users.delete 권한 필요)
+async fn check_include_deleted(state: &AppState, claims: &Claims) -> AppResult<()> {
+    let user_id: i32 = claims.sub.parse().unwrap_or(0);
+    let role_service = RoleService::new(state.db_pool.clone());
+    if !role_service.has_permission(user_id, UsersDelete::CODE).await? {
+        return Err(AppError::forbidden(
+            "permission_denied",
+            format!("삭제된 사용자 조회에는 '{}' 권한이 필요합니다", UsersDelete::CODE),
+        ));
+    }
+    Ok(())
+}
+
+// 사용자 한 명의 응답 (조회자에게 공개된 프로필 항목 포함)
+async fn with_profile(state: &AppState, claims: &Claims, user: User) -> AppResult<UserResponse> {
+    let mut responses = with_profiles(state, claims, vec![user]).await?;
+    Ok(responses.remove(0))
+}
+
 // 사용자 목록 조회 핸들러
+// 응답 본문은 다른 백엔드와 같은 배열 형태를 유지하고, 페이지 정보는 헤더로 전달
+// (X-Total-Count, Link: first/prev/next/last)
+#[utoipa::path(
+    get,
+    path = "/api/v1/users",
+    tag = "users",
+    summary = "사용자 목록 조회",
+    params(UserListQuery),
+    responses(
+        (status = 200, description = "사용자 목록 (페이지 정보는 X-Total-Count, Link 헤더)", body = Vec<UserResponse>),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "users.read 권한 필요 (include_deleted는 users.delete)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn get_users(
     State(state): State<AppState>,
-    Extension(claims): Extension<Claims>,
-) -> impl IntoResponse {
-    let user_service = UserService::new(state.db_pool);
+    OriginalUri(uri): OriginalUri,
+    RequirePermission(claims, _): RequirePermission<UsersRead>,
+    Query(query): Query<UserListQuery>,
+) -> AppResult<impl IntoResponse> {
+    query.validate()?;
+    if query.include_deleted == Some(true) {
+        check_include_deleted(&state, &claims).await?;
+    }
 
-    match user_service.find_all().await {
-        Ok(users) => {
-            let user_responses: Vec<UserResponse> = users
-                .into_iter()
-                .map(UserResponse::from)
-                .collect();
+    let user_service = UserService::new(state.db_pool.clone());
 
-            (StatusCode::OK, Json(user_responses)).into_response()
-        }
-        Err(err) => {
-            tracing::error!("사용자 목록 조회 실패: {}", err);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new("database_error", "서버 오류가 발생했습니다")),
-            ).into_response()
-        }
-    }
+    let (users, total) = user_service.find_page(&query).await?;
+    let user_responses = with_profiles(&state, &claims, users).await?;
+
+    let headers = pagination_headers(uri.path(), &query, query.page, query.per_page, total);
+    Ok((StatusCode::OK, headers, Json(user_responses)))
 }
 
 // 특정 사용자 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}",
+    tag = "users",
+    summary = "사용자 조회",
+    params(("id" = i32, Path, description = "사용자 ID"), UserLookupQuery),
+    responses(
+        (status = 200, description = "사용자 정보", body = UserResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "users.read 권한 필요 (include_deleted는 users.delete)", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn get_user(
     State(state): State<AppState>,
     Path(user_id): Path<i32>,
-    Extension(claims): Extension<Claims>,
-) -> impl IntoResponse {
-    let user_service = UserService::new(state.db_pool);
+    RequirePermission(claims, _): RequirePermission<UsersRead>,
+    Query(query): Query<UserLookupQuery>,
+) -> AppResult<Json<UserResponse>> {
+    let user_service = UserService::new(state.db_pool.clone());
 
-    match user_service.find_by_id(user_id).await {
-        Ok(Some(user)) => {
-            (StatusCode::OK, Json(UserResponse::from(user))).into_response()
-        }
-        Ok(None) => {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse::new("user_not_found", "사용자를 찾을 수 없습니다")),
-            ).into_response()
-        }
-        Err(err) => {
-            tracing::error!("사용자 조회 실패: {}", err);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new("database_error", "서버 오류가 발생했습니다")),
-            ).into_response()
-        }
-    }
+    let user = if query.include_deleted {
+        check_include_deleted(&state, &claims).await?;
+        user_service.find_by_id_including_deleted(user_id).await?
+    } else {
+        user_service.find_by_id(user_id).await?
+    };
+    let user = user.ok_or_else(AppError::user_not_found)?;
+
+    Ok(Json(with_profile(&state, &claims, user).await?))
 }
 
 // 사용자 정보 수정 핸들러
+// 다른 사용자가 쓰는 이메일로 바꾸면 users.email 유니크 제약 위반으로 409 응답
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/{id}",
+    tag = "users",
+    summary = "사용자 정보 수정",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    request_body = UpdateUserRequest,
+    responses(
+        (status = 200, description = "수정된 사용자 정보", body = UserResponse),
+        (status = 400, description = "유효성 검사 실패", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "수정 권한 없음", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+        (status = 409, description = "이미 존재하는 이메일 (email_exists)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn update_user(
     State(state): State<AppState>,
     Path(user_id): Path<i32>,
     Extension(claims): Extension<Claims>,
-    Json(request): Json<UpdateUserRequest>,
-) -> impl IntoResponse {
-    // 요청 데이터 유효성 검사
-    if let Err(errors) = request.validate() {
-        let error_messages: Vec<String> = errors
-            .field_errors()
-            .into_iter()
-            .flat_map(|(_, field_errors)| {
-                field_errors.iter().map(|error| {
-                    error.message.as_ref().unwrap_or(&"유효성 검사 실패".into()).to_string()
-                })
-            })
-            .collect();
-        
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("validation_error", &error_messages.join(", "))),
-        ).into_response();
-    }
-
-    // 권한 확인: 본인 또는 관리자만 수정 가능
+    client: ClientInfo,
+    ValidatedJson(request): ValidatedJson<UpdateUserRequest>,
+) -> AppResult<Json<UserResponse>> {
+    // 권한 확인: 본인 정보 수정은 허용, 다른 사용자 수정이나 역할/활성 상태 변경은 users.update 권한 필요
     let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
-    if current_user_id != user_id && claims.role != "admin" {
-        return (
-            StatusCode::FORBIDDEN,
-            Json(ErrorResponse::new("permission_denied", "수정 권한이 없습니다")),
-        ).into_response();
+    let role_service = RoleService::new(state.db_pool.clone());
+    let needs_permission =
+        current_user_id != user_id || request.role.is_some() || request.is_active.is_some();
+    if needs_permission && !role_service.has_permission(current_user_id, UsersUpdate::CODE).await? {
+        return Err(AppError::forbidden("permission_denied", "수정 권한이 없습니다"));
     }
 
-    let user_service = UserService::new(state.db_pool);
-
-    // 이메일 중복 확인 (이메일 수정 시)
-    if let Some(ref email) = request.email {
-        match user_service.find_by_email(email).await {
-            Ok(Some(existing_user)) => {
-                // 다른 사용자가 이미 해당 이메일을 사용 중인지 확인
-                if existing_user.id != user_id {
-                    return (
-                        StatusCode::CONFLICT,
-                        Json(ErrorResponse::new("email_exists", "이미 존재하는 이메일입니다")),
-                    ).into_response();
-                }
-            }
-            Ok(None) => {
-                // 이메일이 존재하지 않음 (정상)
-            }
-            Err(err) => {
-                tracing::error!("데이터베이스 오류: {}", err);
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse::new("database_error", "서버 오류가 발생했습니다")),
-                ).into_response();
-            }
+    // 역할 존재 여부 확인 (역할 변경 시)
+    if let Some(ref role) = request.role {
+        if !role_service.role_exists(role).await? {
+            return Err(AppError::bad_request("invalid_role", "존재하지 않는 역할입니다"));
         }
     }
 
+    let user_service = UserService::new(state.db_pool.clone());
+
+    // 감사 로그용 변경 전 상태 조회
+    let before = user_service
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
     // 사용자 정보 수정
-    match user_service.update_user(user_id, request).await {
-        Ok(Some(user)) => {
-            (StatusCode::OK, Json(UserResponse::from(user))).into_response()
-        }
-        Ok(None) => {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse::new("user_not_found", "사용자를 찾을 수 없습니다")),
-            ).into_response()
+    let deactivated = request.is_active == Some(false);
+    let user = user_service
+        .update_user(user_id, request)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    // 비활성화된 사용자의 기존 세션 즉시 차단
+    if deactivated {
+        let session_service = SessionService::new(state.db_pool.clone(), state.session_cache.clone());
+        let token_service = TokenService::new(state.db_pool.clone(), state.config.clone());
+        session_service.invalidate_user(user_id);
+        if let Err(err) = token_service.revoke_all_for_user(user_id).await {
+            tracing::error!("리프레시 토큰 폐기 실패: {}", err);
         }
-        Err(err) => {
-            tracing::error!("사용자 수정 실패: {}", err);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new("update_error", "사용자 정보 수정에 실패했습니다")),
-            ).into_response()
+    }
+
+    // 이메일이 바뀌었으면 새 주소로 인증 메일 발송
+    if before.email != user.email {
+        if let Err(err) = super::auth::send_verification_email(&state, &user).await {
+            tracing::error!("인증 메일 발송 실패: {}", err);
         }
     }
+
+    AuditService::new(state.db_pool.clone())
+        .record(
+            NewAuditEvent::new("user.update", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(user_changes(&before, &user)),
+        )
+        .await;
+
+    Ok(Json(with_profile(&state, &claims, user).await?))
 }
 
-// 사용자 삭제 핸들러 (관리자 전용)
+// 사용자 삭제 핸들러 (users.delete 권한 필요)
+// 소프트 삭제 후 USER_PURGE_RETENTION_DAYS 동안 복구할 수 있고, 이후 영구 삭제
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/users/{id}",
+    tag = "users",
+    summary = "사용자 삭제 (보관 기간 동안 복구 가능)",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 400, description = "자기 자신은 삭제 불가", body = ErrorResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "users.delete 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn delete_user(
     State(state): State<AppState>,
     Path(user_id): Path<i32>,
-    Extension(claims): Extension<Claims>,
-) -> impl IntoResponse {
-    // 관리자 권한 확인 (미들웨어에서 이미 확인하지만 추가 보안)
-    if claims.role != "admin" {
-        return (
-            StatusCode::FORBIDDEN,
-            Json(ErrorResponse::new("permission_denied", "관리자 권한이 필요합니다")),
-        ).into_response();
-    }
-
+    RequirePermission(claims, _): RequirePermission<UsersDelete>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
     // 자기 자신은 삭제할 수 없음
     let current_user_id: i32 = claims.sub.parse().unwrap_or(0);
     if current_user_id == user_id {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("self_delete_error", "자기 자신은 삭제할 수 없습니다")),
-        ).into_response();
+        return Err(AppError::bad_request("self_delete_error", "자기 자신은 삭제할 수 없습니다"));
     }
 
-    let user_service = UserService::new(state.db_pool);
+    let user_service = UserService::new(state.db_pool.clone());
+    let audit_service = AuditService::new(state.db_pool.clone());
+    let session_service = SessionService::new(state.db_pool.clone(), state.session_cache.clone());
+    let token_service = TokenService::new(state.db_pool.clone(), state.config.clone());
 
-    match user_service.delete_user(user_id).await {
-        Ok(true) => {
-            (StatusCode::NO_CONTENT, Json(())).into_response()
-        }
-        Ok(false) => {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse::new("user_not_found", "사용자를 찾을 수 없습니다")),
-            ).into_response()
-        }
-        Err(err) => {
-            tracing::error!("사용자 삭제 실패: {}", err);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new("delete_error", "사용자 삭제에 실패했습니다")),
-            ).into_response()
-        }
+    // 삭제된 사용자 정보를 감사 로그에 남기기 위해 먼저 조회
+    let before = user_service.find_by_id(user_id).await?;
+
+    if !user_service.delete_user(user_id).await? {
+        return Err(AppError::user_not_found());
+    }
+
+    // 복구 후 이전 세션이 되살아나지 않도록 액세스 토큰(전체 세션 폐기 기록)과 리프레시 토큰을 모두 폐기
+    session_service.revoke_all(user_id).await?;
+    if let Err(err) = token_service.revoke_all_for_user(user_id).await {
+        tracing::error!("리프레시 토큰 폐기 실패: {}", err);
+    }
+
+    let mut event = NewAuditEvent::new("user.delete", &client)
+        .actor_claims(&claims)
+        .target("user", user_id);
+    if let Some(before) = before {
+        event = event.changes(serde_json::json!({ "before": before }));
+    }
+    audit_service.record(event).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 계정 잠금 해제 핸들러 (users.update 권한 필요)
+// 로그인 실패 횟수를 초기화해 즉시 다시 로그인할 수 있도록 한다
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/users/{id}/unlock",
+    tag = "users",
+    summary = "계정 잠금 해제",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    responses(
+        (status = 204, description = "잠금 해제 완료"),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "users.update 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn unlock_user(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<UsersUpdate>,
+    client: ClientInfo,
+) -> AppResult<StatusCode> {
+    let user_service = UserService::new(state.db_pool.clone());
+    let throttle_service = LoginThrottleService::new(state.db_pool.clone(), state.config.login_throttle.clone());
+
+    let user = user_service
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    throttle_service.unlock(&user.email).await?;
+
+    AuditService::new(state.db_pool)
+        .record(
+            NewAuditEvent::new("user.unlock", &client)
+                .actor_claims(&claims)
+                .target("user", user_id),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 삭제된 사용자 복구 핸들러 (users.delete 권한 필요)
+// 삭제 후 같은 이메일로 다시 가입한 사용자가 있으면 409 email_exists 응답
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/users/{id}/restore",
+    tag = "users",
+    summary = "삭제된 사용자 복구",
+    params(("id" = i32, Path, description = "사용자 ID")),
+    responses(
+        (status = 200, description = "복구된 사용자 정보", body = UserResponse),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+        (status = 403, description = "users.delete 권한 필요", body = ErrorResponse),
+        (status = 404, description = "사용자 없음 (영구 삭제됨)", body = ErrorResponse),
+        (status = 409, description = "삭제되지 않은 사용자 (user_not_deleted) 또는 이메일 사용 중 (email_exists)", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn restore_user(
+    State(state): State<AppState>,
+    Path(user_id): Path<i32>,
+    RequirePermission(claims, _): RequirePermission<UsersDelete>,
+    client: ClientInfo,
+) -> AppResult<Json<UserResponse>> {
+    let user_service = UserService::new(state.db_pool.clone());
+
+    let before = user_service
+        .find_by_id_including_deleted(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+    if before.deleted_at.is_none() {
+        return Err(AppError::conflict("user_not_deleted", "삭제되지 않은 사용자입니다"));
     }
-} 
\ No newline at end of file
+
+    let user = user_service
+        .restore_user(user_id)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    AuditService::new(state.db_pool.clone())
+        .record(
+            NewAuditEvent::new("user.restore", &client)
+                .actor_claims(&claims)
+                .target("user", user_id)
+                .changes(user_changes(&before, &user)),
+        )
+        .await;
+
+    Ok(Json(with_profile(&state, &claims, user).await?))
+}
@@ -0,0 +1,652 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::fmt;
+
+// 생성하는 VCALENDAR의 PRODID
+const PRODID: &str = "-//Sample Intranet//Calendar//KO";
+
+// 내용 줄 최대 길이 (CRLF 제외, 옥텟 단위)
+const MAX_LINE_OCTETS: usize = 75;
+
+// 가져올 수 있는 최대 일정 수
+pub const MAX_IMPORT_EVENTS: usize = 1000;
+
+// 일정 시각 (하루 종일 일정은 날짜, 나머지는 UTC 시각)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventTime {
+    Date(NaiveDate),
+    DateTime(DateTime<Utc>),
+}
+
+impl EventTime {
+    // 날짜는 UTC 자정으로 변환
+    pub fn to_utc(self) -> DateTime<Utc> {
+        match self {
+            Self::Date(date) => date.and_hms_opt(0, 0, 0).unwrap().and_utc(),
+            Self::DateTime(time) => time,
+        }
+    }
+}
+
+// 일정 상태 (STATUS)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventStatus {
+    Confirmed,
+    Tentative,
+    Cancelled,
+}
+
+impl EventStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Confirmed => "CONFIRMED",
+            Self::Tentative => "TENTATIVE",
+            Self::Cancelled => "CANCELLED",
+        }
+    }
+}
+
+// VEVENT 한 개 (종료 시각은 포함하지 않음, 하루 종일 일정은 마지막 날의 다음 날)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub start: EventTime,
+    pub end: EventTime,
+    pub status: Option<EventStatus>,
+    pub categories: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+// 구독용 VCALENDAR 생성 (RFC 5545, CRLF 줄바꿈, 75옥텟 줄 접기)
+// 시각은 모두 UTC로 내보내므로 VTIMEZONE은 넣지 않음
+pub fn write_calendar(name: &str, events: &[Event], stamp: DateTime<Utc>) -> String {
+    let mut output = String::new();
+    let mut line = |content: String| push_folded(&mut output, &content);
+
+    line("BEGIN:VCALENDAR".to_string());
+    line("VERSION:2.0".to_string());
+    line(format!("PRODID:{}", PRODID));
+    line("CALSCALE:GREGORIAN".to_string());
+    line("METHOD:PUBLISH".to_string());
+    line(format!("X-WR-CALNAME:{}", escape_text(name)));
+
+    for event in events {
+        line("BEGIN:VEVENT".to_string());
+        line(format!("UID:{}", escape_text(&event.uid)));
+        line(format!("DTSTAMP:{}", format_utc(stamp)));
+        line(format_time("DTSTART", event.start));
+        line(format_time("DTEND", event.end));
+        line(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if let Some(description) = &event.description {
+            line(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        if let Some(location) = &event.location {
+            line(format!("LOCATION:{}", escape_text(location)));
+        }
+        if let Some(status) = event.status {
+            line(format!("STATUS:{}", status.as_str()));
+        }
+        if let Some(categories) = &event.categories {
+            line(format!("CATEGORIES:{}", escape_text(categories)));
+        }
+        if let Some(last_modified) = event.last_modified {
+            line(format!("LAST-MODIFIED:{}", format_utc(last_modified)));
+        }
+        // 하루 종일 일정은 바쁨으로 표시하지 않음
+        if matches!(event.start, EventTime::Date(_)) {
+            line("TRANSP:TRANSPARENT".to_string());
+        }
+        line("END:VEVENT".to_string());
+    }
+
+    line("END:VCALENDAR".to_string());
+    output
+}
+
+fn format_utc(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn format_time(name: &str, time: EventTime) -> String {
+    match time {
+        EventTime::Date(date) => format!("{};VALUE=DATE:{}", name, date.format("%Y%m%d")),
+        EventTime::DateTime(time) => format!("{}:{}", name, format_utc(time)),
+    }
+}
+
+// TEXT 값 이스케이프 (역슬래시, 세미콜론, 쉼표, 줄바꿈)
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// 75옥텟을 넘는 줄은 UTF-8 문자 경계에서 나눠 다음 줄을 공백으로 시작
+fn push_folded(output: &mut String, content: &str) {
+    let mut limit = MAX_LINE_OCTETS;
+    let mut octets = 0;
+    for c in content.chars() {
+        if octets + c.len_utf8() > limit {
+            output.push_str("\r\n ");
+            // 이어지는 줄은 맨 앞 공백도 길이에 포함
+            limit = MAX_LINE_OCTETS - 1;
+            octets = 0;
+        }
+        output.push(c);
+        octets += c.len_utf8();
+    }
+    output.push_str("\r\n");
+}
+
+// iCalendar 파일 전체를 읽을 수 없는 오류
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ICalError {
+    #[error("VCALENDAR가 없습니다")]
+    MissingCalendar,
+
+    #[error("{line}번째 줄의 형식이 올바르지 않습니다")]
+    Syntax { line: usize },
+
+    #[error("BEGIN/END가 맞지 않습니다 ({0})")]
+    Unbalanced(String),
+
+    #[error("일정은 한 번에 최대 {}개까지 가져올 수 있습니다", MAX_IMPORT_EVENTS)]
+    TooManyEvents,
+}
+
+// 가져오지 않은 일정의 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    MissingUid,
+    MissingStart,
+    InvalidTime(String),
+    UnknownTimeZone(String),
+    EmptyRange,
+    Recurring,
+    Cancelled,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingUid => write!(f, "UID가 없습니다"),
+            Self::MissingStart => write!(f, "DTSTART가 없습니다"),
+            Self::InvalidTime(value) => write!(f, "시각 형식이 올바르지 않습니다 ({})", value),
+            Self::UnknownTimeZone(tzid) => write!(f, "알 수 없는 시간대입니다 ({})", tzid),
+            Self::EmptyRange => write!(f, "종료 시각이 시작 시각 이후여야 합니다"),
+            Self::Recurring => write!(f, "반복 일정(RRULE)은 가져오지 않습니다"),
+            Self::Cancelled => write!(f, "취소된 일정입니다"),
+        }
+    }
+}
+
+// 가져오지 않은 일정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Skipped {
+    pub uid: Option<String>,
+    pub summary: Option<String>,
+    pub reason: SkipReason,
+}
+
+// 파싱 결과
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Parsed {
+    pub events: Vec<Event>,
+    pub skipped: Vec<Skipped>,
+}
+
+// 내용 줄 (이름, 파라미터, 값)
+struct ContentLine<'a> {
+    name: String,
+    params: Vec<(String, String)>,
+    value: &'a str,
+}
+
+impl ContentLine<'_> {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+// VEVENT를 읽는 동안 모은 속성
+#[derive(Default)]
+struct EventBuilder {
+    uid: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    start: Option<Result<EventTime, SkipReason>>,
+    end: Option<Result<EventTime, SkipReason>>,
+    duration: Option<Result<Duration, SkipReason>>,
+    recurring: bool,
+    cancelled: bool,
+}
+
+impl EventBuilder {
+    fn build(self) -> Result<Event, Skipped> {
+        let skipped = |reason| Skipped {
+            uid: self.uid.clone(),
+            summary: self.summary.clone(),
+            reason,
+        };
+
+        let uid = self.uid.clone().ok_or_else(|| skipped(SkipReason::MissingUid))?;
+        if self.cancelled {
+            return Err(skipped(SkipReason::Cancelled));
+        }
+        if self.recurring {
+            return Err(skipped(SkipReason::Recurring));
+        }
+        let start = self
+            .start
+            .clone()
+            .ok_or_else(|| skipped(SkipReason::MissingStart))?
+            .map_err(skipped)?;
+
+        // DTEND가 없으면 DURATION, 둘 다 없으면 날짜 일정은 하루 (RFC 5545 3.6.1)
+        let end = match (self.end.clone(), self.duration.clone(), start) {
+            (Some(end), _, _) => end.map_err(skipped)?,
+            (None, Some(duration), EventTime::Date(date)) => {
+                EventTime::Date(date + Duration::days(duration.map_err(skipped)?.num_days()))
+            }
+            (None, Some(duration), EventTime::DateTime(time)) => EventTime::DateTime(time + duration.map_err(skipped)?),
+            (None, None, EventTime::Date(date)) => EventTime::Date(date + Duration::days(1)),
+            (None, None, EventTime::DateTime(time)) => EventTime::DateTime(time),
+        };
+        if end.to_utc() <= start.to_utc() {
+            return Err(skipped(SkipReason::EmptyRange));
+        }
+
+        Ok(Event {
+            uid,
+            summary: self.summary.unwrap_or_default(),
+            description: self.description.filter(|text| !text.is_empty()),
+            location: self.location.filter(|text| !text.is_empty()),
+            start,
+            end,
+            status: None,
+            categories: None,
+            last_modified: None,
+        })
+    }
+}
+
+// iCalendar 파일에서 VEVENT 읽기
+// TZID가 없는 시각은 X-WR-TIMEZONE, 그것도 없으면 UTC 기준으로 해석
+pub fn parse_calendar(input: &str) -> Result<Parsed, ICalError> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let lines = unfold(input);
+
+    let mut parsed = Parsed::default();
+    let mut stack: Vec<String> = Vec::new();
+    let mut default_zone: Option<Tz> = None;
+    let mut event: Option<EventBuilder> = None;
+    let mut found_calendar = false;
+
+    for (number, raw) in &lines {
+        if raw.trim().is_empty() {
+            continue;
+        }
+        let line = parse_line(raw).ok_or(ICalError::Syntax { line: *number })?;
+
+        match line.name.as_str() {
+            "BEGIN" => {
+                let component = line.value.trim().to_ascii_uppercase();
+                if stack.is_empty() && component != "VCALENDAR" {
+                    return Err(ICalError::MissingCalendar);
+                }
+                if component == "VCALENDAR" {
+                    found_calendar = true;
+                }
+                // VCALENDAR 바로 아래의 VEVENT만 일정으로 읽음 (VALARM 등 하위 구성요소는 무시)
+                if component == "VEVENT" && stack.len() == 1 {
+                    if parsed.events.len() + parsed.skipped.len() >= MAX_IMPORT_EVENTS {
+                        return Err(ICalError::TooManyEvents);
+                    }
+                    event = Some(EventBuilder::default());
+                }
+                stack.push(component);
+                continue;
+            }
+            "END" => {
+                let component = line.value.trim().to_ascii_uppercase();
+                if stack.pop().as_deref() != Some(component.as_str()) {
+                    return Err(ICalError::Unbalanced(component));
+                }
+                if component == "VEVENT" && stack.len() == 1 {
+                    if let Some(builder) = event.take() {
+                        match builder.build() {
+                            Ok(built) => parsed.events.push(built),
+                            Err(skipped) => parsed.skipped.push(skipped),
+                        }
+                    }
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        match (stack.len(), event.as_mut()) {
+            (1, _) if line.name == "X-WR-TIMEZONE" => default_zone = line.value.trim().parse().ok(),
+            (2, Some(builder)) => match line.name.as_str() {
+                "UID" => builder.uid = Some(unescape_text(line.value)).filter(|uid| !uid.is_empty()),
+                "SUMMARY" => builder.summary = Some(unescape_text(line.value)),
+                "DESCRIPTION" => builder.description = Some(unescape_text(line.value)),
+                "LOCATION" => builder.location = Some(unescape_text(line.value)),
+                "DTSTART" => builder.start = Some(parse_time(&line, default_zone)),
+                "DTEND" => builder.end = Some(parse_time(&line, default_zone)),
+                "DURATION" => builder.duration = Some(parse_duration(line.value)),
+                "RRULE" | "RDATE" => builder.recurring = true,
+                "STATUS" => builder.cancelled = line.value.trim().eq_ignore_ascii_case("CANCELLED"),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    if let Some(component) = stack.pop() {
+        return Err(ICalError::Unbalanced(component));
+    }
+    if !found_calendar {
+        return Err(ICalError::MissingCalendar);
+    }
+    Ok(parsed)
+}
+
+// 접힌 줄 펼치기 (CRLF 또는 LF 뒤 공백/탭으로 시작하는 줄은 앞 줄에 이어 붙임)
+// 반환하는 줄 번호는 원본 파일 기준
+fn unfold(input: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (index, line) in input.split('\n').enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some((_, previous))) => previous.push_str(continuation),
+            _ => lines.push((index + 1, line.to_string())),
+        }
+    }
+    lines
+}
+
+// NAME;PARAM=VALUE;PARAM="VALUE":값
+fn parse_line(line: &str) -> Option<ContentLine<'_>> {
+    let mut params = Vec::new();
+    let mut in_quotes = false;
+    let mut segment_start = 0;
+    let mut name: Option<String> = None;
+
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' | ':' if !in_quotes => {
+                let segment = &line[segment_start..index];
+                match &name {
+                    None => name = Some(segment.to_ascii_uppercase()),
+                    Some(_) => {
+                        let (key, value) = segment.split_once('=')?;
+                        params.push((key.to_ascii_uppercase(), value.trim_matches('"').to_string()));
+                    }
+                }
+                segment_start = index + 1;
+                if c == ':' {
+                    let name = name?;
+                    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                        return None;
+                    }
+                    return Some(ContentLine {
+                        name,
+                        params,
+                        value: &line[index + 1..],
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// DATE(20240101), UTC(20240101T090000Z), TZID 또는 기본 시간대 기준 현지 시각(20240101T090000)
+fn parse_time(line: &ContentLine, default_zone: Option<Tz>) -> Result<EventTime, SkipReason> {
+    let value = line.value.trim();
+    let invalid = || SkipReason::InvalidTime(value.to_string());
+
+    if line.param("VALUE").is_some_and(|kind| kind.eq_ignore_ascii_case("DATE")) || value.len() == 8 {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map(EventTime::Date)
+            .map_err(|_| invalid());
+    }
+
+    if let Some(utc) = value.strip_suffix('Z') {
+        let local = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+        return Ok(EventTime::DateTime(local.and_utc()));
+    }
+
+    let local = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+    let zone = match line.param("TZID") {
+        Some(tzid) => tzid
+            .trim_start_matches('/')
+            .parse::<Tz>()
+            .map_err(|_| SkipReason::UnknownTimeZone(tzid.to_string()))?,
+        None => default_zone.unwrap_or(Tz::UTC),
+    };
+    // 일광 절약 시간 전환으로 두 번 있는 시각은 앞의 것, 없는 시각은 오류
+    zone.from_local_datetime(&local)
+        .earliest()
+        .map(|time| EventTime::DateTime(time.with_timezone(&Utc)))
+        .ok_or_else(invalid)
+}
+
+// DURATION 값 (P1D, PT1H30M, P1W 등, 음수는 허용하지 않음)
+fn parse_duration(value: &str) -> Result<Duration, SkipReason> {
+    let value = value.trim();
+    let invalid = || SkipReason::InvalidTime(value.to_string());
+    let body = value.strip_prefix('+').unwrap_or(value).strip_prefix('P').ok_or_else(invalid)?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in body.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' if !in_time && number.is_empty() => in_time = true,
+            _ => {
+                let amount: i64 = number.parse().map_err(|_| invalid())?;
+                number.clear();
+                total += match (c, in_time) {
+                    ('W', false) => Duration::weeks(amount),
+                    ('D', false) => Duration::days(amount),
+                    ('H', true) => Duration::hours(amount),
+                    ('M', true) => Duration::minutes(amount),
+                    ('S', true) => Duration::seconds(amount),
+                    _ => return Err(invalid()),
+                };
+            }
+        }
+    }
+    if !number.is_empty() {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+// TEXT 값 이스케이프 해제
+fn unescape_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => text.push('\n'),
+            Some(other) => text.push(other),
+            None => text.push('\\'),
+        }
+    }
+    text.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+    }
+
+    fn utc(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn writes_escaped_and_folded_lines() {
+        let event = Event {
+            uid: "booking-1@sample-intranet".to_string(),
+            summary: "회의; 주간, 점검\\".to_string(),
+            description: Some("첫 줄\n둘째 줄 ".repeat(8)),
+            location: None,
+            start: EventTime::DateTime(utc("2024-07-01T00:00:00Z")),
+            end: EventTime::DateTime(utc("2024-07-01T01:00:00Z")),
+            status: Some(EventStatus::Tentative),
+            categories: None,
+            last_modified: None,
+        };
+        let holiday = Event {
+            uid: "holiday".to_string(),
+            summary: "광복절".to_string(),
+            start: EventTime::Date(date("2024-08-15")),
+            end: EventTime::Date(date("2024-08-16")),
+            status: None,
+            description: None,
+            ..event.clone()
+        };
+        let output = write_calendar("팀 달력", &[event, holiday], utc("2024-06-01T12:00:00Z"));
+
+        assert!(output.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(output.ends_with("END:VCALENDAR\r\n"));
+        assert!(output.contains("\r\nSUMMARY:회의\\; 주간\\, 점검\\\\\r\n"));
+        assert!(output.contains("\r\nDTSTAMP:20240601T120000Z\r\n"));
+        assert!(output.contains("\r\nDTSTART:20240701T000000Z\r\nDTEND:20240701T010000Z\r\n"));
+        assert!(output.contains("\r\nSTATUS:TENTATIVE\r\n"));
+        assert!(output.contains("\r\nDTSTART;VALUE=DATE:20240815\r\nDTEND;VALUE=DATE:20240816\r\n"));
+        assert!(output.contains("\r\nTRANSP:TRANSPARENT\r\n"));
+
+        for line in output.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS, "{} 옥텟 초과: {}", MAX_LINE_OCTETS, line);
+            assert!(!line.contains('\n') && !line.contains('\r'));
+        }
+        assert!(output.contains("\r\n "));
+    }
+
+    #[test]
+    fn round_trips_written_calendar() {
+        let event = Event {
+            uid: "event-7@sample-intranet".to_string(),
+            summary: "창립 기념일, 전사 휴무".to_string(),
+            description: Some("오전 10시\n본관 강당".repeat(5)),
+            location: Some("본관; 강당".to_string()),
+            start: EventTime::Date(date("2024-10-01")),
+            end: EventTime::Date(date("2024-10-02")),
+            status: None,
+            categories: None,
+            last_modified: None,
+        };
+        let parsed = parse_calendar(&write_calendar("회사", std::slice::from_ref(&event), Utc::now())).unwrap();
+
+        assert_eq!(parsed.events, vec![event]);
+        assert!(parsed.skipped.is_empty());
+    }
+
+    #[test]
+    fn parses_times_and_skips_unsupported_events() {
+        let input = [
+            "BEGIN:VCALENDAR",
+            "X-WR-TIMEZONE:Asia/Seoul",
+            "BEGIN:VEVENT",
+            "UID:new-year",
+            "DTSTART;VALUE=DATE:20250101",
+            "SUMMARY:신정",
+            "BEGIN:VALARM",
+            "DTSTART:20240101T000000Z",
+            "END:VALARM",
+            "END:VEVENT",
+            "BEGIN:VEVENT",
+            "UID:workshop",
+            "DTSTART;TZID=America/New_York:20250704T090000",
+            "DURATION:PT1H30M",
+            "SUMMARY:Work",
+            " shop",
+            "END:VEVENT",
+            "BEGIN:VEVENT",
+            "UID:floating",
+            "DTSTART:20250301T090000",
+            "DTEND:20250301T100000",
+            "END:VEVENT",
+            "BEGIN:VEVENT",
+            "UID:weekly",
+            "DTSTART:20250101T000000Z",
+            "DTEND:20250101T010000Z",
+            "RRULE:FREQ=WEEKLY",
+            "END:VEVENT",
+            "BEGIN:VEVENT",
+            "UID:windows",
+            "DTSTART;TZID=Korea Standard Time:20250101T090000",
+            "END:VEVENT",
+            "BEGIN:VEVENT",
+            "DTSTART;VALUE=DATE:20250101",
+            "END:VEVENT",
+            "END:VCALENDAR",
+        ]
+        .join("\n");
+        let parsed = parse_calendar(&input).unwrap();
+
+        assert_eq!(parsed.events.len(), 3);
+        assert_eq!(parsed.events[0].start, EventTime::Date(date("2025-01-01")));
+        assert_eq!(parsed.events[0].end, EventTime::Date(date("2025-01-02")));
+        assert_eq!(parsed.events[1].summary, "Workshop");
+        assert_eq!(parsed.events[1].start, EventTime::DateTime(utc("2025-07-04T13:00:00Z")));
+        assert_eq!(parsed.events[1].end, EventTime::DateTime(utc("2025-07-04T14:30:00Z")));
+        assert_eq!(parsed.events[2].start, EventTime::DateTime(utc("2025-03-01T00:00:00Z")));
+
+        let reasons: Vec<_> = parsed.skipped.iter().map(|skipped| skipped.reason.clone()).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                SkipReason::Recurring,
+                SkipReason::UnknownTimeZone("Korea Standard Time".to_string()),
+                SkipReason::MissingUid,
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert_eq!(parse_calendar("hello"), Err(ICalError::Syntax { line: 1 }));
+        assert_eq!(parse_calendar(""), Err(ICalError::MissingCalendar));
+        assert_eq!(parse_calendar("BEGIN:VEVENT\nEND:VEVENT"), Err(ICalError::MissingCalendar));
+        assert_eq!(
+            parse_calendar("BEGIN:VCALENDAR\nBEGIN:VEVENT\nEND:VCALENDAR"),
+            Err(ICalError::Unbalanced("VCALENDAR".to_string()))
+        );
+        assert_eq!(
+            parse_calendar("BEGIN:VCALENDAR\nBEGIN:VEVENT\n"),
+            Err(ICalError::Unbalanced("VEVENT".to_string()))
+        );
+    }
+}
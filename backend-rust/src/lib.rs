@@ -0,0 +1,416 @@
+use axum::{extract::DefaultBodyLimit, response::Json, Router};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tower_http::cors::CorsLayer;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use routing::{delete, get, post, put, ApiRouter};
+
+pub mod config;
+pub mod database;
+pub mod error;
+pub mod handlers;
+pub mod ical;
+pub mod mail;
+pub mod markdown;
+pub mod middleware;
+pub mod models;
+pub mod openapi;
+pub mod routing;
+pub mod rrule;
+pub mod services;
+pub mod storage;
+
+// 애플리케이션 상태
+#[derive(Clone)]
+pub struct AppState {
+    pub db_pool: sqlx::PgPool,
+    pub config: config::Config,
+    pub mailer: Arc<dyn mail::MailSender>,
+    pub session_cache: Arc<services::SessionCache>,
+    pub blob_store: Arc<dyn storage::BlobStore>,
+}
+
+// 라우터 생성
+pub fn create_router(state: AppState) -> Router {
+    routes(&state)
+        .into_router()
+        // OpenAPI 문서 (/api/v1/openapi.json) 및 Swagger UI (/api/v1/docs)
+        .merge(SwaggerUi::new("/api/v1/docs").url("/api/v1/openapi.json", openapi::ApiDoc::openapi()))
+        // 추적 ID 부여 및 에러 응답 형식 협상 (problem+json)
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::error_format_middleware,
+        ))
+        .layer(CorsLayer::permissive()) // 개발 환경용 CORS 설정
+        .with_state(state)
+}
+
+// 문서화 대상 라우트 (OpenAPI 문서와 일치하는지 openapi.rs 테스트에서 확인)
+fn routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        // 헬스체크 엔드포인트
+        .route("/health", get(health_check))
+        // API v1 라우트
+        .nest("/api/v1", api_routes(state))
+}
+
+// API 라우트 정의
+fn api_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        // 인증 라우트
+        .nest("/auth", auth_routes(state))
+        // 사용자 라우트 (인증 필요)
+        .nest("/users", user_routes(state))
+        // 부서 및 조직도 라우트 (인증 필요)
+        .nest("/departments", department_routes(state))
+        .nest("/org-chart", org_chart_routes(state))
+        // 공지사항 피드 (인증 필요)
+        .nest("/announcements", announcement_routes(state))
+        // 휴가 종류, 잔여 일수, 신청/승인, 팀 휴가 달력 (인증 필요)
+        .nest("/leave-types", leave_type_routes(state))
+        .nest("/leave-balances", leave_balance_routes(state))
+        .nest("/leave-requests", leave_request_routes(state))
+        .nest("/leave-calendar", leave_calendar_routes(state))
+        // 예약 자원, 예약/체크인 (인증 필요)
+        .nest("/resources", resource_routes(state))
+        .nest("/bookings", booking_routes(state))
+        // 회사 일정, 달력 구독 (구독 피드는 토큰으로 인증 없이 조회)
+        .nest("/calendar", calendar_routes(state))
+        // 문서함 폴더, 문서 업로드/버전/내려받기 (인증 필요)
+        .nest("/documents", document_routes(state))
+        // 사용자 검색 (인증 필요)
+        .nest("/search", search_routes(state))
+        // 프로필 사진 (인증 없이 조회)
+        .nest("/avatars", avatar_routes())
+        // 관리자 라우트 (관리자 권한 필요)
+        .nest("/admin", admin_routes(state))
+}
+
+// 인증 라우트
+fn auth_routes(state: &AppState) -> ApiRouter {
+    // 로그아웃은 유효한 액세스 토큰이 필요
+    let session_routes = ApiRouter::new()
+        .route("/logout", post(handlers::auth::logout))
+        .route("/logout-all", post(handlers::auth::logout_all))
+        .route("/mfa", get(handlers::mfa::get_mfa_status))
+        .route("/mfa/enroll", post(handlers::mfa::enroll_mfa))
+        .route("/mfa/confirm", post(handlers::mfa::confirm_mfa))
+        .route("/mfa/recovery-codes", post(handlers::mfa::regenerate_recovery_codes))
+        .route("/mfa/disable", post(handlers::mfa::disable_mfa))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ));
+
+    ApiRouter::new()
+        .route("/register", post(handlers::auth::register))
+        .route("/login", post(handlers::auth::login))
+        .route("/mfa/verify", post(handlers::auth::verify_mfa))
+        .route("/refresh", post(handlers::auth::refresh))
+        .route("/password/forgot", post(handlers::auth::forgot_password))
+        .route("/password/reset", post(handlers::auth::reset_password))
+        .route("/email/verify", post(handlers::auth::verify_email))
+        .route("/email/resend", post(handlers::auth::resend_verification))
+        .merge(session_routes)
+}
+
+// 사용자 라우트
+fn user_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/", get(handlers::users::get_users))
+        .route("/:id", get(handlers::users::get_user))
+        .route("/:id", put(handlers::users::update_user))
+        .route(
+            "/:id/profile",
+            get(handlers::profiles::get_profile).put(handlers::profiles::update_profile),
+        )
+        // 업로드 크기 제한은 AVATAR_MAX_BYTES에 multipart 경계/헤더 여유분을 더한 값
+        .route(
+            "/:id/avatar",
+            put(handlers::avatars::upload_avatar)
+                .delete(handlers::avatars::delete_avatar)
+                .layer(DefaultBodyLimit::max(state.config.avatar_max_bytes + 64 * 1024)),
+        )
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 공지사항 라우트 (작성/수정/삭제는 관리자 라우트)
+fn announcement_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/", get(handlers::announcements::get_announcements))
+        .route("/:id", get(handlers::announcements::get_announcement))
+        .route("/:id/read", post(handlers::announcements::mark_announcement_read))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 휴가 종류 라우트 (생성/수정은 관리자 라우트)
+fn leave_type_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/", get(handlers::leaves::get_leave_types))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 휴가 잔여 일수 라우트 (조정은 관리자 라우트)
+fn leave_balance_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/", get(handlers::leaves::get_leave_balances))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 휴가 신청 라우트 (승인/반려 권한은 LeaveService에서 단계별로 확인)
+fn leave_request_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route(
+            "/",
+            get(handlers::leaves::get_leave_requests).post(handlers::leaves::create_leave_request),
+        )
+        .route("/pending", get(handlers::leaves::get_pending_leave_requests))
+        .route("/:id", get(handlers::leaves::get_leave_request))
+        .route("/:id/approve", post(handlers::leaves::approve_leave_request))
+        .route("/:id/reject", post(handlers::leaves::reject_leave_request))
+        .route("/:id/cancel", post(handlers::leaves::cancel_leave_request))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 팀 휴가 달력 라우트
+fn leave_calendar_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/", get(handlers::leaves::get_leave_calendar))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 예약 자원 라우트 (생성/수정/삭제는 관리자 라우트)
+fn resource_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/", get(handlers::bookings::get_resources))
+        .route("/availability", get(handlers::bookings::get_resource_availability))
+        .route("/:id", get(handlers::bookings::get_resource))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 예약 라우트 (다른 사용자의 예약 변경/취소는 resources.manage 권한 필요)
+fn booking_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route(
+            "/",
+            get(handlers::bookings::get_bookings).post(handlers::bookings::create_booking),
+        )
+        .route(
+            "/:id",
+            get(handlers::bookings::get_booking)
+                .put(handlers::bookings::update_booking)
+                .delete(handlers::bookings::cancel_booking),
+        )
+        .route("/:id/check-in", post(handlers::bookings::check_in_booking))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 달력 라우트 (회사 일정 등록/수정/삭제와 .ics 가져오기는 관리자 라우트)
+fn calendar_routes(state: &AppState) -> ApiRouter {
+    let session_routes = ApiRouter::new()
+        .route("/events", get(handlers::calendar::get_calendar_events))
+        .route(
+            "/feed",
+            get(handlers::calendar::get_calendar_feed)
+                .post(handlers::calendar::issue_calendar_feed)
+                .delete(handlers::calendar::revoke_calendar_feed),
+        )
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ));
+
+    // 캘린더 앱은 Authorization 헤더를 보내지 않으므로 주소의 토큰(<토큰>.ics)으로 사용자 확인
+    ApiRouter::new()
+        .route("/:token", get(handlers::calendar::get_calendar_ics))
+        .merge(session_routes)
+}
+
+// 문서함 라우트
+fn document_routes(state: &AppState) -> ApiRouter {
+    // 업로드 크기 제한은 DOCUMENT_MAX_BYTES에 multipart 경계/헤더 여유분을 더한 값
+    let upload_limit = DefaultBodyLimit::max(state.config.document_max_bytes + 64 * 1024);
+
+    ApiRouter::new()
+        .route(
+            "/folders",
+            get(handlers::documents::get_document_folders).post(handlers::documents::create_document_folder),
+        )
+        .route(
+            "/folders/:id",
+            get(handlers::documents::get_document_folder)
+                .put(handlers::documents::update_document_folder)
+                .delete(handlers::documents::delete_document_folder),
+        )
+        .route(
+            "/folders/:id/acl",
+            get(handlers::documents::get_document_folder_acl).put(handlers::documents::update_document_folder_acl),
+        )
+        .route(
+            "/folders/:id/documents",
+            get(handlers::documents::get_folder_documents)
+                .post(handlers::documents::upload_document)
+                .layer(upload_limit),
+        )
+        .route("/quota", get(handlers::documents::get_document_quota))
+        .route(
+            "/:id",
+            get(handlers::documents::get_document)
+                .put(handlers::documents::update_document)
+                .delete(handlers::documents::delete_document),
+        )
+        .route(
+            "/:id/versions",
+            get(handlers::documents::get_document_versions)
+                .post(handlers::documents::upload_document_version)
+                .layer(upload_limit),
+        )
+        .route("/:id/download", get(handlers::documents::download_document))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 검색 라우트
+fn search_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/users", get(handlers::search::search_users))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 프로필 사진 라우트
+fn avatar_routes() -> ApiRouter {
+    ApiRouter::new().route("/:hash/:size", get(handlers::avatars::get_avatar))
+}
+
+// 부서 라우트
+fn department_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/", get(handlers::departments::get_departments))
+        .route("/:id", get(handlers::departments::get_department))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 조직도 라우트
+fn org_chart_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/", get(handlers::departments::get_org_chart))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 관리자 라우트 (각 핸들러에서 RequirePermission으로 권한 확인)
+fn admin_routes(state: &AppState) -> ApiRouter {
+    ApiRouter::new()
+        .route("/users/:id", delete(handlers::users::delete_user))
+        .route("/users/:id/unlock", post(handlers::users::unlock_user))
+        .route("/users/:id/restore", post(handlers::users::restore_user))
+        .route(
+            "/users/:id/roles",
+            get(handlers::roles::get_user_roles).put(handlers::roles::set_user_roles),
+        )
+        .route("/roles", get(handlers::roles::get_roles).post(handlers::roles::create_role))
+        .route(
+            "/roles/:id",
+            put(handlers::roles::update_role).delete(handlers::roles::delete_role),
+        )
+        .route(
+            "/users/:id/department",
+            put(handlers::departments::set_user_department).delete(handlers::departments::remove_user_department),
+        )
+        .route("/departments", post(handlers::departments::create_department))
+        .route(
+            "/departments/:id",
+            put(handlers::departments::update_department).delete(handlers::departments::delete_department),
+        )
+        .route(
+            "/announcements",
+            get(handlers::announcements::get_admin_announcements).post(handlers::announcements::create_announcement),
+        )
+        .route(
+            "/announcements/:id",
+            put(handlers::announcements::update_announcement).delete(handlers::announcements::delete_announcement),
+        )
+        .route("/announcements/:id/reads", get(handlers::announcements::get_announcement_reads))
+        .route("/leave-types", post(handlers::leaves::create_leave_type))
+        .route("/leave-types/:id", put(handlers::leaves::update_leave_type))
+        .route("/users/:id/leave-balances", put(handlers::leaves::set_leave_balance))
+        .route("/resources", post(handlers::bookings::create_resource))
+        .route(
+            "/resources/:id",
+            put(handlers::bookings::update_resource).delete(handlers::bookings::delete_resource),
+        )
+        .route("/calendar/events", post(handlers::calendar::create_calendar_event))
+        .route(
+            "/calendar/events/:id",
+            put(handlers::calendar::update_calendar_event).delete(handlers::calendar::delete_calendar_event),
+        )
+        // 업로드 크기 제한은 CALENDAR_IMPORT_MAX_BYTES에 multipart 경계/헤더 여유분을 더한 값
+        .route(
+            "/calendar/import",
+            post(handlers::calendar::import_calendar_events)
+                .layer(DefaultBodyLimit::max(state.config.calendar_import_max_bytes + 64 * 1024)),
+        )
+        .route(
+            "/users/:id/document-quota",
+            get(handlers::documents::get_user_document_quota).put(handlers::documents::set_user_document_quota),
+        )
+        .route("/permissions", get(handlers::roles::get_permissions))
+        .route("/audit", get(handlers::audit::get_audit_events))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ))
+}
+
+// 헬스체크 핸들러
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "system",
+    summary = "헬스체크",
+    responses(
+        (status = 200, description = "서버 정상 동작")
+    )
+)]
+async fn health_check() -> Json<Value> {
+    Json(json!({
+        "status": "healthy",
+        "timestamp": chrono::Utc::now().timestamp()
+    }))
+} 
\ No newline at end of file
@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use std::{path::PathBuf, sync::Arc};
+use uuid::Uuid;
+
+use crate::config::{MailConfig, MailTransport, SmtpTls};
+
+// 발송할 메일 (본문은 일반 텍스트)
+#[derive(Debug, Clone)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+// 메일 발송 인터페이스 (SMTP 또는 로컬 개발용 파일 저장)
+#[async_trait]
+pub trait MailSender: Send + Sync {
+    async fn send(&self, mail: Mail) -> anyhow::Result<()>;
+}
+
+// 응답을 기다리게 하지 않도록 백그라운드에서 발송 (실패는 로그로만 남김)
+pub fn send_in_background(mailer: Arc<dyn MailSender>, mail: Mail) {
+    tokio::spawn(async move {
+        let to = mail.to.clone();
+        if let Err(err) = mailer.send(mail).await {
+            tracing::error!("메일 발송 실패 ({}): {}", to, err);
+        }
+    });
+}
+
+// 설정에 따라 메일 발송기 생성
+pub fn create_mail_sender(config: &MailConfig) -> anyhow::Result<Arc<dyn MailSender>> {
+    let sender: Arc<dyn MailSender> = match config.transport {
+        MailTransport::Smtp => Arc::new(SmtpMailSender::new(config)?),
+        MailTransport::File => Arc::new(FileMailSender::new(config)?),
+    };
+
+    tracing::info!("메일 발송 방식: {:?}", config.transport);
+    Ok(sender)
+}
+
+// SMTP 서버로 메일 발송
+pub struct SmtpMailSender {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailSender {
+    pub fn new(config: &MailConfig) -> anyhow::Result<Self> {
+        let mut builder = match config.smtp_tls {
+            SmtpTls::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)?,
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_host)?,
+            // 로컬 테스트용 메일 서버(MailHog 등)는 TLS 없이 연결
+            SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.smtp_host),
+        }
+        .port(config.smtp_port);
+
+        if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from: config.from.parse()?,
+        })
+    }
+}
+
+#[async_trait]
+impl MailSender for SmtpMailSender {
+    async fn send(&self, mail: Mail) -> anyhow::Result<()> {
+        let message = build_message(&self.from, &mail)?;
+        self.transport.send(message).await?;
+        Ok(())
+    }
+}
+
+// 메일을 .eml 파일로 저장 (로컬 개발/테스트용, 실제로 발송하지 않음)
+pub struct FileMailSender {
+    dir: PathBuf,
+    from: Mailbox,
+}
+
+impl FileMailSender {
+    pub fn new(config: &MailConfig) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(&config.outbox_dir)?;
+        Ok(Self {
+            dir: PathBuf::from(&config.outbox_dir),
+            from: config.from.parse()?,
+        })
+    }
+}
+
+#[async_trait]
+impl MailSender for FileMailSender {
+    async fn send(&self, mail: Mail) -> anyhow::Result<()> {
+        let message = build_message(&self.from, &mail)?;
+        let path = self.dir.join(format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%d%H%M%S"),
+            Uuid::new_v4()
+        ));
+
+        tokio::fs::write(&path, message.formatted()).await?;
+        tracing::info!("메일 저장: {} → {}", mail.to, path.display());
+        Ok(())
+    }
+}
+
+fn build_message(from: &Mailbox, mail: &Mail) -> anyhow::Result<Message> {
+    let message = Message::builder()
+        .from(from.clone())
+        .to(mail.to.parse()?)
+        .subject(&mail.subject)
+        .header(ContentType::TEXT_PLAIN)
+        .body(mail.body.clone())?;
+
+    Ok(message)
+}
@@ -1,20 +1,7 @@
-use axum::{
-    http::StatusCode,
-    response::Json,
-    routing::{delete, get, post, put},
-    Router,
-};
-use serde_json::{json, Value};
-use std::env;
-use tower_http::cors::CorsLayer;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-mod config;
-mod database;
-mod handlers;
-mod middleware;
-mod models;
-mod services;
+use sample_intranet_rust::{config, create_router, database, mail, services, storage, AppState};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -36,11 +23,51 @@ async fn main() -> anyhow::Result<()> {
 
     // 데이터베이스 연결
     let db_pool = database::create_pool(&config.database_url).await?;
-    database::run_migrations(&db_pool).await?;
+
+    // `migrate` 명령이면 마이그레이션만 실행하고 종료
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("migrate") {
+        return database::MigrateCommand::parse(&args[1..])?.execute(&db_pool).await;
+    }
+
+    database::run_migrations(&db_pool, false).await?;
     tracing::info!("데이터베이스 초기화 완료");
 
+    // 메일 발송기
+    let mailer = mail::create_mail_sender(&config.mail)?;
+
+    // 파일 저장소 (프로필 사진)
+    let blob_store = storage::create_blob_store(&config.storage)?;
+
     // 애플리케이션 상태
-    let app_state = AppState { db_pool };
+    let app_state = AppState {
+        db_pool,
+        config: config.clone(),
+        mailer,
+        blob_store,
+        session_cache: Arc::new(services::SessionCache::new(Duration::from_secs(
+            config.session_cache_seconds,
+        ))),
+    };
+
+    // 삭제된 사용자 영구 삭제 작업
+    if config.user_purge_interval_minutes > 0 {
+        services::spawn_purge_task(
+            app_state.db_pool.clone(),
+            app_state.blob_store.clone(),
+            config.user_purge_retention_days,
+            Duration::from_secs(config.user_purge_interval_minutes * 60),
+        );
+    }
+
+    // 체크인하지 않은 예약 자동 해제 작업
+    if config.booking_release_interval_minutes > 0 {
+        services::spawn_release_task(
+            app_state.db_pool.clone(),
+            config.booking_check_in_grace_minutes,
+            Duration::from_secs(config.booking_release_interval_minutes * 60),
+        );
+    }
 
     // 라우터 설정
     let app = create_router(app_state);
@@ -49,67 +76,8 @@ async fn main() -> anyhow::Result<()> {
     let listener = tokio::net::TcpListener::bind(&config.server_address).await?;
     tracing::info!("서버 시작: {}", config.server_address);
 
-    axum::serve(listener, app).await?;
+    // 감사 로그에 클라이언트 IP를 남기기 위해 연결 정보 포함
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
 
     Ok(())
 }
-
-// 애플리케이션 상태
-#[derive(Clone)]
-pub struct AppState {
-    pub db_pool: sqlx::PgPool,
-}
-
-// 라우터 생성
-fn create_router(state: AppState) -> Router {
-    Router::new()
-        // 헬스체크 엔드포인트
-        .route("/health", get(health_check))
-        // API v1 라우트
-        .nest("/api/v1", api_routes())
-        .layer(CorsLayer::permissive()) // 개발 환경용 CORS 설정
-        .with_state(state)
-}
-
-// API 라우트 정의
-fn api_routes() -> Router<AppState> {
-    Router::new()
-        // 인증 라우트
-        .nest("/auth", auth_routes())
-        // 사용자 라우트 (인증 필요)
-        .nest("/users", user_routes())
-        // 관리자 라우트 (관리자 권한 필요)
-        .nest("/admin", admin_routes())
-}
-
-// 인증 라우트
-fn auth_routes() -> Router<AppState> {
-    Router::new()
-        .route("/register", post(handlers::auth::register))
-        .route("/login", post(handlers::auth::login))
-}
-
-// 사용자 라우트
-fn user_routes() -> Router<AppState> {
-    Router::new()
-        .route("/", get(handlers::users::get_users))
-        .route("/:id", get(handlers::users::get_user))
-        .route("/:id", put(handlers::users::update_user))
-        .route_layer(axum::middleware::from_fn(middleware::auth_middleware))
-}
-
-// 관리자 라우트
-fn admin_routes() -> Router<AppState> {
-    Router::new()
-        .route("/users/:id", delete(handlers::users::delete_user))
-        .route_layer(axum::middleware::from_fn(middleware::admin_middleware))
-        .route_layer(axum::middleware::from_fn(middleware::auth_middleware))
-}
-
-// 헬스체크 핸들러
-async fn health_check() -> Json<Value> {
-    Json(json!({
-        "status": "healthy",
-        "timestamp": chrono::Utc::now().timestamp()
-    }))
-} 
\ No newline at end of file
@@ -0,0 +1,27 @@
+use pulldown_cmark::{html, Options, Parser};
+
+// Markdown을 화면에 바로 넣을 수 있는 HTML로 변환
+// 본문에 직접 쓴 HTML과 스크립트/이벤트 속성은 제거하고 링크에는 rel="noopener noreferrer"를 붙임
+pub fn render(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, Parser::new_ext(markdown, options));
+
+    ammonia::clean(&unsafe_html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_markdown_and_strips_scripts() {
+        let html = render("# 공지\n\n**중요** [링크](https://example.com)\n\n<script>alert(1)</script><img src=x onerror=alert(1)>");
+
+        assert!(html.contains("<h1>공지</h1>"));
+        assert!(html.contains("<strong>중요</strong>"));
+        assert!(html.contains(r#"<a href="https://example.com" rel="noopener noreferrer">링크</a>"#));
+        assert!(!html.contains("script"));
+        assert!(!html.contains("onerror"));
+    }
+}
@@ -1,27 +1,38 @@
 use axum::{
-    http::{Request, StatusCode},
+    async_trait,
+    body::{Body, Bytes},
+    extract::{ConnectInfo, FromRequest, FromRequestParts, Request, State},
+    http::{header, request::Parts, HeaderMap, HeaderValue, StatusCode},
     middleware::Next,
-    response::{IntoResponse, Response},
-    Json,
+    response::Response,
 };
 use chrono::{Duration, Utc};
+use ipnet::IpNet;
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
-use std::env;
-
-use crate::models::{Claims, ErrorResponse};
-
-// JWT 토큰 생성
-pub fn create_jwt_token(user_id: i32, email: &str, role: &str) -> anyhow::Result<String> {
-    let jwt_secret = env::var("JWT_SECRET")
-        .unwrap_or_else(|_| "your-secret-key-change-in-production".to_string());
-    
-    let jwt_expires_hours = env::var("JWT_EXPIRES_HOURS")
-        .unwrap_or_else(|_| "24".to_string())
-        .parse::<i64>()
-        .unwrap_or(24);
-
-    let expiration = Utc::now()
-        .checked_add_signed(Duration::hours(jwt_expires_hours))
+use serde::de::DeserializeOwned;
+use serde_json::error::Category;
+use std::{
+    convert::Infallible,
+    marker::PhantomData,
+    net::{IpAddr, SocketAddr},
+};
+use tracing::Instrument;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    config::Config,
+    error::AppError,
+    models::{Claims, ClientInfo, ErrorResponse, ProblemDetails},
+    services::{RoleService, SessionService},
+    AppState,
+};
+
+// JWT 액세스 토큰 생성
+pub fn create_jwt_token(config: &Config, user_id: i32, email: &str, role: &str) -> anyhow::Result<String> {
+    let now = Utc::now();
+    let expiration = now
+        .checked_add_signed(Duration::minutes(config.jwt_access_expires_minutes))
         .expect("유효한 타임스탬프")
         .timestamp() as usize;
 
@@ -29,26 +40,26 @@ pub fn create_jwt_token(user_id: i32, email: &str, role: &str) -> anyhow::Result
         sub: user_id.to_string(),
         email: email.to_string(),
         role: role.to_string(),
+        jti: Uuid::new_v4(),
+        iat: now.timestamp() as usize,
         exp: expiration,
+        iat_ms: Some(now.timestamp_millis()),
     };
 
     let token = encode(
         &Header::default(),
         &claims,
-        &EncodingKey::from_secret(jwt_secret.as_ref()),
+        &EncodingKey::from_secret(config.jwt_secret.as_ref()),
     )?;
 
     Ok(token)
 }
 
 // JWT 토큰 검증
-pub fn verify_jwt_token(token: &str) -> anyhow::Result<Claims> {
-    let jwt_secret = env::var("JWT_SECRET")
-        .unwrap_or_else(|_| "your-secret-key-change-in-production".to_string());
-
+pub fn verify_jwt_token(config: &Config, token: &str) -> anyhow::Result<Claims> {
     let token_data = decode::<Claims>(
         token,
-        &DecodingKey::from_secret(jwt_secret.as_ref()),
+        &DecodingKey::from_secret(config.jwt_secret.as_ref()),
         &Validation::default(),
     )?;
 
@@ -56,64 +67,375 @@ pub fn verify_jwt_token(token: &str) -> anyhow::Result<Claims> {
 }
 
 // 인증 미들웨어
-pub async fn auth_middleware<B>(
-    mut request: Request<B>,
-    next: Next<B>,
-) -> Result<Response, StatusCode> {
+pub async fn auth_middleware(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
     // Authorization 헤더에서 토큰 추출
-    let auth_header = request
+    let token = request
         .headers()
         .get("Authorization")
         .and_then(|header| header.to_str().ok())
-        .and_then(|header| {
-            if header.starts_with("Bearer ") {
-                Some(&header[7..])
-            } else {
-                None
-            }
-        });
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .ok_or_else(|| AppError::unauthorized("missing_token", "인증 토큰이 필요합니다"))?;
+
+    // 토큰 검증
+    let claims = verify_jwt_token(&state.config, token)
+        .map_err(|_| AppError::unauthorized("invalid_token", "유효하지 않은 토큰입니다"))?;
+
+    // 폐기된 토큰 및 비활성/삭제된 사용자 확인
+    let session_service = SessionService::new(state.db_pool.clone(), state.session_cache.clone());
+    if !session_service.is_token_active(&claims).await? {
+        return Err(AppError::unauthorized("token_revoked", "만료되었거나 폐기된 토큰입니다"));
+    }
+
+    // 요청에 사용자 정보 추가
+    request.extensions_mut().insert(claims);
+
+    Ok(next.run(request).await)
+}
+
+// 요청 추적 ID 헤더 (요청에 있으면 그대로 사용하고 없으면 새로 발급)
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+// problem+json으로 변환할 때 읽는 에러 본문 최대 크기
+const MAX_ERROR_BODY_BYTES: usize = 64 * 1024;
+
+// 에러 응답 형식 미들웨어
+// 모든 응답에 X-Request-Id(추적 ID)를 붙이고, Accept 헤더가 application/problem+json을
+// 선호하는 요청의 에러 응답은 RFC 7807 문서로 변환 (기본은 다른 백엔드와 같은 {error, message})
+pub async fn error_format_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let trace_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| !id.is_empty() && id.len() <= 128)
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let wants_problem = prefers_problem_json(request.headers());
+    let instance = request.uri().path().to_string();
+
+    // 요청 처리 중 로그에 추적 ID 포함
+    let response = next
+        .run(request)
+        .instrument(tracing::info_span!("request", trace_id = %trace_id))
+        .await;
+
+    let status = response.status();
+    let mut response = if wants_problem && (status.is_client_error() || status.is_server_error()) {
+        problem_response(response, &state.config.problem_type_base_url, &instance, &trace_id).await
+    } else {
+        response
+    };
+
+    if let Ok(value) = HeaderValue::from_str(&trace_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+// Accept 헤더에서 application/problem+json의 우선순위가 application/json 이상인지 확인
+fn prefers_problem_json(headers: &HeaderMap) -> bool {
+    let Some(accept) = headers.get(header::ACCEPT).and_then(|value| value.to_str().ok()) else {
+        return false;
+    };
+
+    let mut problem_quality = None;
+    let mut json_quality = 0.0_f32;
+    for item in accept.split(',') {
+        let mut params = item.split(';');
+        let media_type = params.next().unwrap_or_default().trim().to_ascii_lowercase();
+        let quality = params
+            .filter_map(|param| param.trim().strip_prefix("q="))
+            .find_map(|quality| quality.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+
+        match media_type.as_str() {
+            "application/problem+json" => problem_quality = Some(quality),
+            "application/json" => json_quality = json_quality.max(quality),
+            _ => {}
+        }
+    }
 
-    let token = match auth_header {
-        Some(token) => token,
+    matches!(problem_quality, Some(quality) if quality > 0.0 && quality >= json_quality)
+}
+
+// 에러 응답을 problem+json으로 변환
+// AppError 응답은 보관된 ErrorResponse를, 그 외(인증 실패, 없는 경로 등)는 상태 코드와 본문 텍스트를 사용
+async fn problem_response(response: Response, type_base_url: &str, instance: &str, trace_id: &str) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let status = parts.status;
+    let reason = status.canonical_reason().unwrap_or("Error");
+
+    let (code, detail, errors) = match parts.extensions.remove::<ErrorResponse>() {
+        Some(error) => (error.error, error.message, error.details),
         None => {
-            return Err(StatusCode::UNAUTHORIZED);
+            let text = axum::body::to_bytes(body, MAX_ERROR_BODY_BYTES)
+                .await
+                .map(|bytes| String::from_utf8_lossy(&bytes).trim().to_string())
+                .unwrap_or_default();
+            let code = reason.to_ascii_lowercase().replace([' ', '-'], "_");
+            let detail = if text.is_empty() { reason.to_string() } else { text };
+            (code, detail, None)
         }
     };
 
-    // 토큰 검증
-    let claims = match verify_jwt_token(token) {
-        Ok(claims) => claims,
-        Err(_) => {
-            return Err(StatusCode::UNAUTHORIZED);
+    let problem = ProblemDetails {
+        problem_type: format!("{}/{}", type_base_url, code),
+        title: reason.to_string(),
+        status: status.as_u16(),
+        detail,
+        instance: instance.to_string(),
+        trace_id: trace_id.to_string(),
+        code,
+        errors,
+    };
+
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("application/problem+json"));
+    let body = serde_json::to_vec(&problem).unwrap_or_default();
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+// 권한 코드 (RequirePermission의 타입 인자로 사용)
+pub trait PermissionCode {
+    const CODE: &'static str;
+}
+
+macro_rules! permission_codes {
+    ($($name:ident => $code:literal),* $(,)?) => {
+        $(
+            pub struct $name;
+
+            impl PermissionCode for $name {
+                const CODE: &'static str = $code;
+            }
+        )*
+    };
+}
+
+permission_codes! {
+    UsersRead => "users.read",
+    UsersUpdate => "users.update",
+    UsersDelete => "users.delete",
+    RolesManage => "roles.manage",
+    AuditRead => "audit.read",
+    DepartmentsManage => "departments.manage",
+    AnnouncementsPublish => "announcements.publish",
+    LeaveManage => "leave.manage",
+    ResourcesManage => "resources.manage",
+    CalendarManage => "calendar.manage",
+    DocumentsManage => "documents.manage",
+}
+
+// 권한 확인 추출자 (auth_middleware 뒤에서 사용)
+// 예: `RequirePermission(claims, _): RequirePermission<UsersDelete>`
+pub struct RequirePermission<P>(pub Claims, pub PhantomData<P>);
+
+#[async_trait]
+impl<P: PermissionCode> FromRequestParts<AppState> for RequirePermission<P> {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let unauthorized = || AppError::unauthorized("unauthorized", "인증이 필요합니다");
+        let claims = parts
+            .extensions
+            .get::<Claims>()
+            .cloned()
+            .ok_or_else(unauthorized)?;
+        let user_id: i32 = claims.sub.parse().map_err(|_| unauthorized())?;
+
+        let role_service = RoleService::new(state.db_pool.clone());
+        if role_service.has_permission(user_id, P::CODE).await? {
+            return Ok(RequirePermission(claims, PhantomData));
+        }
+
+        // 2단계 인증을 등록하지 않아 역할 권한이 비활성화된 경우 별도 안내
+        if role_service.permission_requires_mfa(user_id, P::CODE).await? {
+            return Err(AppError::forbidden("mfa_required", "이 작업을 하려면 2단계 인증을 등록해야 합니다"));
         }
+
+        Err(AppError::forbidden("permission_denied", format!("'{}' 권한이 필요합니다", P::CODE)))
+    }
+}
+
+// 유효성 검사를 포함한 JSON 본문 추출자
+// 역직렬화 실패(잘못된 JSON, 누락/잘못된 타입의 필드)와 Validate 실패를 모두
+// 필드별 오류가 담긴 400 validation_error 응답으로 변환
+// 예: `ValidatedJson(request): ValidatedJson<RegisterRequest>`
+pub struct ValidatedJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if !has_json_content_type(request.headers()) {
+            return Err(AppError::UnsupportedMediaType);
+        }
+
+        let bytes = Bytes::from_request(request, state).await.map_err(|rejection| {
+            if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE {
+                AppError::PayloadTooLarge
+            } else {
+                AppError::bad_request("invalid_body", rejection.body_text())
+            }
+        })?;
+
+        let deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
+        let value: T = serde_path_to_error::deserialize(deserializer).map_err(json_error)?;
+        value.validate()?;
+
+        Ok(ValidatedJson(value))
+    }
+}
+
+// application/json 또는 application/*+json
+fn has_json_content_type(headers: &HeaderMap) -> bool {
+    let Some(content_type) = headers.get(header::CONTENT_TYPE).and_then(|value| value.to_str().ok()) else {
+        return false;
     };
+    let mime = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
 
-    // 요청에 사용자 정보 추가
-    request.extensions_mut().insert(claims);
+    mime == "application/json" || (mime.starts_with("application/") && mime.ends_with("+json"))
+}
 
-    Ok(next.run(request).await)
+// JSON 역직렬화 오류를 필드 오류로 변환
+// 필드 이름은 `profile.name`, `roles[0]` 형태의 경로, 본문 자체의 문법 오류는 `body`
+fn json_error(err: serde_path_to_error::Error<serde_json::Error>) -> AppError {
+    let path = err.path().to_string();
+    let inner = err.inner();
+
+    if !matches!(inner.classify(), Category::Data) {
+        return AppError::invalid_field("body", "invalid_json", "JSON 형식이 올바르지 않습니다");
+    }
+
+    let message = inner.to_string();
+    let detail = message.split(" at line ").next().unwrap_or_default();
+    let parent = if path == "." { String::new() } else { format!("{}.", path) };
+
+    // 누락된 필드는 상위 경로에 필드 이름을 붙여 표시
+    if let Some(field) = detail
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.strip_suffix('`'))
+    {
+        return AppError::invalid_field(&format!("{}{}", parent, field), "required", "필수 항목입니다");
+    }
+
+    let field = if path == "." { "body".to_string() } else { path };
+    if detail.starts_with("invalid type") {
+        AppError::invalid_field(&field, "invalid_type", format!("값의 타입이 올바르지 않습니다 ({})", detail))
+    } else {
+        AppError::invalid_field(&field, "invalid_value", format!("값이 올바르지 않습니다 ({})", detail))
+    }
 }
 
-// 관리자 권한 확인 미들웨어
-pub async fn admin_middleware<B>(
-    request: Request<B>,
-    next: Next<B>,
-) -> Result<Response, StatusCode> {
-    // 요청에서 사용자 정보 추출
-    let claims = request
-        .extensions()
-        .get::<Claims>()
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+// 클라이언트 IP/User-Agent 추출자 (감사 로그, 로그인 실패 제한용)
+// 접속한 주소를 사용하고, 그 주소가 TRUSTED_PROXIES에 있을 때만 X-Forwarded-For를 따라간다
+#[async_trait]
+impl FromRequestParts<AppState> for ClientInfo {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let forwarded_for: Vec<&str> = parts
+            .headers
+            .get_all("X-Forwarded-For")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .collect();
+
+        let ip_address = client_ip(peer, &forwarded_for.join(","), &state.config.trusted_proxies)
+            .map(|ip| ip.to_string());
+
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
 
-    // 관리자 권한 확인
-    if claims.role != "admin" {
-        return Err(StatusCode::FORBIDDEN);
+        Ok(ClientInfo {
+            ip_address,
+            user_agent,
+        })
     }
+}
 
-    Ok(next.run(request).await)
+// 실제 클라이언트 주소 결정
+// 신뢰할 프록시가 보낸 요청이면 X-Forwarded-For를 오른쪽(가까운 홉)부터 읽어 처음 나오는 신뢰하지 않는 주소를 사용
+// (왼쪽 항목은 클라이언트가 임의로 넣을 수 있음), 형식이 잘못된 항목을 만나면 그 직전 홉에서 멈춘다
+fn client_ip(peer: Option<IpAddr>, forwarded_for: &str, trusted_proxies: &[IpNet]) -> Option<IpAddr> {
+    let is_trusted = |ip: &IpAddr| trusted_proxies.iter().any(|net| net.contains(ip));
+
+    let mut client = peer?.to_canonical();
+    if !is_trusted(&client) {
+        return Some(client);
+    }
+
+    for hop in forwarded_for.rsplit(',').map(str::trim).filter(|hop| !hop.is_empty()) {
+        let Some(ip) = hop
+            .parse::<IpAddr>()
+            .ok()
+            .or_else(|| hop.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+        else {
+            break;
+        };
+        client = ip.to_canonical();
+        if !is_trusted(&client) {
+            break;
+        }
+    }
+
+    Some(client)
 }
 
-// 현재 사용자 정보 추출 헬퍼 함수
-pub fn get_current_user(request: &axum::http::Request<axum::body::Body>) -> Option<&Claims> {
-    request.extensions().get::<Claims>()
-} 
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(text: &str) -> IpAddr {
+        text.parse().unwrap()
+    }
+
+    fn proxies() -> Vec<IpNet> {
+        vec!["10.0.0.0/8".parse().unwrap(), IpNet::from(ip("192.168.1.10"))]
+    }
+
+    #[test]
+    fn client_ip_ignores_forwarded_for_from_untrusted_peer() {
+        assert_eq!(client_ip(Some(ip("203.0.113.7")), "1.2.3.4", &proxies()), Some(ip("203.0.113.7")));
+        assert_eq!(client_ip(Some(ip("203.0.113.7")), "1.2.3.4", &[]), Some(ip("203.0.113.7")));
+        assert_eq!(client_ip(None, "1.2.3.4", &proxies()), None);
+    }
+
+    #[test]
+    fn client_ip_takes_rightmost_untrusted_hop() {
+        // 클라이언트가 넣은 1.2.3.4는 무시하고 프록시가 덧붙인 주소를 사용
+        assert_eq!(
+            client_ip(Some(ip("10.0.0.2")), "1.2.3.4, 198.51.100.9, 10.0.0.5", &proxies()),
+            Some(ip("198.51.100.9"))
+        );
+        assert_eq!(client_ip(Some(ip("192.168.1.10")), "198.51.100.9:5000", &proxies()), Some(ip("198.51.100.9")));
+        assert_eq!(client_ip(Some(ip("::ffff:10.0.0.2")), "[2001:db8::1]:443", &proxies()), Some(ip("2001:db8::1")));
+    }
+
+    #[test]
+    fn client_ip_stops_at_invalid_or_missing_hops() {
+        assert_eq!(client_ip(Some(ip("10.0.0.2")), "", &proxies()), Some(ip("10.0.0.2")));
+        assert_eq!(client_ip(Some(ip("10.0.0.2")), "198.51.100.9, unknown", &proxies()), Some(ip("10.0.0.2")));
+        assert_eq!(client_ip(Some(ip("10.0.0.2")), "10.0.0.3, 10.0.0.4", &proxies()), Some(ip("10.0.0.3")));
+    }
+}
@@ -1,6 +1,9 @@
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
 use sqlx::FromRow;
+use std::collections::BTreeMap;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 use validator::Validate;
 
 // 사용자 모델 (데이터베이스 테이블과 매핑)
@@ -13,20 +16,35 @@ pub struct User {
     pub name: String,
     pub role: String,
     pub is_active: bool,
+    pub email_verified_at: Option<DateTime<Utc>>, // NULL이면 이메일 미인증
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>, // NULL이 아니면 삭제된 사용자 (보관 기간 후 영구 삭제)
+    pub avatar_hash: Option<String>,       // 프로필 사진 원본의 SHA-256 (파일 저장소 키)
 }
 
 // 사용자 응답 구조체 (비밀번호 제외)
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UserResponse {
     pub id: i32,
     pub email: String,
     pub name: String,
     pub role: String,
     pub is_active: bool,
+    pub email_verified_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+
+    // 프로필 사진 URL (가장 큰 크기, /64 /128로 작은 크기 조회), 없으면 null
+    pub avatar_url: Option<String>,
+
+    // 삭제된 사용자를 포함해 조회한 경우에만 표시
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    // 프로필이 있는 사용자를 사용자 API로 조회한 경우, 조회자에게 공개된 항목만 표시
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<UserProfileResponse>,
 }
 
 impl From<User> for UserResponse {
@@ -37,14 +55,35 @@ impl From<User> for UserResponse {
             name: user.name,
             role: user.role,
             is_active: user.is_active,
+            email_verified_at: user.email_verified_at,
             created_at: user.created_at,
             updated_at: user.updated_at,
+            avatar_url: user.avatar_hash.as_deref().map(|hash| avatar_url(hash, AVATAR_SIZES[AVATAR_SIZES.len() - 1])),
+            deleted_at: user.deleted_at,
+            profile: None,
         }
     }
 }
 
+// 프로필 사진 크기 (정사각형 PNG, 픽셀)
+pub const AVATAR_SIZES: [u32; 3] = [64, 128, 256];
+
+// 프로필 사진 조회 URL
+pub fn avatar_url(hash: &str, size: u32) -> String {
+    format!("/api/v1/avatars/{}/{}", hash, size)
+}
+
+// 프로필 사진 업로드 요청 (multipart/form-data, OpenAPI 문서용)
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct AvatarUploadRequest {
+    // PNG, JPEG, GIF, WebP 이미지 (AVATAR_MAX_BYTES 이하)
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
 // 회원가입 요청 구조체
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct RegisterRequest {
     #[validate(email(message = "유효한 이메일 주소를 입력해주세요"))]
     pub email: String,
@@ -54,13 +93,10 @@ pub struct RegisterRequest {
     
     #[validate(length(min = 2, max = 50, message = "이름은 2자 이상 50자 이하여야 합니다"))]
     pub name: String,
-    
-    #[validate(custom = "validate_role")]
-    pub role: Option<String>,
 }
 
 // 로그인 요청 구조체
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct LoginRequest {
     #[validate(email(message = "유효한 이메일 주소를 입력해주세요"))]
     pub email: String,
@@ -70,7 +106,7 @@ pub struct LoginRequest {
 }
 
 // 사용자 정보 수정 요청 구조체
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateUserRequest {
     #[validate(email(message = "유효한 이메일 주소를 입력해주세요"))]
     pub email: Option<String>,
@@ -81,52 +117,1972 @@ pub struct UpdateUserRequest {
     #[validate(length(min = 2, max = 50, message = "이름은 2자 이상 50자 이하여야 합니다"))]
     pub name: Option<String>,
     
-    #[validate(custom = "validate_role")]
+    #[validate(custom(function = "validate_role"))]
     pub role: Option<String>,
     
     pub is_active: Option<bool>,
 }
 
+// 사용자 목록 정렬 기준
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserSortField {
+    Id,
+    Email,
+    Name,
+    Role,
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl UserSortField {
+    pub fn column(self) -> &'static str {
+        match self {
+            UserSortField::Id => "id",
+            UserSortField::Email => "email",
+            UserSortField::Name => "name",
+            UserSortField::Role => "role",
+            UserSortField::CreatedAt => "created_at",
+            UserSortField::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+// 정렬 방향
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl SortOrder {
+    pub fn keyword(self) -> &'static str {
+        match self {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
+    }
+}
+
+// 사용자 목록 조회 쿼리 파라미터
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UserListQuery {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "페이지는 1 이상이어야 합니다"))]
+    pub page: i64,
+
+    #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 100, message = "페이지 크기는 1 이상 100 이하여야 합니다"))]
+    pub per_page: i64,
+
+    #[serde(default)]
+    pub sort: UserSortField,
+
+    #[serde(default)]
+    pub order: SortOrder,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+
+    // 이메일/이름 검색어
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 100, message = "검색어는 100자 이하여야 합니다"))]
+    pub q: Option<String>,
+
+    // 삭제된 사용자 포함 여부 (users.delete 권한 필요)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_deleted: Option<bool>,
+}
+
+// 사용자 단건 조회 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UserLookupQuery {
+    // 삭제된 사용자 포함 여부 (users.delete 권한 필요)
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+// 사용자 검색 쿼리 파라미터
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UserSearchQuery {
+    // 검색어 (이름/이메일/역할, 한글 초성 가능: "ㅎㄱㄷ", "홍ㄱ")
+    #[serde(default)]
+    #[validate(custom(function = "validate_search_query"))]
+    pub q: String,
+
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "페이지는 1 이상이어야 합니다"))]
+    pub page: i64,
+
+    #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 100, message = "페이지 크기는 1 이상 100 이하여야 합니다"))]
+    pub per_page: i64,
+}
+
+// 검색어 검사 함수 (공백 제외 1자 이상 100자 이하)
+fn validate_search_query(q: &str) -> Result<(), validator::ValidationError> {
+    let length = q.trim().chars().count();
+    if (1..=100).contains(&length) {
+        Ok(())
+    } else {
+        let mut error = validator::ValidationError::new("length");
+        error.message = Some("검색어는 1자 이상 100자 이하여야 합니다".into());
+        Err(error)
+    }
+}
+
+// 사용자 검색 결과 항목
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserSearchHit {
+    pub user: UserResponse,
+
+    // 관련도 점수 (높을수록 앞, 정확히 일치 > 앞부분 일치 > 부분 일치 > 유사)
+    pub score: f32,
+
+    pub highlight: UserSearchHighlight,
+}
+
+// 검색어와 일치한 부분을 <mark>로 감싼 값 (HTML 이스케이프됨, 일치하지 않은 항목은 생략)
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct UserSearchHighlight {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "<mark>홍길</mark>동")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_per_page() -> i64 {
+    20
+}
+
+// 토큰 갱신 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RefreshRequest {
+    #[validate(length(min = 1, message = "리프레시 토큰을 입력해주세요"))]
+    pub refresh_token: String,
+}
+
+// 비밀번호 재설정 메일 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ForgotPasswordRequest {
+    #[validate(email(message = "유효한 이메일 주소를 입력해주세요"))]
+    pub email: String,
+}
+
+// 비밀번호 재설정 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ResetPasswordRequest {
+    #[validate(length(min = 1, message = "재설정 토큰을 입력해주세요"))]
+    pub token: String,
+
+    #[validate(length(min = 6, message = "비밀번호는 최소 6자 이상이어야 합니다"))]
+    pub password: String,
+}
+
+// 이메일 인증 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct VerifyEmailRequest {
+    #[validate(length(min = 1, message = "인증 토큰을 입력해주세요"))]
+    pub token: String,
+}
+
+// 인증 메일 재발송 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ResendVerificationRequest {
+    #[validate(email(message = "유효한 이메일 주소를 입력해주세요"))]
+    pub email: String,
+}
+
+// 2단계 인증 코드 구조체 (인증 앱의 6자리 코드 또는 복구 코드 중 하나)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct MfaCodeRequest {
+    #[validate(length(min = 1, message = "인증 코드를 입력해주세요"))]
+    pub code: Option<String>,
+
+    #[validate(length(min = 1, message = "복구 코드를 입력해주세요"))]
+    pub recovery_code: Option<String>,
+}
+
+// 2단계 로그인 요청 구조체 (로그인 1단계에서 받은 토큰과 인증 코드)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct MfaVerifyRequest {
+    #[validate(length(min = 1, message = "2단계 인증 토큰을 입력해주세요"))]
+    pub mfa_token: String,
+
+    #[validate(length(min = 1, message = "인증 코드를 입력해주세요"))]
+    pub code: Option<String>,
+
+    #[validate(length(min = 1, message = "복구 코드를 입력해주세요"))]
+    pub recovery_code: Option<String>,
+}
+
+// 2단계 인증 상태 응답 구조체
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MfaStatusResponse {
+    pub enabled: bool,
+    pub required: bool, // 역할 정책상 등록이 필요한지 여부
+}
+
+// 2단계 인증 등록 시작 응답 구조체 (otpauth_uri는 QR 코드로 표시)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MfaEnrollResponse {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+// 복구 코드 응답 구조체 (원문은 발급 시 한 번만 제공)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MfaRecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+// 로그인 1단계 응답 구조체 (2단계 인증이 등록된 계정)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MfaChallengeResponse {
+    pub mfa_required: bool,
+    pub mfa_token: String,
+    pub expires_in: i64, // 2단계 인증 토큰 만료까지 남은 초
+}
+
+// 로그아웃 요청 구조체 (리프레시 토큰을 함께 보내면 해당 세션의 갱신도 차단)
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct LogoutRequest {
+    pub refresh_token: Option<String>,
+}
+
 // 인증 응답 구조체
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AuthResponse {
     pub token: String,
+    pub refresh_token: String,
+    pub token_type: String,
+    pub expires_in: i64, // 액세스 토큰 만료까지 남은 초
     pub user: UserResponse,
 }
 
+// 리프레시 토큰 모델 (원문은 저장하지 않고 SHA-256 해시만 저장)
+#[derive(Debug, Clone, FromRow)]
+pub struct RefreshToken {
+    pub id: i64,
+    pub user_id: i32,
+    pub family_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
 // JWT 클레임 구조체
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,    // 사용자 ID
     pub email: String,  // 이메일
     pub role: String,   // 역할
+    pub jti: Uuid,      // 토큰 고유 ID (폐기 확인용)
+    pub iat: usize,     // 발급 시간
     pub exp: usize,     // 만료 시간
+    // 밀리초 단위 발급 시간 (같은 초에 있었던 전체 세션 폐기와 구분, 없는 토큰은 iat로 비교)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat_ms: Option<i64>,
 }
 
-// 역할 유효성 검사 함수
+// 역할 이름 형식 검사 함수 (역할 존재 여부는 roles 테이블에서 확인)
 fn validate_role(role: &str) -> Result<(), validator::ValidationError> {
-    match role {
-        "user" | "admin" => Ok(()),
-        _ => {
-            let mut error = validator::ValidationError::new("invalid_role");
-            error.message = Some("역할은 'user' 또는 'admin'이어야 합니다".into());
-            Err(error)
+    let valid_format = !role.is_empty()
+        && role.len() <= 50
+        && role
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+
+    if valid_format {
+        Ok(())
+    } else {
+        let mut error = validator::ValidationError::new("invalid_role");
+        error.message = Some("역할 이름은 50자 이하의 영문 소문자, 숫자, '_', '-'만 사용할 수 있습니다".into());
+        Err(error)
+    }
+}
+
+// 역할 모델
+#[derive(Debug, Clone, FromRow)]
+pub struct Role {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub is_system: bool,
+    pub require_mfa: bool, // 이 역할의 권한을 사용하려면 2단계 인증 등록 필요
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 권한 모델
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct Permission {
+    pub id: i32,
+    pub code: String,
+    pub description: String,
+}
+
+// 역할 응답 구조체 (부여된 권한 코드 포함)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoleResponse {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub is_system: bool,
+    pub require_mfa: bool,
+    pub permissions: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RoleResponse {
+    pub fn new(role: Role, permissions: Vec<String>) -> Self {
+        RoleResponse {
+            id: role.id,
+            name: role.name,
+            description: role.description,
+            is_system: role.is_system,
+            require_mfa: role.require_mfa,
+            permissions,
+            created_at: role.created_at,
+            updated_at: role.updated_at,
         }
     }
 }
 
-// 에러 응답 구조체
-#[derive(Debug, Serialize)]
-pub struct ErrorResponse {
-    pub error: String,
-    pub message: String,
+// 역할 생성 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateRoleRequest {
+    #[validate(custom(function = "validate_role"))]
+    pub name: String,
+
+    #[validate(length(max = 255, message = "설명은 255자 이하여야 합니다"))]
+    #[serde(default)]
+    pub description: String,
+
+    #[serde(default)]
+    pub permissions: Vec<String>,
+
+    #[serde(default)]
+    pub require_mfa: bool,
 }
 
-impl ErrorResponse {
-    pub fn new(error: &str, message: &str) -> Self {
+// 역할 수정 요청 구조체 (permissions를 보내면 권한 목록 전체를 교체)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateRoleRequest {
+    #[validate(length(max = 255, message = "설명은 255자 이하여야 합니다"))]
+    pub description: Option<String>,
+
+    pub permissions: Option<Vec<String>>,
+
+    pub require_mfa: Option<bool>,
+}
+
+// 사용자 추가 역할 설정 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AssignRolesRequest {
+    pub roles: Vec<String>,
+}
+
+// 사용자 역할/권한 응답 구조체
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserRolesResponse {
+    pub user_id: i32,
+    pub primary_role: String,   // users.role (다른 백엔드와 공유)
+    pub roles: Vec<String>,     // 추가로 부여된 역할
+    pub permissions: Vec<String>,
+}
+
+// 부서 모델
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct Department {
+    pub id: i32,
+    pub name: String,
+    pub parent_id: Option<i32>,    // NULL이면 최상위 부서
+    pub head_user_id: Option<i32>, // 부서장
+    pub sort_order: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 부서 응답 구조체 (소속 인원 수 포함)
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct DepartmentResponse {
+    pub id: i32,
+    pub name: String,
+    pub parent_id: Option<i32>,
+    pub head_user_id: Option<i32>,
+    pub sort_order: i32,
+    pub member_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 부서 상세 응답 구조체 (소속 사용자 목록 포함)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DepartmentDetailResponse {
+    #[serde(flatten)]
+    pub department: DepartmentResponse,
+    pub members: Vec<DepartmentMemberResponse>,
+}
+
+// 부서 생성 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateDepartmentRequest {
+    #[validate(length(min = 1, max = 100, message = "부서 이름은 1자 이상 100자 이하여야 합니다"))]
+    pub name: String,
+
+    pub parent_id: Option<i32>,
+
+    pub head_user_id: Option<i32>,
+
+    #[serde(default)]
+    pub sort_order: i32,
+}
+
+// 부서 수정 요청 구조체
+// parent_id/head_user_id는 생략하면 유지하고 null을 보내면 해제 (최상위 부서로 이동, 부서장 없음)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateDepartmentRequest {
+    #[validate(length(min = 1, max = 100, message = "부서 이름은 1자 이상 100자 이하여야 합니다"))]
+    pub name: Option<String>,
+
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<i32>)]
+    pub parent_id: Option<Option<i32>>,
+
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<i32>)]
+    pub head_user_id: Option<Option<i32>>,
+
+    pub sort_order: Option<i32>,
+}
+
+// 필드 생략(None)과 null(Some(None))을 구분
+fn double_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+// 사용자 소속 모델
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct DepartmentMember {
+    pub user_id: i32,
+    pub department_id: i32,
+    pub title: Option<String>,
+    pub manager_id: Option<i32>, // 직속 상사
+}
+
+// 소속 사용자 응답 구조체
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct DepartmentMemberResponse {
+    pub user_id: i32,
+    pub department_id: i32,
+    pub name: String,
+    pub email: String,
+    pub title: Option<String>,
+    pub manager_id: Option<i32>,
+}
+
+// 사용자 소속 설정 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetDepartmentMemberRequest {
+    pub department_id: i32,
+
+    #[validate(length(max = 100, message = "직함은 100자 이하여야 합니다"))]
+    pub title: Option<String>,
+
+    pub manager_id: Option<i32>,
+}
+
+// 조직도 조회 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OrgChartQuery {
+    // 이 부서를 최상위로 하는 하위 트리만 조회 (생략하면 전체)
+    pub root_id: Option<i32>,
+}
+
+// 조직도의 사용자 (부서장은 다른 부서 소속일 수 있으므로 직함은 본인 소속 기준)
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct OrgChartPerson {
+    pub user_id: i32,
+    pub name: String,
+    pub email: String,
+    pub title: Option<String>,
+    pub manager_id: Option<i32>,
+}
+
+// 조직도 노드 (부서와 하위 부서)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrgChartNode {
+    pub id: i32,
+    pub name: String,
+    pub head: Option<OrgChartPerson>,
+    pub members: Vec<OrgChartPerson>,
+    #[schema(no_recursion)]
+    pub children: Vec<OrgChartNode>,
+}
+
+// 프로필 항목 공개 범위 (넓은 범위부터 Public < Colleagues < Admin 순서)
+// public: 모든 로그인 사용자, colleagues: 같은 부서와 직속 상사/부하, admin: 본인과 users.update 권한 보유자
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ProfileVisibility {
+    Public,
+    Colleagues,
+    Admin,
+}
+
+// 항목별 공개 범위 (생략한 항목은 기본값)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct ProfileVisibilitySettings {
+    pub phone: ProfileVisibility,
+    pub extension: ProfileVisibility,
+    pub office_location: ProfileVisibility,
+    pub job_title: ProfileVisibility,
+    pub hire_date: ProfileVisibility,
+    pub birthday: ProfileVisibility,
+    pub bio: ProfileVisibility,
+    pub skills: ProfileVisibility,
+}
+
+impl Default for ProfileVisibilitySettings {
+    // 업무 연락에 필요한 항목은 전체 공개, 개인 연락처와 날짜는 동료에게만 공개
+    fn default() -> Self {
         Self {
-            error: error.to_string(),
-            message: message.to_string(),
+            phone: ProfileVisibility::Colleagues,
+            extension: ProfileVisibility::Public,
+            office_location: ProfileVisibility::Public,
+            job_title: ProfileVisibility::Public,
+            hire_date: ProfileVisibility::Colleagues,
+            birthday: ProfileVisibility::Colleagues,
+            bio: ProfileVisibility::Public,
+            skills: ProfileVisibility::Public,
+        }
+    }
+}
+
+// 사용자 프로필 모델
+#[derive(Debug, Clone, FromRow)]
+pub struct UserProfile {
+    pub user_id: i32,
+    pub phone: Option<String>,
+    pub extension: Option<String>,       // 내선 번호
+    pub office_location: Option<String>,
+    pub job_title: Option<String>,
+    pub hire_date: Option<NaiveDate>,
+    pub birthday: Option<NaiveDate>,
+    pub bio: Option<String>,
+    pub skills: Vec<String>,
+    pub visibility: sqlx::types::Json<ProfileVisibilitySettings>,
+}
+
+impl UserProfile {
+    // 아직 프로필을 작성하지 않은 사용자 (모든 항목 비어 있음, 기본 공개 범위)
+    pub fn empty(user_id: i32) -> Self {
+        Self {
+            user_id,
+            phone: None,
+            extension: None,
+            office_location: None,
+            job_title: None,
+            hire_date: None,
+            birthday: None,
+            bio: None,
+            skills: Vec::new(),
+            visibility: sqlx::types::Json(ProfileVisibilitySettings::default()),
+        }
+    }
+
+    // 조회자의 접근 수준에서 볼 수 있는 항목만 남긴 응답
+    pub fn visible_to(&self, access: ProfileVisibility) -> UserProfileResponse {
+        let settings = &self.visibility.0;
+        let visible = |level: ProfileVisibility| level <= access;
+
+        UserProfileResponse {
+            phone: self.phone.clone().filter(|_| visible(settings.phone)),
+            extension: self.extension.clone().filter(|_| visible(settings.extension)),
+            office_location: self.office_location.clone().filter(|_| visible(settings.office_location)),
+            job_title: self.job_title.clone().filter(|_| visible(settings.job_title)),
+            hire_date: self.hire_date.filter(|_| visible(settings.hire_date)),
+            birthday: self.birthday.filter(|_| visible(settings.birthday)),
+            bio: self.bio.clone().filter(|_| visible(settings.bio)),
+            skills: visible(settings.skills).then(|| self.skills.clone()),
+            visibility: (access == ProfileVisibility::Admin).then(|| settings.clone()),
+        }
+    }
+}
+
+// 프로필 응답 구조체 (비어 있거나 조회자에게 공개되지 않은 항목은 생략)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UserProfileResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub office_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hire_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+
+    // 항목별 공개 범위 (본인과 관리자에게만 표시)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ProfileVisibilitySettings>,
+}
+
+// 프로필 수정 요청 구조체 (프로필 전체를 교체하며 생략한 항목은 비움)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateProfileRequest {
+    #[validate(length(max = 30, message = "전화번호는 30자 이하여야 합니다"))]
+    pub phone: Option<String>,
+
+    #[validate(length(max = 20, message = "내선 번호는 20자 이하여야 합니다"))]
+    pub extension: Option<String>,
+
+    #[validate(length(max = 100, message = "근무지는 100자 이하여야 합니다"))]
+    pub office_location: Option<String>,
+
+    #[validate(length(max = 100, message = "직무는 100자 이하여야 합니다"))]
+    pub job_title: Option<String>,
+
+    pub hire_date: Option<NaiveDate>,
+
+    pub birthday: Option<NaiveDate>,
+
+    #[validate(length(max = 2000, message = "자기소개는 2000자 이하여야 합니다"))]
+    pub bio: Option<String>,
+
+    #[serde(default)]
+    #[validate(custom(function = "validate_skills"))]
+    pub skills: Vec<String>,
+
+    #[serde(default)]
+    pub visibility: ProfileVisibilitySettings,
+}
+
+// 기술 태그 검사 함수 (최대 30개, 태그당 1~50자)
+fn validate_skills(skills: &[String]) -> Result<(), validator::ValidationError> {
+    let valid = skills.len() <= 30
+        && skills
+            .iter()
+            .all(|skill| !skill.trim().is_empty() && skill.trim().chars().count() <= 50);
+
+    if valid {
+        Ok(())
+    } else {
+        let mut error = validator::ValidationError::new("invalid_skills");
+        error.message = Some("기술 태그는 최대 30개, 태그당 1자 이상 50자 이하여야 합니다".into());
+        Err(error)
+    }
+}
+
+// 공지사항 게시 상태 (게시 예정, 게시 중, 만료)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AnnouncementStatus {
+    Scheduled,
+    Published,
+    Expired,
+}
+
+impl AnnouncementStatus {
+    pub fn at(publish_at: DateTime<Utc>, expires_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> Self {
+        if publish_at > now {
+            Self::Scheduled
+        } else if expires_at.is_some_and(|expires_at| expires_at <= now) {
+            Self::Expired
+        } else {
+            Self::Published
+        }
+    }
+}
+
+// 공지사항 모델 (작성자 이름, 조회자의 읽음 시각, 읽은 사용자 수 포함)
+#[derive(Debug, Clone, FromRow)]
+pub struct Announcement {
+    pub id: i32,
+    pub title: String,
+    pub body: String, // Markdown
+    pub author_id: Option<i32>, // 작성자가 영구 삭제되면 NULL
+    pub author_name: Option<String>,
+    pub pinned: bool,
+    pub publish_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub audience_roles: Vec<String>, // 비어 있으면 전체 공지
+    pub read_at: Option<DateTime<Utc>>,
+    pub read_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Announcement {
+    // 응답 변환 (읽은 사용자 수는 게시 권한 보유자에게만 표시)
+    pub fn into_response(self, include_read_count: bool) -> AnnouncementResponse {
+        AnnouncementResponse {
+            status: AnnouncementStatus::at(self.publish_at, self.expires_at, Utc::now()),
+            body_html: crate::markdown::render(&self.body),
+            id: self.id,
+            title: self.title,
+            body: self.body,
+            author_id: self.author_id,
+            author_name: self.author_name,
+            pinned: self.pinned,
+            publish_at: self.publish_at,
+            expires_at: self.expires_at,
+            audience_roles: self.audience_roles,
+            read_at: self.read_at,
+            read_count: include_read_count.then_some(self.read_count),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
         }
     }
+}
+
+// 공지사항 응답 구조체
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AnnouncementResponse {
+    pub id: i32,
+    pub title: String,
+
+    // 작성한 Markdown 원문
+    pub body: String,
+
+    // 화면 표시용 HTML (허용된 태그만 남긴 값)
+    pub body_html: String,
+
+    pub author_id: Option<i32>,
+    pub author_name: Option<String>,
+    pub pinned: bool,
+    pub status: AnnouncementStatus,
+    pub publish_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub audience_roles: Vec<String>,
+
+    // 조회자가 읽은 시각 (읽지 않았으면 null)
+    pub read_at: Option<DateTime<Utc>>,
+
+    // 읽은 사용자 수 (관리 목록/수정 응답에만 포함)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_count: Option<i64>,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 공지사항 작성 요청 구조체
+// publish_at을 생략하면 바로 게시, expires_at을 생략하면 만료 없음, audience_roles가 비어 있으면 전체 공지
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateAnnouncementRequest {
+    #[validate(length(min = 1, max = 200, message = "제목은 1자 이상 200자 이하여야 합니다"))]
+    pub title: String,
+
+    #[validate(length(min = 1, max = 50000, message = "본문은 1자 이상 50000자 이하여야 합니다"))]
+    pub body: String,
+
+    #[serde(default)]
+    pub pinned: bool,
+
+    pub publish_at: Option<DateTime<Utc>>,
+
+    pub expires_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    #[validate(length(max = 20, message = "대상 역할은 최대 20개까지 지정할 수 있습니다"))]
+    pub audience_roles: Vec<String>,
+}
+
+// 공지사항 수정 요청 구조체
+// 생략한 항목은 유지하고, expires_at에 null을 보내면 만료 없음으로 변경
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateAnnouncementRequest {
+    #[validate(length(min = 1, max = 200, message = "제목은 1자 이상 200자 이하여야 합니다"))]
+    pub title: Option<String>,
+
+    #[validate(length(min = 1, max = 50000, message = "본문은 1자 이상 50000자 이하여야 합니다"))]
+    pub body: Option<String>,
+
+    pub pinned: Option<bool>,
+
+    pub publish_at: Option<DateTime<Utc>>,
+
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<DateTime<Utc>>)]
+    pub expires_at: Option<Option<DateTime<Utc>>>,
+
+    #[validate(length(max = 20, message = "대상 역할은 최대 20개까지 지정할 수 있습니다"))]
+    pub audience_roles: Option<Vec<String>>,
+}
+
+// 공지사항 피드 조회 쿼리 파라미터
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AnnouncementFeedQuery {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "페이지는 1 이상이어야 합니다"))]
+    pub page: i64,
+
+    #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 100, message = "페이지 크기는 1 이상 100 이하여야 합니다"))]
+    pub per_page: i64,
+
+    // 읽지 않은 공지만 조회
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread: Option<bool>,
+}
+
+// 공지사항 관리 목록 조회 쿼리 파라미터 (게시 예정/만료된 공지 포함)
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AnnouncementListQuery {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "페이지는 1 이상이어야 합니다"))]
+    pub page: i64,
+
+    #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 100, message = "페이지 크기는 1 이상 100 이하여야 합니다"))]
+    pub per_page: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AnnouncementStatus>,
+}
+
+// 공지사항을 읽은 사용자
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct AnnouncementRead {
+    pub user_id: i32,
+    pub name: String,
+    pub email: String,
+    pub read_at: DateTime<Utc>,
+}
+
+// 공지사항 읽음 확인 응답 구조체
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AnnouncementReadsResponse {
+    pub announcement_id: i32,
+
+    // 공지 대상인 활성 사용자 수
+    pub audience_count: i64,
+
+    pub read_count: i64,
+
+    // 읽은 사용자 (최근에 읽은 순)
+    pub reads: Vec<AnnouncementRead>,
+}
+
+// 휴가 적립 규칙 (yearly: 매년 1월 1일 전체 부여, monthly: 매월 1/12씩 적립, none: 잔여 일수 관리 안 함)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+pub enum LeaveAccrual {
+    Yearly,
+    Monthly,
+    None,
+}
+
+// 휴가 종류 모델
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct LeaveType {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub annual_days: f64,
+    pub accrual: LeaveAccrual,
+    pub max_carryover_days: f64, // 다음 해로 이월할 수 있는 최대 일수
+    pub paid: bool,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 휴가 종류 생성 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateLeaveTypeRequest {
+    #[validate(custom(function = "validate_leave_type_code"))]
+    pub code: String,
+
+    #[validate(length(min = 1, max = 100, message = "휴가 종류 이름은 1자 이상 100자 이하여야 합니다"))]
+    pub name: String,
+
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 366.0, message = "연간 일수는 0 이상 366 이하여야 합니다"))]
+    pub annual_days: f64,
+
+    #[serde(default = "default_leave_accrual")]
+    pub accrual: LeaveAccrual,
+
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 366.0, message = "최대 이월 일수는 0 이상 366 이하여야 합니다"))]
+    pub max_carryover_days: f64,
+
+    #[serde(default = "default_true")]
+    pub paid: bool,
+}
+
+// 휴가 종류 수정 요청 구조체 (생략한 항목은 유지, 코드는 변경 불가)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateLeaveTypeRequest {
+    #[validate(length(min = 1, max = 100, message = "휴가 종류 이름은 1자 이상 100자 이하여야 합니다"))]
+    pub name: Option<String>,
+
+    #[validate(range(min = 0.0, max = 366.0, message = "연간 일수는 0 이상 366 이하여야 합니다"))]
+    pub annual_days: Option<f64>,
+
+    pub accrual: Option<LeaveAccrual>,
+
+    #[validate(range(min = 0.0, max = 366.0, message = "최대 이월 일수는 0 이상 366 이하여야 합니다"))]
+    pub max_carryover_days: Option<f64>,
+
+    pub paid: Option<bool>,
+
+    pub is_active: Option<bool>,
+}
+
+fn default_leave_accrual() -> LeaveAccrual {
+    LeaveAccrual::Yearly
+}
+
+fn default_true() -> bool {
+    true
+}
+
+// 휴가 종류 코드 검사 함수 (영문 소문자, 숫자, 밑줄 1~50자)
+fn validate_leave_type_code(code: &str) -> Result<(), validator::ValidationError> {
+    let valid = !code.is_empty()
+        && code.len() <= 50
+        && code.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        let mut error = validator::ValidationError::new("invalid_code");
+        error.message = Some("코드는 영문 소문자, 숫자, 밑줄로 된 1자 이상 50자 이하여야 합니다".into());
+        Err(error)
+    }
+}
+
+// 휴가 종류 목록 조회 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LeaveTypeQuery {
+    // 사용 중지된 종류 포함 (leave.manage 권한 필요)
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+// 연도별 휴가 잔여 일수
+// entitled_days = accrued_days + carried_over_days + adjustment_days
+// remaining_days = entitled_days - used_days - pending_days (승인 대기 중인 신청도 미리 차감)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LeaveBalance {
+    pub leave_type_id: i32,
+    pub leave_type_code: String,
+    pub leave_type_name: String,
+    pub year: i32,
+
+    // 적립 규칙으로 오늘까지 부여된 일수 (지난 연도는 연말 기준)
+    pub accrued_days: f64,
+    pub carried_over_days: f64,
+    pub adjustment_days: f64,
+    pub entitled_days: f64,
+    pub used_days: f64,
+    pub pending_days: f64,
+    pub remaining_days: f64,
+}
+
+// 휴가 잔여 일수 조회 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LeaveBalanceQuery {
+    // 조회 연도 (생략하면 올해)
+    pub year: Option<i32>,
+
+    // 다른 사용자의 잔여 일수 조회 (leave.manage 권한 필요, 생략하면 본인)
+    pub user_id: Option<i32>,
+}
+
+// 휴가 잔여 일수 조정 요청 구조체 (해당 연도의 이월/조정 일수를 교체)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetLeaveBalanceRequest {
+    pub leave_type_id: i32,
+
+    #[validate(range(min = 2000, max = 2100, message = "연도는 2000 이상 2100 이하여야 합니다"))]
+    pub year: i32,
+
+    // 전년도 이월 일수 (휴가 종류의 최대 이월 일수 이하)
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 366.0, message = "이월 일수는 0 이상 366 이하여야 합니다"))]
+    pub carried_over_days: f64,
+
+    // 수동 조정 일수 (음수면 차감)
+    #[serde(default)]
+    #[validate(range(min = -366.0, max = 366.0, message = "조정 일수는 -366 이상 366 이하여야 합니다"))]
+    pub adjustment_days: f64,
+
+    #[validate(length(max = 200, message = "메모는 200자 이하여야 합니다"))]
+    pub note: Option<String>,
+}
+
+// 휴가 신청 상태 (직속 상사 승인 대기 -> 인사 승인 대기 -> 승인, 또는 반려/취소)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum LeaveStatus {
+    PendingManager,
+    PendingHr,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+impl LeaveStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PendingManager => "pending_manager",
+            Self::PendingHr => "pending_hr",
+            Self::Approved => "approved",
+            Self::Rejected => "rejected",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+// 반차 (오전/오후)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+pub enum HalfDay {
+    Am,
+    Pm,
+}
+
+// 승인 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+pub enum LeaveStep {
+    Manager,
+    Hr,
+}
+
+// 단계별 결정
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+pub enum LeaveDecision {
+    Approved,
+    Rejected,
+}
+
+// 휴가 신청 모델 (신청자 이름, 휴가 종류 포함)
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct LeaveRequest {
+    pub id: i32,
+    pub user_id: i32,
+    pub user_name: String,
+    pub leave_type_id: i32,
+    pub leave_type_code: String,
+    pub leave_type_name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub half_day: Option<HalfDay>,
+    pub days: f64, // 주말 제외 사용 일수 (반차는 0.5)
+    pub reason: Option<String>,
+    pub status: LeaveStatus,
+    pub manager_id: Option<i32>, // 1단계 승인자 (직속 상사가 없으면 null이고 바로 인사 승인 단계)
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 휴가 승인/반려 기록
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct LeaveApproval {
+    pub step: LeaveStep,
+    pub actor_id: Option<i32>,
+    pub actor_name: Option<String>,
+    pub decision: LeaveDecision,
+    pub comment: Option<String>,
+    pub decided_at: DateTime<Utc>,
+}
+
+// 휴가 신청 상세 응답 구조체 (승인/반려 기록 포함)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LeaveRequestDetailResponse {
+    #[serde(flatten)]
+    pub request: LeaveRequest,
+    pub approvals: Vec<LeaveApproval>,
+}
+
+// 휴가 신청 요청 구조체
+// 기간은 같은 연도 안이어야 하며, half_day는 하루짜리 신청에만 지정
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateLeaveRequest {
+    pub leave_type_id: i32,
+
+    pub start_date: NaiveDate,
+
+    pub end_date: NaiveDate,
+
+    pub half_day: Option<HalfDay>,
+
+    #[validate(length(max = 500, message = "사유는 500자 이하여야 합니다"))]
+    pub reason: Option<String>,
+}
+
+// 휴가 승인/반려 요청 구조체
+#[derive(Debug, Default, Deserialize, Validate, ToSchema)]
+pub struct LeaveDecisionRequest {
+    #[validate(length(max = 500, message = "의견은 500자 이하여야 합니다"))]
+    pub comment: Option<String>,
+}
+
+// 휴가 신청 목록 조회 쿼리 파라미터
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LeaveRequestListQuery {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "페이지는 1 이상이어야 합니다"))]
+    pub page: i64,
+
+    #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 100, message = "페이지 크기는 1 이상 100 이하여야 합니다"))]
+    pub per_page: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<LeaveStatus>,
+
+    // 시작일 기준 연도
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<i32>,
+
+    // 다른 사용자의 신청 조회 (leave.manage 권한 필요, 생략하면 본인)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i32>,
+}
+
+// 팀 휴가 달력 조회 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LeaveCalendarQuery {
+    pub from: NaiveDate,
+
+    // 조회 기간은 from부터 최대 93일
+    pub to: NaiveDate,
+
+    // 부서와 하위 부서 소속 사용자의 휴가 (생략하면 본인 소속 부서)
+    pub department_id: Option<i32>,
+
+    // 승인 대기 중인 신청 포함
+    #[serde(default)]
+    pub include_pending: bool,
+}
+
+// 팀 휴가 달력 항목 (사유는 포함하지 않음)
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct LeaveCalendarEntry {
+    pub leave_request_id: i32,
+    pub user_id: i32,
+    pub user_name: String,
+    pub department_id: i32,
+    pub leave_type_code: String,
+    pub leave_type_name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub half_day: Option<HalfDay>,
+    pub days: f64,
+    pub status: LeaveStatus,
+}
+
+// 예약 자원 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+pub enum ResourceKind {
+    Room,
+    Equipment,
+    Vehicle,
+}
+
+// 예약 자원 모델
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct Resource {
+    pub id: i32,
+    pub name: String,
+    pub kind: ResourceKind,
+    pub capacity: Option<i32>, // 수용 인원
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub requires_check_in: bool, // 시작 후 유예 시간 안에 체크인하지 않으면 자동 해제
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 예약 자원 생성 요청 구조체
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateResourceRequest {
+    #[validate(length(min = 1, max = 100, message = "자원 이름은 1자 이상 100자 이하여야 합니다"))]
+    pub name: String,
+
+    pub kind: ResourceKind,
+
+    #[validate(range(min = 1, max = 10000, message = "수용 인원은 1 이상 10000 이하여야 합니다"))]
+    pub capacity: Option<i32>,
+
+    #[validate(length(max = 200, message = "위치는 200자 이하여야 합니다"))]
+    pub location: Option<String>,
+
+    #[validate(length(max = 500, message = "설명은 500자 이하여야 합니다"))]
+    pub description: Option<String>,
+
+    #[serde(default = "default_true")]
+    pub requires_check_in: bool,
+}
+
+// 예약 자원 수정 요청 구조체
+// capacity/location/description은 생략하면 유지하고 null을 보내면 삭제
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateResourceRequest {
+    #[validate(length(min = 1, max = 100, message = "자원 이름은 1자 이상 100자 이하여야 합니다"))]
+    pub name: Option<String>,
+
+    pub kind: Option<ResourceKind>,
+
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<i32>)]
+    #[validate(range(min = 1, max = 10000, message = "수용 인원은 1 이상 10000 이하여야 합니다"))]
+    pub capacity: Option<Option<i32>>,
+
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<String>)]
+    #[validate(length(max = 200, message = "위치는 200자 이하여야 합니다"))]
+    pub location: Option<Option<String>>,
+
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<String>)]
+    #[validate(length(max = 500, message = "설명은 500자 이하여야 합니다"))]
+    pub description: Option<Option<String>>,
+
+    pub requires_check_in: Option<bool>,
+
+    pub is_active: Option<bool>,
+}
+
+// 예약 자원 목록 조회 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ResourceListQuery {
+    pub kind: Option<ResourceKind>,
+
+    // 수용 인원이 이 값 이상인 자원
+    pub min_capacity: Option<i32>,
+
+    // 사용 중지된 자원 포함 (resources.manage 권한 필요)
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+// 예약 가능 여부 조회 쿼리 파라미터 (사용 중인 자원만)
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ResourceAvailabilityQuery {
+    pub from: DateTime<Utc>,
+
+    // 조회 기간은 from부터 최대 31일
+    pub to: DateTime<Utc>,
+
+    pub kind: Option<ResourceKind>,
+
+    pub min_capacity: Option<i32>,
+
+    // 기간 전체가 비어 있는 자원만
+    #[serde(default)]
+    pub available_only: bool,
+}
+
+// 자원이 예약된 시간대
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct BusySlot {
+    #[serde(skip)]
+    pub resource_id: i32,
+    pub booking_id: i32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+// 자원별 예약 가능 여부 (busy는 조회 기간과 겹치는 예약, 시작 순)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResourceAvailability {
+    #[serde(flatten)]
+    pub resource: Resource,
+    pub available: bool,
+    pub busy: Vec<BusySlot>,
+}
+
+// 예약 상태 (확정 -> 체크인, 또는 자동 해제/취소)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum BookingStatus {
+    Confirmed,
+    CheckedIn,
+    Released,
+    Cancelled,
+}
+
+impl BookingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Confirmed => "confirmed",
+            Self::CheckedIn => "checked_in",
+            Self::Released => "released",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+// 예약 모델 (자원 이름, 예약자 이름 포함)
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct Booking {
+    pub id: i32,
+    pub resource_id: i32,
+    pub resource_name: String,
+    pub user_id: i32,
+    pub user_name: String,
+    pub title: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub series_id: Option<Uuid>, // 반복 예약이면 같은 반복의 회차끼리 같은 값
+    pub recurrence: Option<String>,
+    pub status: BookingStatus,
+    pub checked_in_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 예약 요청 구조체
+// recurrence는 RRULE 일부 (FREQ=DAILY|WEEKLY|MONTHLY|YEARLY, INTERVAL, COUNT 또는 UNTIL, WEEKLY의 BYDAY)
+// 반복 회차의 요일/날짜는 starts_at에 지정한 UTC 오프셋 기준으로 계산
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateBookingRequest {
+    pub resource_id: i32,
+
+    #[validate(length(min = 1, max = 200, message = "제목은 1자 이상 200자 이하여야 합니다"))]
+    pub title: String,
+
+    #[schema(value_type = DateTime<Utc>, example = "2024-07-01T09:00:00+09:00")]
+    pub starts_at: DateTime<FixedOffset>,
+
+    #[schema(value_type = DateTime<Utc>, example = "2024-07-01T10:00:00+09:00")]
+    pub ends_at: DateTime<FixedOffset>,
+
+    #[validate(length(min = 1, max = 200, message = "반복 규칙은 1자 이상 200자 이하여야 합니다"))]
+    #[schema(example = "FREQ=WEEKLY;BYDAY=MO,WE;COUNT=8")]
+    pub recurrence: Option<String>,
+}
+
+// 예약 응답 구조체 (반복 예약이면 모든 회차)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateBookingResponse {
+    pub series_id: Option<Uuid>,
+    pub bookings: Vec<Booking>,
+}
+
+// 예약 수정 요청 구조체 (한 회차만 변경, 생략한 항목은 유지)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateBookingRequest {
+    // 다른 자원으로 옮기기
+    pub resource_id: Option<i32>,
+
+    #[validate(length(min = 1, max = 200, message = "제목은 1자 이상 200자 이하여야 합니다"))]
+    pub title: Option<String>,
+
+    pub starts_at: Option<DateTime<Utc>>,
+
+    pub ends_at: Option<DateTime<Utc>>,
+}
+
+// 예약 목록 조회 쿼리 파라미터
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BookingListQuery {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "페이지는 1 이상이어야 합니다"))]
+    pub page: i64,
+
+    #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 100, message = "페이지 크기는 1 이상 100 이하여야 합니다"))]
+    pub per_page: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<i32>,
+
+    // 예약자 (생략하면 전체)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<BookingStatus>,
+
+    // 기간과 겹치는 예약만
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<DateTime<Utc>>,
+}
+
+// 예약 취소 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CancelBookingQuery {
+    // 반복 예약의 이 회차와 이후 회차를 모두 취소
+    #[serde(default)]
+    pub series: bool,
+}
+
+// 회사 일정 종류 (holiday는 .ics 가져오기 기본값)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+pub enum CalendarEventKind {
+    #[default]
+    Event,
+    Holiday,
+}
+
+impl CalendarEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Event => "event",
+            Self::Holiday => "holiday",
+        }
+    }
+}
+
+// 회사 일정 모델
+// 하루 종일 일정은 UTC 자정으로 저장하고 ends_at은 마지막 날의 다음 날
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct CalendarEvent {
+    pub id: i32,
+    pub kind: CalendarEventKind,
+    pub title: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub all_day: bool,
+    pub source_uid: Option<String>, // .ics 파일에서 가져온 일정의 UID
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 회사 일정 등록 요청 구조체
+// all_day이면 starts_at/ends_at은 UTC 자정이어야 함
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateCalendarEventRequest {
+    #[serde(default)]
+    pub kind: CalendarEventKind,
+
+    #[validate(length(min = 1, max = 200, message = "제목은 1자 이상 200자 이하여야 합니다"))]
+    pub title: String,
+
+    #[validate(length(max = 2000, message = "설명은 2000자 이하여야 합니다"))]
+    pub description: Option<String>,
+
+    #[validate(length(max = 200, message = "장소는 200자 이하여야 합니다"))]
+    pub location: Option<String>,
+
+    pub starts_at: DateTime<Utc>,
+
+    pub ends_at: DateTime<Utc>,
+
+    #[serde(default)]
+    pub all_day: bool,
+}
+
+// 회사 일정 수정 요청 구조체
+// description/location은 생략하면 유지하고 null을 보내면 삭제
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateCalendarEventRequest {
+    pub kind: Option<CalendarEventKind>,
+
+    #[validate(length(min = 1, max = 200, message = "제목은 1자 이상 200자 이하여야 합니다"))]
+    pub title: Option<String>,
+
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<String>)]
+    #[validate(length(max = 2000, message = "설명은 2000자 이하여야 합니다"))]
+    pub description: Option<Option<String>>,
+
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<String>)]
+    #[validate(length(max = 200, message = "장소는 200자 이하여야 합니다"))]
+    pub location: Option<Option<String>>,
+
+    pub starts_at: Option<DateTime<Utc>>,
+
+    pub ends_at: Option<DateTime<Utc>>,
+
+    pub all_day: Option<bool>,
+}
+
+// 회사 일정 조회 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CalendarEventQuery {
+    pub from: DateTime<Utc>,
+
+    // 조회 기간은 from부터 최대 366일
+    pub to: DateTime<Utc>,
+
+    pub kind: Option<CalendarEventKind>,
+}
+
+// .ics 가져오기 요청 (multipart/form-data, OpenAPI 문서용)
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct CalendarImportRequest {
+    // iCalendar 파일 (CALENDAR_IMPORT_MAX_BYTES 이하)
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+// .ics 가져오기 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CalendarImportQuery {
+    // 가져온 일정의 종류 (생략하면 holiday)
+    pub kind: Option<CalendarEventKind>,
+}
+
+// 가져오지 않은 일정
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SkippedCalendarEvent {
+    pub uid: Option<String>,
+    pub summary: Option<String>,
+    pub reason: String,
+}
+
+// .ics 가져오기 결과 (같은 UID의 일정은 갱신)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CalendarImportResponse {
+    pub created: i64,
+    pub updated: i64,
+    pub skipped: Vec<SkippedCalendarEvent>,
+}
+
+// 달력 구독 정보 (토큰은 발급할 때만 확인 가능)
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct CalendarFeed {
+    pub created_at: DateTime<Utc>,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+}
+
+// 달력 구독 발급 응답 (url은 Outlook/Google 캘린더의 URL로 구독에 사용)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CalendarFeedResponse {
+    pub token: String,
+    pub url: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// 달력 구독 주소
+pub fn calendar_feed_url(token: &str) -> String {
+    format!("/api/v1/calendar/{}.ics", token)
+}
+
+// 문서함 접근 권한 (상위 폴더에 부여한 권한은 하위 폴더와 문서에도 적용)
+// read: 목록 조회/내려받기, write: 업로드/새 버전/하위 폴더 생성/문서 변경, manage: 폴더 변경/삭제와 접근 권한 설정
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+pub enum DocumentAccess {
+    Read,
+    Write,
+    Manage,
+}
+
+impl DocumentAccess {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Manage => "manage",
+        }
+    }
+}
+
+// 문서함 폴더 모델 (access는 조회한 사용자의 접근 권한)
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct DocumentFolder {
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    pub name: String,
+    pub owner_id: Option<i32>, // 만든 사용자 (하위 트리 전체의 manage 권한)
+    pub owner_name: Option<String>,
+    pub access: DocumentAccess,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 폴더 목록 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DocumentFolderQuery {
+    // 생략하면 접근할 수 있는 최상위 폴더 (상위 폴더에 접근할 수 없는 공유 폴더 포함)
+    pub parent_id: Option<i32>,
+}
+
+// 폴더 생성 요청 구조체 (parent_id를 생략하면 최상위 폴더)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateDocumentFolderRequest {
+    #[validate(
+        length(min = 1, max = 200, message = "폴더 이름은 1자 이상 200자 이하여야 합니다"),
+        custom(function = "validate_document_name")
+    )]
+    pub name: String,
+
+    pub parent_id: Option<i32>,
+}
+
+// 폴더 수정 요청 구조체
+// parent_id는 생략하면 유지하고 null을 보내면 최상위 폴더로 이동
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateDocumentFolderRequest {
+    #[validate(
+        length(min = 1, max = 200, message = "폴더 이름은 1자 이상 200자 이하여야 합니다"),
+        custom(function = "validate_document_name")
+    )]
+    pub name: Option<String>,
+
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<i32>)]
+    pub parent_id: Option<Option<i32>>,
+}
+
+// 폴더 접근 권한 항목 (user_id와 role 중 하나)
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct DocumentFolderAclEntry {
+    pub user_id: Option<i32>,
+    pub user_name: Option<String>,
+    pub role: Option<String>,
+    pub access: DocumentAccess,
+}
+
+// 폴더 접근 권한 설정 항목 (user_id와 role 중 하나만 지정)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DocumentFolderAclEntryRequest {
+    pub user_id: Option<i32>,
+    pub role: Option<String>,
+    pub access: DocumentAccess,
+}
+
+// 폴더 접근 권한 교체 요청 구조체 (기존 항목은 모두 지우고 entries로 교체)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateDocumentFolderAclRequest {
+    #[validate(length(max = 100, message = "접근 권한은 최대 100개까지 지정할 수 있습니다"))]
+    pub entries: Vec<DocumentFolderAclEntryRequest>,
+}
+
+// 문서 모델 (크기, 형식, 체크섬은 현재 버전 기준)
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct Document {
+    pub id: i32,
+    pub folder_id: i32,
+    pub name: String,
+    pub owner_id: Option<i32>, // 처음 올린 사용자 (용량 할당량 계산 기준)
+    pub owner_name: Option<String>,
+    pub current_version: i32,
+    pub size_bytes: i64,
+    pub content_type: String,
+    pub checksum_sha256: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// 문서 버전 모델
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct DocumentVersion {
+    pub version: i32,
+    pub size_bytes: i64,
+    pub content_type: String,
+    pub checksum_sha256: String,
+    pub uploaded_by: Option<i32>,
+    pub uploaded_by_name: Option<String>,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// 문서 업로드 요청 (multipart/form-data, OpenAPI 문서용)
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct DocumentUploadRequest {
+    // 업로드할 파일 (DOCUMENT_MAX_BYTES 이하)
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    // 문서 이름 (생략하면 파일 이름, 새 버전 업로드에서는 무시)
+    pub name: Option<String>,
+    // 파일의 SHA-256 (16진수, 지정하면 받은 내용과 비교)
+    pub checksum: Option<String>,
+    // 버전 설명
+    pub comment: Option<String>,
+}
+
+// 문서 수정 요청 구조체 (이름 변경, 다른 폴더로 이동)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateDocumentRequest {
+    #[validate(
+        length(min = 1, max = 255, message = "문서 이름은 1자 이상 255자 이하여야 합니다"),
+        custom(function = "validate_document_name")
+    )]
+    pub name: Option<String>,
+
+    pub folder_id: Option<i32>,
+}
+
+// 문서 내려받기 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DocumentDownloadQuery {
+    // 생략하면 현재 버전
+    pub version: Option<i32>,
+}
+
+// 문서 용량 사용량 (모든 버전의 크기 합계)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DocumentQuota {
+    pub user_id: i32,
+    pub used_bytes: i64,
+    pub quota_bytes: i64,
+    pub is_default: bool, // DOCUMENT_QUOTA_BYTES 기본값 사용 여부
+}
+
+// 문서 용량 할당량 설정 요청 구조체 (quota_bytes가 null이면 기본값으로 되돌림)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetDocumentQuotaRequest {
+    #[validate(range(min = 0, message = "할당량은 0 이상이어야 합니다"))]
+    pub quota_bytes: Option<i64>,
+}
+
+// 폴더/문서 이름 검사 (경로 구분자와 제어 문자 불가, 앞뒤 공백 불가)
+pub(crate) fn validate_document_name(name: &str) -> Result<(), validator::ValidationError> {
+    let valid = name.trim() == name
+        && !matches!(name, "." | "..")
+        && !name.chars().any(|c| c.is_control() || c == '/' || c == '\\');
+
+    if valid {
+        Ok(())
+    } else {
+        let mut error = validator::ValidationError::new("invalid_name");
+        error.message = Some("이름에는 '/', '\\', 제어 문자를 쓸 수 없고 앞뒤 공백이 없어야 합니다".into());
+        Err(error)
+    }
+}
+
+// 감사 로그 모델
+#[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
+pub struct AuditEvent {
+    pub id: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub actor_id: Option<i32>,
+    pub actor_email: Option<String>,
+    pub action: String,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub changes: Option<serde_json::Value>, // 변경 전/후 값 (비밀번호 해시 제외)
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+// 새 감사 로그 항목
+#[derive(Debug, Default)]
+pub struct NewAuditEvent {
+    pub actor_id: Option<i32>,
+    pub actor_email: Option<String>,
+    pub action: String,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub changes: Option<serde_json::Value>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl NewAuditEvent {
+    pub fn new(action: &str, client: &ClientInfo) -> Self {
+        Self {
+            action: action.to_string(),
+            ip_address: client.ip_address.clone(),
+            user_agent: client.user_agent.clone(),
+            ..Default::default()
+        }
+    }
+
+    pub fn actor(mut self, actor_id: i32, actor_email: &str) -> Self {
+        self.actor_id = Some(actor_id);
+        self.actor_email = Some(actor_email.to_string());
+        self
+    }
+
+    // JWT 클레임의 사용자를 행위자로 설정
+    pub fn actor_claims(self, claims: &Claims) -> Self {
+        let actor_id = claims.sub.parse().unwrap_or(0);
+        self.actor(actor_id, &claims.email)
+    }
+
+    pub fn target(mut self, target_type: &str, target_id: impl ToString) -> Self {
+        self.target_type = Some(target_type.to_string());
+        self.target_id = Some(target_id.to_string());
+        self
+    }
+
+    pub fn changes(mut self, changes: serde_json::Value) -> Self {
+        self.changes = Some(changes);
+        self
+    }
+}
+
+// 요청 클라이언트 정보 (감사 로그용)
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+// 감사 로그 내보내기 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+// 감사 로그 조회 쿼리 파라미터
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditQuery {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "페이지는 1 이상이어야 합니다"))]
+    pub page: i64,
+
+    #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 100, message = "페이지 크기는 1 이상 100 이하여야 합니다"))]
+    pub per_page: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+// 처리 결과 안내 메시지 응답 구조체
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MessageResponse {
+    pub message: String,
+}
+
+impl MessageResponse {
+    pub fn new(message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+        }
+    }
+}
+
+// 에러 응답 구조체
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    pub error: String,
+    pub message: String,
+
+    // 유효성 검사 실패 시 필드별 오류
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<BTreeMap<String, Vec<FieldError>>>,
+}
+
+// 필드별 유효성 검사 오류
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FieldError {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn new(error: &str, message: &str) -> Self {
+        Self {
+            error: error.to_string(),
+            message: message.to_string(),
+            details: None,
+        }
+    }
+
+    pub fn with_details(mut self, details: BTreeMap<String, Vec<FieldError>>) -> Self {
+        self.details = Some(details);
+        self
+    }
+}
+
+// RFC 7807 에러 응답 구조체 (Accept: application/problem+json 요청 시)
+// code, errors는 기존 ErrorResponse의 error, details에 해당하는 확장 필드
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    pub instance: String,
+    pub trace_id: String,
+    pub code: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<BTreeMap<String, Vec<FieldError>>>,
 } 
\ No newline at end of file
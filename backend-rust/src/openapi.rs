@@ -0,0 +1,303 @@
+use utoipa::{
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+
+use crate::{handlers, models};
+
+// OpenAPI 문서 (핸들러의 #[utoipa::path]와 모델의 ToSchema에서 생성)
+// /api/v1/openapi.json 으로 제공하고 /api/v1/docs 에서 Swagger UI로 확인
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Sample Intranet API",
+        description = "사내 인트라넷 Rust 백엔드 API. 인증이 필요한 엔드포인트는 `Authorization: Bearer <액세스 토큰>` 헤더를 사용합니다."
+    ),
+    paths(
+        crate::health_check,
+        handlers::auth::register,
+        handlers::auth::login,
+        handlers::auth::verify_mfa,
+        handlers::auth::refresh,
+        handlers::auth::logout,
+        handlers::auth::logout_all,
+        handlers::auth::forgot_password,
+        handlers::auth::reset_password,
+        handlers::auth::verify_email,
+        handlers::auth::resend_verification,
+        handlers::mfa::get_mfa_status,
+        handlers::mfa::enroll_mfa,
+        handlers::mfa::confirm_mfa,
+        handlers::mfa::regenerate_recovery_codes,
+        handlers::mfa::disable_mfa,
+        handlers::users::get_users,
+        handlers::users::get_user,
+        handlers::users::update_user,
+        handlers::users::delete_user,
+        handlers::users::unlock_user,
+        handlers::users::restore_user,
+        handlers::profiles::get_profile,
+        handlers::profiles::update_profile,
+        handlers::avatars::upload_avatar,
+        handlers::avatars::delete_avatar,
+        handlers::avatars::get_avatar,
+        handlers::search::search_users,
+        handlers::roles::get_roles,
+        handlers::roles::create_role,
+        handlers::roles::update_role,
+        handlers::roles::delete_role,
+        handlers::roles::get_permissions,
+        handlers::roles::get_user_roles,
+        handlers::roles::set_user_roles,
+        handlers::audit::get_audit_events,
+        handlers::departments::get_departments,
+        handlers::departments::get_department,
+        handlers::departments::get_org_chart,
+        handlers::departments::create_department,
+        handlers::departments::update_department,
+        handlers::departments::delete_department,
+        handlers::departments::set_user_department,
+        handlers::departments::remove_user_department,
+        handlers::announcements::get_announcements,
+        handlers::announcements::get_announcement,
+        handlers::announcements::mark_announcement_read,
+        handlers::announcements::get_admin_announcements,
+        handlers::announcements::create_announcement,
+        handlers::announcements::update_announcement,
+        handlers::announcements::delete_announcement,
+        handlers::announcements::get_announcement_reads,
+        handlers::leaves::get_leave_types,
+        handlers::leaves::create_leave_type,
+        handlers::leaves::update_leave_type,
+        handlers::leaves::get_leave_balances,
+        handlers::leaves::set_leave_balance,
+        handlers::leaves::create_leave_request,
+        handlers::leaves::get_leave_requests,
+        handlers::leaves::get_pending_leave_requests,
+        handlers::leaves::get_leave_request,
+        handlers::leaves::approve_leave_request,
+        handlers::leaves::reject_leave_request,
+        handlers::leaves::cancel_leave_request,
+        handlers::leaves::get_leave_calendar,
+        handlers::bookings::get_resources,
+        handlers::bookings::get_resource_availability,
+        handlers::bookings::get_resource,
+        handlers::bookings::create_resource,
+        handlers::bookings::update_resource,
+        handlers::bookings::delete_resource,
+        handlers::bookings::create_booking,
+        handlers::bookings::get_bookings,
+        handlers::bookings::get_booking,
+        handlers::bookings::update_booking,
+        handlers::bookings::cancel_booking,
+        handlers::bookings::check_in_booking,
+        handlers::calendar::get_calendar_events,
+        handlers::calendar::create_calendar_event,
+        handlers::calendar::update_calendar_event,
+        handlers::calendar::delete_calendar_event,
+        handlers::calendar::import_calendar_events,
+        handlers::calendar::get_calendar_feed,
+        handlers::calendar::issue_calendar_feed,
+        handlers::calendar::revoke_calendar_feed,
+        handlers::calendar::get_calendar_ics,
+        handlers::documents::get_document_folders,
+        handlers::documents::create_document_folder,
+        handlers::documents::get_document_folder,
+        handlers::documents::update_document_folder,
+        handlers::documents::delete_document_folder,
+        handlers::documents::get_document_folder_acl,
+        handlers::documents::update_document_folder_acl,
+        handlers::documents::get_folder_documents,
+        handlers::documents::upload_document,
+        handlers::documents::get_document,
+        handlers::documents::update_document,
+        handlers::documents::delete_document,
+        handlers::documents::get_document_versions,
+        handlers::documents::upload_document_version,
+        handlers::documents::download_document,
+        handlers::documents::get_document_quota,
+        handlers::documents::get_user_document_quota,
+        handlers::documents::set_user_document_quota,
+    ),
+    // 응답/요청 본문에서 참조되지 않는 스키마 (쿼리 파라미터 열거형, 대체 응답 형식)
+    components(schemas(
+        models::UserSortField,
+        models::SortOrder,
+        models::ExportFormat,
+        models::MfaChallengeResponse,
+        models::ProblemDetails,
+    )),
+    modifiers(&BearerAuth),
+    tags(
+        (name = "system", description = "헬스체크"),
+        (name = "auth", description = "회원가입, 로그인, 토큰 갱신, 비밀번호 재설정, 이메일 인증"),
+        (name = "mfa", description = "2단계 인증 (TOTP) 등록 및 관리"),
+        (name = "users", description = "사용자 조회, 수정, 삭제"),
+        (name = "roles", description = "역할 및 권한 관리"),
+        (name = "audit", description = "감사 로그"),
+        (name = "departments", description = "부서, 소속, 조직도"),
+        (name = "announcements", description = "공지사항 게시, 피드, 읽음 확인"),
+        (name = "leave", description = "휴가 종류, 잔여 일수, 신청과 승인, 팀 휴가 달력"),
+        (name = "bookings", description = "회의실/장비/차량 예약, 반복 예약, 예약 가능 여부, 체크인"),
+        (name = "calendar", description = "회사 일정과 공휴일, .ics 가져오기, iCalendar 구독 피드"),
+        (name = "documents", description = "문서함 폴더와 접근 권한, 문서 업로드/버전/내려받기, 용량 할당량"),
+    )
+)]
+pub struct ApiDoc;
+
+// JWT 액세스 토큰 인증 방식 (security(("bearer_auth" = [])) 에서 참조)
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    const METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+
+    // create_router가 쓰는 routes()에 등록된 (메서드, 경로) 목록
+    // 라우트 함수가 상태(설정, 인증 미들웨어)를 받으므로 DB에 연결하지 않는 상태를 만들어 호출
+    fn router_operations() -> anyhow::Result<BTreeSet<(String, String)>> {
+        let root = std::env::temp_dir().join(format!("openapi-routes-{}", uuid::Uuid::new_v4().simple()));
+        let mut config = crate::config::Config::from_env()?;
+        config.mail.outbox_dir = root.join("mail").to_string_lossy().into_owned();
+
+        let state = crate::AppState {
+            db_pool: sqlx::postgres::PgPoolOptions::new().connect_lazy(&config.database_url)?,
+            mailer: Arc::new(crate::mail::FileMailSender::new(&config.mail)?),
+            blob_store: Arc::new(crate::storage::FilesystemBlobStore::new(root.join("storage"))?),
+            session_cache: Arc::new(crate::services::SessionCache::new(Duration::from_secs(
+                config.session_cache_seconds,
+            ))),
+            config,
+        };
+
+        let operations = crate::routes(&state)
+            .operations()
+            .iter()
+            .map(|(method, path)| (method.as_str().to_lowercase(), openapi_path(path)))
+            .collect();
+
+        std::fs::remove_dir_all(root)?;
+        Ok(operations)
+    }
+
+    // axum 경로(/users/:id/)를 OpenAPI 경로(/users/{id})로 변환
+    fn openapi_path(path: &str) -> String {
+        let converted: Vec<String> = path
+            .split('/')
+            .map(|segment| match segment.strip_prefix(':') {
+                Some(param) => format!("{{{}}}", param),
+                None => segment.to_string(),
+            })
+            .collect();
+        let converted = converted.join("/");
+        match converted.trim_end_matches('/') {
+            "" => "/".to_string(),
+            trimmed => trimmed.to_string(),
+        }
+    }
+
+    // OpenAPI 문서에 정의된 (메서드, 경로) 목록
+    fn documented_operations() -> BTreeSet<(String, String)> {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        spec["paths"]
+            .as_object()
+            .expect("paths 없음")
+            .iter()
+            .flat_map(|(path, item)| {
+                METHODS
+                    .iter()
+                    .filter(|method| item.get(**method).is_some())
+                    .map(|method| (method.to_string(), path.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn router_records_nested_routes() -> anyhow::Result<()> {
+        let operations = router_operations()?;
+        assert!(operations.contains(&("get".to_string(), "/health".to_string())));
+        assert!(operations.contains(&("get".to_string(), "/api/v1/users".to_string())));
+        assert!(operations.contains(&("put".to_string(), "/api/v1/admin/users/{id}/roles".to_string())));
+        assert!(operations.contains(&("post".to_string(), "/api/v1/auth/logout".to_string())));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn spec_matches_registered_routes() -> anyhow::Result<()> {
+        let routes = router_operations()?;
+        let documented = documented_operations();
+
+        let undocumented: Vec<_> = routes.difference(&documented).collect();
+        let unknown: Vec<_> = documented.difference(&routes).collect();
+
+        assert!(
+            undocumented.is_empty(),
+            "OpenAPI 문서에 없는 라우트가 있습니다 (핸들러에 #[utoipa::path]를 추가하고 ApiDoc paths에 등록하세요): {:?}",
+            undocumented
+        );
+        assert!(
+            unknown.is_empty(),
+            "라우터에 없는 경로가 OpenAPI 문서에 있습니다: {:?}",
+            unknown
+        );
+        Ok(())
+    }
+
+    // 문서 안의 모든 $ref가 components.schemas에 정의되어 있어야 함
+    #[test]
+    fn spec_references_resolve() {
+        fn collect_refs(value: &serde_json::Value, refs: &mut BTreeSet<String>) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    if let Some(serde_json::Value::String(reference)) = map.get("$ref") {
+                        refs.insert(reference.clone());
+                    }
+                    map.values().for_each(|v| collect_refs(v, refs));
+                }
+                serde_json::Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+                _ => {}
+            }
+        }
+
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let mut refs = BTreeSet::new();
+        collect_refs(&spec, &mut refs);
+
+        for reference in refs {
+            let name = reference.trim_start_matches("#/components/schemas/");
+            assert!(spec["components"]["schemas"].get(name).is_some(), "정의되지 않은 스키마 참조: {}", reference);
+        }
+    }
+
+    #[test]
+    fn spec_includes_models_and_bearer_scheme() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let components = &spec["components"];
+
+        for schema in ["RegisterRequest", "UserResponse", "AuthResponse", "ErrorResponse", "FieldError"] {
+            assert!(components["schemas"].get(schema).is_some(), "{} 스키마 없음", schema);
+        }
+        assert_eq!(components["securitySchemes"]["bearer_auth"]["scheme"], "bearer");
+        assert_eq!(components["securitySchemes"]["bearer_auth"]["bearerFormat"], "JWT");
+    }
+}
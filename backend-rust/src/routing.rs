@@ -0,0 +1,180 @@
+use axum::{
+    extract::Request,
+    handler::Handler,
+    http::Method,
+    response::IntoResponse,
+    routing::{MethodFilter, MethodRouter, Route},
+    Router,
+};
+use std::convert::Infallible;
+use tower::{Layer, Service};
+
+use crate::AppState;
+
+// 등록한 (메서드, 경로)를 함께 기록하는 라우터
+// axum Router는 등록된 라우트를 조회할 수 없으므로 OpenAPI 문서와 비교할 목록을 여기서 모은다
+pub struct ApiRouter {
+    router: Router<AppState>,
+    operations: Vec<(Method, String)>,
+}
+
+impl ApiRouter {
+    pub fn new() -> Self {
+        Self {
+            router: Router::new(),
+            operations: Vec::new(),
+        }
+    }
+
+    pub fn route(mut self, path: &str, methods: ApiMethods) -> Self {
+        self.operations
+            .extend(methods.methods.into_iter().map(|method| (method, path.to_string())));
+        self.router = self.router.route(path, methods.router);
+        self
+    }
+
+    pub fn nest(mut self, path: &str, other: ApiRouter) -> Self {
+        self.operations.extend(
+            other
+                .operations
+                .into_iter()
+                .map(|(method, nested)| (method, format!("{}{}", path, nested))),
+        );
+        self.router = self.router.nest(path, other.router);
+        self
+    }
+
+    pub fn merge(mut self, other: ApiRouter) -> Self {
+        self.operations.extend(other.operations);
+        self.router = self.router.merge(other.router);
+        self
+    }
+
+    pub fn route_layer<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + 'static,
+        L::Service: Service<Request> + Clone + Send + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        self.router = self.router.route_layer(layer);
+        self
+    }
+
+    // 등록된 (메서드, 경로) 목록 (경로는 axum 형식, 예: /api/v1/users/:id)
+    pub fn operations(&self) -> &[(Method, String)] {
+        &self.operations
+    }
+
+    pub fn into_router(self) -> Router<AppState> {
+        self.router
+    }
+}
+
+impl Default for ApiRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// 한 경로의 메서드별 핸들러 (get(a).put(b) 형태로 연결)
+pub struct ApiMethods {
+    router: MethodRouter<AppState>,
+    methods: Vec<Method>,
+}
+
+impl ApiMethods {
+    fn on<H, T>(mut self, filter: MethodFilter, method: Method, handler: H) -> Self
+    where
+        H: Handler<T, AppState>,
+        T: 'static,
+    {
+        self.router = self.router.on(filter, handler);
+        self.methods.push(method);
+        self
+    }
+
+    pub fn get<H, T>(self, handler: H) -> Self
+    where
+        H: Handler<T, AppState>,
+        T: 'static,
+    {
+        self.on(MethodFilter::GET, Method::GET, handler)
+    }
+
+    pub fn post<H, T>(self, handler: H) -> Self
+    where
+        H: Handler<T, AppState>,
+        T: 'static,
+    {
+        self.on(MethodFilter::POST, Method::POST, handler)
+    }
+
+    pub fn put<H, T>(self, handler: H) -> Self
+    where
+        H: Handler<T, AppState>,
+        T: 'static,
+    {
+        self.on(MethodFilter::PUT, Method::PUT, handler)
+    }
+
+    pub fn delete<H, T>(self, handler: H) -> Self
+    where
+        H: Handler<T, AppState>,
+        T: 'static,
+    {
+        self.on(MethodFilter::DELETE, Method::DELETE, handler)
+    }
+
+    pub fn layer<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + 'static,
+        L::Service: Service<Request> + Clone + Send + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        self.router = self.router.layer(layer);
+        self
+    }
+}
+
+fn methods() -> ApiMethods {
+    ApiMethods {
+        router: MethodRouter::new(),
+        methods: Vec::new(),
+    }
+}
+
+pub fn get<H, T>(handler: H) -> ApiMethods
+where
+    H: Handler<T, AppState>,
+    T: 'static,
+{
+    methods().get(handler)
+}
+
+pub fn post<H, T>(handler: H) -> ApiMethods
+where
+    H: Handler<T, AppState>,
+    T: 'static,
+{
+    methods().post(handler)
+}
+
+pub fn put<H, T>(handler: H) -> ApiMethods
+where
+    H: Handler<T, AppState>,
+    T: 'static,
+{
+    methods().put(handler)
+}
+
+pub fn delete<H, T>(handler: H) -> ApiMethods
+where
+    H: Handler<T, AppState>,
+    T: 'static,
+{
+    methods().delete(handler)
+}
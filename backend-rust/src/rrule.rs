@@ -0,0 +1,315 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+
+// 반복 예약 한 번에 만들 수 있는 최대 회차
+pub const MAX_OCCURRENCES: usize = 100;
+
+// 규칙에 맞는 날짜가 하나도 없는 경우(매월 31일 + 12개월 간격 등)에도 끝나도록 확인할 최대 주기 수
+const MAX_PERIODS: u32 = 1200;
+
+// 반복 주기
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+// 반복 종료 조건
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceEnd {
+    Count(usize),
+    Until(Until),
+}
+
+// UNTIL 값 (날짜만 지정하면 시작 시각의 UTC 오프셋 기준으로 그날 전체 포함)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Until {
+    Date(NaiveDate),
+    Local(NaiveDateTime),
+    Utc(DateTime<Utc>),
+}
+
+// 반복 규칙 파싱/전개 오류
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RRuleError {
+    #[error("반복 규칙 형식이 올바르지 않습니다: {0}")]
+    Syntax(String),
+
+    #[error("지원하지 않는 반복 규칙 항목입니다: {0}")]
+    Unsupported(String),
+
+    #[error("반복 규칙에 FREQ가 필요합니다")]
+    MissingFrequency,
+
+    #[error("반복 규칙에 COUNT 또는 UNTIL 중 하나가 필요합니다")]
+    MissingEnd,
+
+    #[error("반복 회차는 최대 {}회입니다", MAX_OCCURRENCES)]
+    TooManyOccurrences,
+
+    #[error("반복 규칙에 맞는 회차가 없습니다")]
+    NoOccurrences,
+}
+
+// RFC 5545 RRULE 중 예약에 필요한 일부
+// FREQ=DAILY|WEEKLY|MONTHLY|YEARLY, INTERVAL, COUNT 또는 UNTIL, BYDAY(WEEKLY에서 요일 목록만)
+// 예: FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;COUNT=8
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub end: RecurrenceEnd,
+    pub by_day: Vec<Weekday>,
+}
+
+impl RecurrenceRule {
+    // "RRULE:" 접두사는 있어도 되고 항목 이름은 대소문자를 구분하지 않음
+    pub fn parse(rule: &str) -> Result<Self, RRuleError> {
+        let rule = rule.trim();
+        let rule = rule
+            .get(..6)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("RRULE:"))
+            .map_or(rule, |_| &rule[6..]);
+
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut count = None;
+        let mut until = None;
+        let mut by_day = Vec::new();
+
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| RRuleError::Syntax(part.to_string()))?;
+            let value = value.trim().to_ascii_uppercase();
+
+            match name.trim().to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(RRuleError::Unsupported(part.to_string())),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|interval| (1..=99).contains(interval))
+                        .ok_or_else(|| RRuleError::Syntax(part.to_string()))?
+                }
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|count| *count >= 1)
+                            .ok_or_else(|| RRuleError::Syntax(part.to_string()))?,
+                    )
+                }
+                "UNTIL" => until = Some(parse_until(&value).ok_or_else(|| RRuleError::Syntax(part.to_string()))?),
+                "BYDAY" => {
+                    for day in value.split(',') {
+                        let day = parse_weekday(day).ok_or_else(|| RRuleError::Unsupported(part.to_string()))?;
+                        if !by_day.contains(&day) {
+                            by_day.push(day);
+                        }
+                    }
+                }
+                _ => return Err(RRuleError::Unsupported(part.to_string())),
+            }
+        }
+
+        let frequency = frequency.ok_or(RRuleError::MissingFrequency)?;
+        if !by_day.is_empty() && frequency != Frequency::Weekly {
+            return Err(RRuleError::Unsupported("BYDAY는 FREQ=WEEKLY에서만 사용할 수 있습니다".to_string()));
+        }
+        let end = match (count, until) {
+            (Some(count), None) if count > MAX_OCCURRENCES => return Err(RRuleError::TooManyOccurrences),
+            (Some(count), None) => RecurrenceEnd::Count(count),
+            (None, Some(until)) => RecurrenceEnd::Until(until),
+            (Some(_), Some(_)) => return Err(RRuleError::Syntax("COUNT와 UNTIL은 함께 쓸 수 없습니다".to_string())),
+            (None, None) => return Err(RRuleError::MissingEnd),
+        };
+        by_day.sort_by_key(|day| day.num_days_from_monday());
+
+        Ok(Self { frequency, interval, end, by_day })
+    }
+
+    // 회차별 시작 시각 (첫 회차의 UTC 오프셋 기준으로 요일/날짜 계산, 일광 절약 시간은 반영하지 않음)
+    // 규칙에 맞는 start 이후의 회차만 포함하므로 BYDAY에 start의 요일이 없으면 start는 회차가 아님
+    // 해당 월에 없는 날짜(31일, 2월 29일)는 건너뜀
+    pub fn occurrences(&self, start: DateTime<FixedOffset>) -> Result<Vec<DateTime<FixedOffset>>, RRuleError> {
+        let offset = *start.offset();
+        let local_start = start.naive_local();
+        let start_date = local_start.date();
+        let time = local_start.time();
+
+        let until = match self.end {
+            RecurrenceEnd::Count(_) => None,
+            RecurrenceEnd::Until(Until::Date(date)) => Some(date.and_hms_opt(23, 59, 59).unwrap_or(local_start)),
+            RecurrenceEnd::Until(Until::Local(until)) => Some(until),
+            RecurrenceEnd::Until(Until::Utc(until)) => Some(until.with_timezone(&offset).naive_local()),
+        };
+        let limit = match self.end {
+            RecurrenceEnd::Count(count) => count,
+            RecurrenceEnd::Until(_) => MAX_OCCURRENCES,
+        };
+
+        let by_day = if self.by_day.is_empty() {
+            vec![start_date.weekday()]
+        } else {
+            self.by_day.clone()
+        };
+        let week_start = start_date - Duration::days(start_date.weekday().num_days_from_monday() as i64);
+
+        let mut occurrences = Vec::new();
+        'periods: for period in 0..MAX_PERIODS {
+            let step = period * self.interval;
+            let dates = match self.frequency {
+                Frequency::Daily => vec![Some(start_date + Duration::days(step as i64))],
+                Frequency::Weekly => {
+                    let week = week_start + Duration::weeks(step as i64);
+                    by_day
+                        .iter()
+                        .map(|day| Some(week + Duration::days(day.num_days_from_monday() as i64)))
+                        .collect()
+                }
+                Frequency::Monthly => vec![same_day_in_month(start_date, step)],
+                Frequency::Yearly => vec![same_day_in_month(start_date, step * 12)],
+            };
+
+            for date in dates.into_iter().flatten().filter(|date| *date >= start_date) {
+                let occurrence = date.and_time(time);
+                if until.is_some_and(|until| occurrence > until) {
+                    break 'periods;
+                }
+                if occurrences.len() == limit {
+                    if matches!(self.end, RecurrenceEnd::Count(_)) {
+                        break 'periods;
+                    }
+                    return Err(RRuleError::TooManyOccurrences);
+                }
+                if let Some(occurrence) = offset.from_local_datetime(&occurrence).single() {
+                    occurrences.push(occurrence);
+                }
+            }
+        }
+
+        if occurrences.is_empty() {
+            return Err(RRuleError::NoOccurrences);
+        }
+        Ok(occurrences)
+    }
+}
+
+// months개월 뒤의 같은 날 (그 달에 없는 날이면 None)
+fn same_day_in_month(date: NaiveDate, months: u32) -> Option<NaiveDate> {
+    let first = date.with_day(1)?.checked_add_months(Months::new(months))?;
+    first.with_day(date.day())
+}
+
+fn parse_weekday(day: &str) -> Option<Weekday> {
+    Some(match day.trim() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+// UNTIL=YYYYMMDD, YYYYMMDDTHHMMSS(첫 회차 기준 현지 시각), YYYYMMDDTHHMMSSZ(UTC)
+fn parse_until(value: &str) -> Option<Until> {
+    if let Some(value) = value.strip_suffix('Z') {
+        return NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+            .ok()
+            .map(|until| Until::Utc(until.and_utc()));
+    }
+    if value.contains('T') {
+        return NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok().map(Until::Local);
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d").ok().map(Until::Date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kst(text: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(text).unwrap()
+    }
+
+    fn starts(rule: &str, start: &str) -> Result<Vec<String>, RRuleError> {
+        Ok(RecurrenceRule::parse(rule)?
+            .occurrences(kst(start))?
+            .iter()
+            .map(|occurrence| occurrence.to_rfc3339())
+            .collect())
+    }
+
+    #[test]
+    fn parses_supported_subset() {
+        let rule = RecurrenceRule::parse("RRULE:freq=weekly;INTERVAL=2;BYDAY=WE,MO,MO;COUNT=4").unwrap();
+        assert_eq!(rule.frequency, Frequency::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(rule.end, RecurrenceEnd::Count(4));
+        assert_eq!(rule.by_day, vec![Weekday::Mon, Weekday::Wed]);
+
+        assert_eq!(RecurrenceRule::parse("COUNT=3"), Err(RRuleError::MissingFrequency));
+        assert_eq!(RecurrenceRule::parse("FREQ=DAILY"), Err(RRuleError::MissingEnd));
+        assert_eq!(RecurrenceRule::parse("FREQ=DAILY;COUNT=101"), Err(RRuleError::TooManyOccurrences));
+        assert!(matches!(RecurrenceRule::parse("FREQ=HOURLY;COUNT=3"), Err(RRuleError::Unsupported(_))));
+        assert!(matches!(RecurrenceRule::parse("FREQ=WEEKLY;BYDAY=1MO;COUNT=3"), Err(RRuleError::Unsupported(_))));
+        assert!(matches!(RecurrenceRule::parse("FREQ=DAILY;BYDAY=MO;COUNT=3"), Err(RRuleError::Unsupported(_))));
+        assert!(matches!(RecurrenceRule::parse("FREQ=DAILY;COUNT=3;UNTIL=20240701"), Err(RRuleError::Syntax(_))));
+        assert!(matches!(RecurrenceRule::parse("FREQ=DAILY;INTERVAL=0;COUNT=3"), Err(RRuleError::Syntax(_))));
+    }
+
+    #[test]
+    fn expands_daily_and_weekly_rules_in_start_offset() {
+        assert_eq!(
+            starts("FREQ=DAILY;INTERVAL=2;COUNT=3", "2024-07-01T09:00:00+09:00").unwrap(),
+            vec!["2024-07-01T09:00:00+09:00", "2024-07-03T09:00:00+09:00", "2024-07-05T09:00:00+09:00"]
+        );
+
+        // 월요일 오전 8시(KST)는 UTC로 일요일이지만 요일은 시작 시각의 오프셋 기준
+        // 2024-07-03(수)에 시작하면 그 주의 월요일은 건너뜀
+        assert_eq!(
+            starts("FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20240710", "2024-07-03T08:00:00+09:00").unwrap(),
+            vec!["2024-07-03T08:00:00+09:00", "2024-07-08T08:00:00+09:00", "2024-07-10T08:00:00+09:00"]
+        );
+
+        assert_eq!(
+            starts("FREQ=WEEKLY;INTERVAL=2;COUNT=2", "2024-07-01T09:00:00+09:00").unwrap(),
+            vec!["2024-07-01T09:00:00+09:00", "2024-07-15T09:00:00+09:00"]
+        );
+
+        // UTC로 지정한 UNTIL은 해당 시각의 회차까지 포함
+        assert_eq!(
+            starts("FREQ=DAILY;UNTIL=20240702T000000Z", "2024-07-01T09:00:00+09:00").unwrap(),
+            vec!["2024-07-01T09:00:00+09:00", "2024-07-02T09:00:00+09:00"]
+        );
+    }
+
+    #[test]
+    fn skips_missing_month_days_and_limits_occurrences() {
+        assert_eq!(
+            starts("FREQ=MONTHLY;COUNT=3", "2024-01-31T10:00:00+09:00").unwrap(),
+            vec!["2024-01-31T10:00:00+09:00", "2024-03-31T10:00:00+09:00", "2024-05-31T10:00:00+09:00"]
+        );
+        assert_eq!(
+            starts("FREQ=YEARLY;COUNT=2", "2024-02-29T10:00:00+09:00").unwrap(),
+            vec!["2024-02-29T10:00:00+09:00", "2028-02-29T10:00:00+09:00"]
+        );
+
+        assert_eq!(starts("FREQ=DAILY;UNTIL=20250101", "2024-07-01T09:00:00+09:00"), Err(RRuleError::TooManyOccurrences));
+        assert_eq!(starts("FREQ=DAILY;UNTIL=20240630", "2024-07-01T09:00:00+09:00"), Err(RRuleError::NoOccurrences));
+    }
+}
@@ -0,0 +1,264 @@
+use anyhow::Result;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+
+use crate::models::{
+    Announcement, AnnouncementFeedQuery, AnnouncementListQuery, AnnouncementRead, AnnouncementStatus,
+    CreateAnnouncementRequest, UpdateAnnouncementRequest,
+};
+
+// 공지사항 조회 컬럼 (작성자 이름, 조회자의 읽음 시각, 읽은 사용자 수)
+// 조회자 ID는 push_from에서 바인딩
+const ANNOUNCEMENT_COLUMNS: &str = r#"
+    a.id, a.title, a.body, a.author_id, author.name AS author_name, a.pinned,
+    a.publish_at, a.expires_at, a.audience_roles, r.read_at,
+    (SELECT COUNT(*) FROM announcement_reads ar JOIN users u ON u.id = ar.user_id
+     WHERE ar.announcement_id = a.id AND u.deleted_at IS NULL) AS read_count,
+    a.created_at, a.updated_at
+"#;
+
+pub struct AnnouncementService {
+    pool: PgPool,
+}
+
+impl AnnouncementService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 조회자에게 게시 중인 공지 피드 (고정 공지 먼저, 최근 게시 순)
+    pub async fn feed(&self, viewer_id: i32, query: &AnnouncementFeedQuery) -> Result<(Vec<Announcement>, i64)> {
+        let push_filters = |builder: &mut QueryBuilder<Postgres>| {
+            builder.push(" WHERE ");
+            push_visible(builder, viewer_id);
+            if query.unread == Some(true) {
+                builder.push(" AND r.read_at IS NULL");
+            }
+        };
+
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*)");
+        push_from(&mut count_query, viewer_id);
+        push_filters(&mut count_query);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut list_query = QueryBuilder::<Postgres>::new("SELECT");
+        list_query.push(ANNOUNCEMENT_COLUMNS);
+        push_from(&mut list_query, viewer_id);
+        push_filters(&mut list_query);
+        list_query.push(" ORDER BY a.pinned DESC, a.publish_at DESC, a.id DESC");
+        list_query.push(" LIMIT ").push_bind(query.per_page);
+        list_query.push(" OFFSET ").push_bind((query.page - 1) * query.per_page);
+
+        let announcements = list_query
+            .build_query_as::<Announcement>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok((announcements, total))
+    }
+
+    // 관리 목록 (게시 예정/만료 포함, 게시 시각 최근 순)
+    pub async fn find_page(&self, viewer_id: i32, query: &AnnouncementListQuery) -> Result<(Vec<Announcement>, i64)> {
+        let push_filters = |builder: &mut QueryBuilder<Postgres>| {
+            builder.push(match query.status {
+                None => " WHERE TRUE",
+                Some(AnnouncementStatus::Scheduled) => " WHERE a.publish_at > NOW()",
+                Some(AnnouncementStatus::Published) => {
+                    " WHERE a.publish_at <= NOW() AND (a.expires_at IS NULL OR a.expires_at > NOW())"
+                }
+                Some(AnnouncementStatus::Expired) => " WHERE a.expires_at <= NOW()",
+            });
+        };
+
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*)");
+        push_from(&mut count_query, viewer_id);
+        push_filters(&mut count_query);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut list_query = QueryBuilder::<Postgres>::new("SELECT");
+        list_query.push(ANNOUNCEMENT_COLUMNS);
+        push_from(&mut list_query, viewer_id);
+        push_filters(&mut list_query);
+        list_query.push(" ORDER BY a.publish_at DESC, a.id DESC");
+        list_query.push(" LIMIT ").push_bind(query.per_page);
+        list_query.push(" OFFSET ").push_bind((query.page - 1) * query.per_page);
+
+        let announcements = list_query
+            .build_query_as::<Announcement>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok((announcements, total))
+    }
+
+    // ID로 공지 조회 (게시 상태/대상과 관계없이)
+    pub async fn find_by_id(&self, id: i32, viewer_id: i32) -> Result<Option<Announcement>> {
+        let mut query = QueryBuilder::<Postgres>::new("SELECT");
+        query.push(ANNOUNCEMENT_COLUMNS);
+        push_from(&mut query, viewer_id);
+        query.push(" WHERE a.id = ").push_bind(id);
+
+        Ok(query
+            .build_query_as::<Announcement>()
+            .fetch_optional(&self.pool)
+            .await?)
+    }
+
+    // 조회자에게 게시 중인 공지인지 확인 (게시 기간 안이고 대상 역할에 포함)
+    pub async fn is_visible(&self, id: i32, viewer_id: i32) -> Result<bool> {
+        let mut query = QueryBuilder::<Postgres>::new("SELECT EXISTS (SELECT 1 FROM announcements a WHERE a.id = ");
+        query.push_bind(id).push(" AND ");
+        push_visible(&mut query, viewer_id);
+        query.push(")");
+
+        Ok(query.build_query_scalar().fetch_one(&self.pool).await?)
+    }
+
+    // 공지 작성
+    pub async fn create(&self, author_id: i32, request: &CreateAnnouncementRequest) -> Result<i32> {
+        let id = sqlx::query_scalar::<_, i32>(
+            r#"
+            INSERT INTO announcements (title, body, author_id, pinned, publish_at, expires_at, audience_roles)
+            VALUES ($1, $2, $3, $4, COALESCE($5, NOW()), $6, $7)
+            RETURNING id
+            "#,
+        )
+        .bind(&request.title)
+        .bind(&request.body)
+        .bind(author_id)
+        .bind(request.pinned)
+        .bind(request.publish_at)
+        .bind(request.expires_at)
+        .bind(&request.audience_roles)
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("공지사항 작성 완료: {} ({})", request.title, id);
+        Ok(id)
+    }
+
+    // 공지 수정 (생략한 항목은 유지)
+    pub async fn update(&self, id: i32, request: &UpdateAnnouncementRequest) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE announcements
+            SET title = COALESCE($1, title),
+                body = COALESCE($2, body),
+                pinned = COALESCE($3, pinned),
+                publish_at = COALESCE($4, publish_at),
+                expires_at = CASE WHEN $5 THEN $6 ELSE expires_at END,
+                audience_roles = COALESCE($7, audience_roles),
+                updated_at = NOW()
+            WHERE id = $8
+            "#,
+        )
+        .bind(&request.title)
+        .bind(&request.body)
+        .bind(request.pinned)
+        .bind(request.publish_at)
+        .bind(request.expires_at.is_some())
+        .bind(request.expires_at.flatten())
+        .bind(&request.audience_roles)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() > 0 {
+            tracing::info!("공지사항 수정 완료: {}", id);
+        }
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 공지 삭제 (읽음 기록도 함께 삭제)
+    pub async fn delete(&self, id: i32) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM announcements WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            tracing::info!("공지사항 삭제 완료: {}", id);
+        }
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 읽음 표시 (처음 읽은 시각을 유지하므로 여러 번 호출해도 같은 결과)
+    pub async fn mark_read(&self, id: i32, user_id: i32) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO announcement_reads (announcement_id, user_id)
+            VALUES ($1, $2)
+            ON CONFLICT (announcement_id, user_id) DO NOTHING
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // 공지를 읽은 사용자 (삭제된 사용자 제외, 최근에 읽은 순)
+    pub async fn reads(&self, id: i32) -> Result<Vec<AnnouncementRead>> {
+        let reads = sqlx::query_as::<_, AnnouncementRead>(
+            r#"
+            SELECT u.id AS user_id, u.name, u.email, ar.read_at
+            FROM announcement_reads ar
+            JOIN users u ON u.id = ar.user_id
+            WHERE ar.announcement_id = $1 AND u.deleted_at IS NULL
+            ORDER BY ar.read_at DESC, u.id
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(reads)
+    }
+
+    // 공지 대상인 활성 사용자 수 (대상 역할이 비어 있으면 전체)
+    pub async fn audience_count(&self, audience_roles: &[String]) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*)
+            FROM users u
+            WHERE u.deleted_at IS NULL AND u.is_active
+              AND (cardinality($1::text[]) = 0
+                   OR u.role = ANY($1)
+                   OR EXISTS (SELECT 1 FROM user_roles ur JOIN roles r ON r.id = ur.role_id
+                              WHERE ur.user_id = u.id AND r.name = ANY($1)))
+            "#,
+        )
+        .bind(audience_roles)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+}
+
+// 공지 테이블과 작성자, 조회자의 읽음 기록 조인
+fn push_from(builder: &mut QueryBuilder<Postgres>, viewer_id: i32) {
+    builder
+        .push(" FROM announcements a LEFT JOIN users author ON author.id = a.author_id")
+        .push(" LEFT JOIN announcement_reads r ON r.announcement_id = a.id AND r.user_id = ")
+        .push_bind(viewer_id);
+}
+
+// 게시 기간 안이고 조회자의 기본 역할이나 추가 역할이 대상에 포함되는 공지 조건
+fn push_visible(builder: &mut QueryBuilder<Postgres>, viewer_id: i32) {
+    builder
+        .push("a.publish_at <= NOW() AND (a.expires_at IS NULL OR a.expires_at > NOW())")
+        .push(" AND (cardinality(a.audience_roles) = 0 OR a.audience_roles && ARRAY(")
+        .push("SELECT role::text FROM users WHERE id = ")
+        .push_bind(viewer_id)
+        .push(" UNION SELECT ro.name FROM user_roles ur JOIN roles ro ON ro.id = ur.role_id WHERE ur.user_id = ")
+        .push_bind(viewer_id)
+        .push("))");
+}
@@ -0,0 +1,142 @@
+use anyhow::Result;
+use serde_json::{json, Map, Value};
+use sqlx::{PgPool, Postgres, QueryBuilder};
+
+use crate::models::{AuditEvent, AuditQuery, NewAuditEvent, User};
+
+// CSV 내보내기 최대 행 수
+pub const AUDIT_EXPORT_LIMIT: i64 = 10_000;
+
+pub struct AuditService {
+    pool: PgPool,
+}
+
+impl AuditService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 감사 로그 기록
+    // 기록 실패가 원래 작업을 실패시키지 않도록 에러는 로그로만 남긴다
+    pub async fn record(&self, event: NewAuditEvent) {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO audit_events
+                (actor_id, actor_email, action, target_type, target_id, changes, ip_address, user_agent)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(event.actor_id)
+        .bind(&event.actor_email)
+        .bind(&event.action)
+        .bind(&event.target_type)
+        .bind(&event.target_id)
+        .bind(&event.changes)
+        .bind(&event.ip_address)
+        .bind(&event.user_agent)
+        .execute(&self.pool)
+        .await;
+
+        if let Err(err) = result {
+            tracing::error!("감사 로그 기록 실패 ({}): {}", event.action, err);
+        }
+    }
+
+    // 감사 로그 조회 (필터/페이지네이션), 전체 개수도 함께 반환
+    pub async fn find_page(&self, query: &AuditQuery) -> Result<(Vec<AuditEvent>, i64)> {
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM audit_events");
+        push_filters(&mut count_query, query);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let events = self
+            .find_events(query, query.per_page, (query.page - 1) * query.per_page)
+            .await?;
+
+        Ok((events, total))
+    }
+
+    // 내보내기용 감사 로그 조회 (페이지 무시, 최대 AUDIT_EXPORT_LIMIT 행)
+    pub async fn export(&self, query: &AuditQuery) -> Result<Vec<AuditEvent>> {
+        self.find_events(query, AUDIT_EXPORT_LIMIT, 0).await
+    }
+
+    async fn find_events(&self, query: &AuditQuery, limit: i64, offset: i64) -> Result<Vec<AuditEvent>> {
+        let mut list_query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT id, occurred_at, actor_id, actor_email, action, target_type, target_id,
+                   changes, ip_address, user_agent
+            FROM audit_events
+            "#,
+        );
+        push_filters(&mut list_query, query);
+        list_query.push(" ORDER BY occurred_at DESC, id DESC");
+        list_query.push(" LIMIT ").push_bind(limit);
+        list_query.push(" OFFSET ").push_bind(offset);
+
+        let events = list_query
+            .build_query_as::<AuditEvent>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(events)
+    }
+}
+
+// 사용자 변경 전/후 차이 계산 ({필드: {before, after}})
+// 비밀번호 해시는 기록하지 않고 변경 여부만 남긴다
+pub fn user_changes(before: &User, after: &User) -> Value {
+    let before_fields = serde_json::to_value(before).unwrap_or_default();
+    let after_fields = serde_json::to_value(after).unwrap_or_default();
+
+    let mut changes = Map::new();
+    if let (Value::Object(before_fields), Value::Object(after_fields)) = (before_fields, after_fields) {
+        for (field, after_value) in after_fields {
+            // updated_at은 모든 수정에서 바뀌므로 제외
+            if field == "updated_at" {
+                continue;
+            }
+            let before_value = before_fields.get(&field).cloned().unwrap_or(Value::Null);
+            if before_value != after_value {
+                changes.insert(field, json!({ "before": before_value, "after": after_value }));
+            }
+        }
+    }
+
+    if before.password != after.password {
+        changes.insert("password".to_string(), json!({ "changed": true }));
+    }
+
+    Value::Object(changes)
+}
+
+// 조회 필터 조건 추가
+fn push_filters(builder: &mut QueryBuilder<Postgres>, query: &AuditQuery) {
+    builder.push(" WHERE TRUE");
+
+    if let Some(action) = &query.action {
+        builder.push(" AND action = ").push_bind(action.clone());
+    }
+
+    if let Some(actor_id) = query.actor_id {
+        builder.push(" AND actor_id = ").push_bind(actor_id);
+    }
+
+    if let Some(target_type) = &query.target_type {
+        builder.push(" AND target_type = ").push_bind(target_type.clone());
+    }
+
+    if let Some(target_id) = &query.target_id {
+        builder.push(" AND target_id = ").push_bind(target_id.clone());
+    }
+
+    if let Some(from) = query.from {
+        builder.push(" AND occurred_at >= ").push_bind(from);
+    }
+
+    if let Some(to) = query.to {
+        builder.push(" AND occurred_at < ").push_bind(to);
+    }
+}
@@ -0,0 +1,149 @@
+use image::{imageops::FilterType, DynamicImage, ImageFormat, ImageReader, Limits};
+use sqlx::{PgConnection, PgPool};
+use std::{io::Cursor, sync::Arc};
+
+use crate::{
+    models::AVATAR_SIZES,
+    storage::{content_hash, lock_blob, BlobStore},
+};
+
+// 디코딩할 원본 이미지의 최대 가로/세로 (압축 폭탄 방지)
+const MAX_DIMENSION: u32 = 8000;
+
+// 프로필 사진 처리 오류
+#[derive(Debug, thiserror::Error)]
+pub enum AvatarError {
+    #[error("지원하지 않는 이미지 형식입니다 (PNG, JPEG, GIF, WebP만 가능)")]
+    UnsupportedFormat,
+
+    #[error("이미지를 읽을 수 없습니다")]
+    InvalidImage,
+
+    #[error("이미지가 너무 큽니다 (최대 {MAX_DIMENSION}x{MAX_DIMENSION} 픽셀)")]
+    TooLarge,
+
+    #[error(transparent)]
+    Storage(#[from] anyhow::Error),
+}
+
+// 크기별 썸네일로 변환한 프로필 사진 (저장 전)
+pub struct RenderedAvatar {
+    pub hash: String,
+    thumbnails: Vec<(u32, Vec<u8>)>,
+}
+
+pub struct AvatarService {
+    store: Arc<dyn BlobStore>,
+}
+
+impl AvatarService {
+    pub fn new(store: Arc<dyn BlobStore>) -> Self {
+        Self { store }
+    }
+
+    // 저장소 키
+    pub fn key(hash: &str, size: u32) -> String {
+        format!("avatars/{}/{}.png", hash, size)
+    }
+
+    // 원본 이미지를 크기별 썸네일로 변환 (트랜잭션과 잠금 없이 먼저 실행)
+    // 이미지 디코딩/크기 조정은 CPU 작업이므로 블로킹 스레드에서 실행
+    pub async fn render(data: Vec<u8>) -> Result<RenderedAvatar, AvatarError> {
+        tokio::task::spawn_blocking(move || {
+            Ok(RenderedAvatar {
+                hash: content_hash(&data),
+                thumbnails: render_thumbnails(&data)?,
+            })
+        })
+        .await
+        .map_err(anyhow::Error::from)?
+    }
+
+    // 변환한 썸네일을 저장하고 원본의 해시 반환 (같은 이미지가 이미 저장되어 있으면 그대로 사용)
+    // 사용자의 avatar_hash를 바꾸는 트랜잭션에서 호출해 커밋할 때까지 같은 사진의 삭제를 막음
+    pub async fn save(&self, conn: &mut PgConnection, avatar: RenderedAvatar) -> Result<String, AvatarError> {
+        let RenderedAvatar { hash, thumbnails } = avatar;
+        let largest = AVATAR_SIZES[AVATAR_SIZES.len() - 1];
+        lock_blob(conn, &Self::key(&hash, largest)).await?;
+        if self.store.exists(&Self::key(&hash, largest)).await? {
+            return Ok(hash);
+        }
+
+        // 가장 큰 크기를 마지막에 저장 (위의 존재 확인이 모든 크기가 저장된 것을 뜻하도록)
+        for (size, png) in thumbnails {
+            self.store.put(&Self::key(&hash, size), png, "image/png").await?;
+        }
+
+        tracing::info!("프로필 사진 저장 완료: {}", hash);
+        Ok(hash)
+    }
+
+    // 썸네일 조회 (없으면 None)
+    pub async fn get(&self, hash: &str, size: u32) -> anyhow::Result<Option<Vec<u8>>> {
+        self.store.get(&Self::key(hash, size)).await
+    }
+
+    // 쓰는 사용자가 없으면(삭제된 사용자 포함) 크기별 썸네일 삭제
+    // 같은 사진을 올리는 요청이 끝날 때까지 기다린 뒤 커밋된 참조를 확인
+    pub async fn delete_if_unused(&self, pool: &PgPool, hash: &str) -> anyhow::Result<()> {
+        let largest = AVATAR_SIZES[AVATAR_SIZES.len() - 1];
+        let mut tx = pool.begin().await?;
+        lock_blob(&mut tx, &Self::key(hash, largest)).await?;
+        let in_use = sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM users WHERE avatar_hash = $1)")
+            .bind(hash)
+            .fetch_one(&mut *tx)
+            .await?;
+        if !in_use {
+            self.delete(hash).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    // 크기별 썸네일 삭제
+    async fn delete(&self, hash: &str) -> anyhow::Result<()> {
+        for size in AVATAR_SIZES {
+            self.store.delete(&Self::key(hash, size)).await?;
+        }
+
+        tracing::info!("프로필 사진 삭제 완료: {}", hash);
+        Ok(())
+    }
+}
+
+// 업로드한 이미지를 가운데 기준 정사각형으로 잘라 크기별 PNG로 변환 (작은 크기부터)
+// 형식은 Content-Type이 아니라 파일 내용으로 판별
+pub fn render_thumbnails(data: &[u8]) -> Result<Vec<(u32, Vec<u8>)>, AvatarError> {
+    let format = image::guess_format(data).map_err(|_| AvatarError::UnsupportedFormat)?;
+    if !matches!(
+        format,
+        ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::Gif | ImageFormat::WebP
+    ) {
+        return Err(AvatarError::UnsupportedFormat);
+    }
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+
+    let mut reader = ImageReader::with_format(Cursor::new(data), format);
+    reader.limits(limits);
+    let image = reader.decode().map_err(|err| match err {
+        image::ImageError::Limits(_) => AvatarError::TooLarge,
+        _ => AvatarError::InvalidImage,
+    })?;
+
+    AVATAR_SIZES
+        .iter()
+        .map(|&size| {
+            let thumbnail = DynamicImage::ImageRgba8(
+                image.resize_to_fill(size, size, FilterType::Lanczos3).to_rgba8(),
+            );
+            let mut png = Vec::new();
+            thumbnail
+                .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+                .map_err(anyhow::Error::from)?;
+            Ok((size, png))
+        })
+        .collect()
+}
@@ -0,0 +1,669 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde_json::json;
+use sqlx::{FromRow, PgExecutor, PgPool, Postgres, QueryBuilder};
+use std::time::Duration;
+use uuid::Uuid;
+
+use super::AuditService;
+use crate::{
+    models::{
+        Booking, BookingListQuery, BookingStatus, BusySlot, ClientInfo, CreateBookingRequest, CreateResourceRequest,
+        NewAuditEvent, Resource, ResourceAvailability, ResourceAvailabilityQuery, ResourceKind, ResourceListQuery,
+        UpdateBookingRequest, UpdateResourceRequest,
+    },
+    rrule::{RRuleError, RecurrenceRule},
+};
+
+// 예약 시작 몇 분 전부터 체크인할 수 있는지
+pub const CHECK_IN_OPENS_MINUTES: i64 = 10;
+
+const RESOURCE_COLUMNS: &str = r#"
+    id, name, kind, capacity, location, description, requires_check_in, is_active, created_at, updated_at
+"#;
+
+// 예약 조회 컬럼 (자원 이름, 예약자 이름 포함)
+const BOOKING_COLUMNS: &str = r#"
+    b.id, b.resource_id, r.name AS resource_name, b.user_id, u.name AS user_name, b.title,
+    b.starts_at, b.ends_at, b.series_id, b.recurrence, b.status, b.checked_in_at, b.created_at, b.updated_at
+"#;
+
+const BOOKING_FROM: &str = r#"
+    FROM bookings b
+    JOIN resources r ON r.id = b.resource_id
+    JOIN users u ON u.id = b.user_id
+"#;
+
+// 예약 처리 오류
+#[derive(Debug, thiserror::Error)]
+pub enum BookingError {
+    #[error("예약을 찾을 수 없습니다")]
+    NotFound,
+
+    #[error("예약할 수 없는 자원입니다")]
+    InvalidResource,
+
+    #[error("{0}")]
+    InvalidRange(&'static str),
+
+    #[error(transparent)]
+    InvalidRecurrence(#[from] RRuleError),
+
+    #[error("기존 예약과 시간이 겹칩니다 ({0} 회차)")]
+    Conflict(DateTime<Utc>),
+
+    #[error("'{}' 상태의 예약에는 할 수 없는 작업입니다", .0.as_str())]
+    InvalidTransition(BookingStatus),
+
+    #[error("이미 시작된 예약은 변경할 수 없습니다")]
+    AlreadyStarted,
+
+    #[error("체크인은 {0}부터 가능합니다")]
+    CheckInNotOpen(DateTime<Utc>),
+
+    #[error("체크인 가능 시간이 지났습니다")]
+    CheckInClosed,
+
+    #[error("본인의 예약만 변경할 수 있습니다")]
+    NotOwner,
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+// 체크인 가능 시간 확인
+// 시작 CHECK_IN_OPENS_MINUTES분 전부터 시작 후 유예 시간까지 (예약 종료 시각을 넘지 않음)
+pub fn check_in_window(
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    grace_minutes: i64,
+) -> Result<(), BookingError> {
+    let opens_at = starts_at - ChronoDuration::minutes(CHECK_IN_OPENS_MINUTES);
+    let closes_at = (starts_at + ChronoDuration::minutes(grace_minutes)).min(ends_at);
+
+    if now < opens_at {
+        return Err(BookingError::CheckInNotOpen(opens_at));
+    }
+    if now > closes_at {
+        return Err(BookingError::CheckInClosed);
+    }
+    Ok(())
+}
+
+// 변경 전 잠근 예약
+#[derive(FromRow)]
+struct LockedBooking {
+    resource_id: i32,
+    user_id: i32,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    series_id: Option<Uuid>,
+    status: BookingStatus,
+}
+
+// 자동 해제된 예약
+#[derive(Debug, FromRow)]
+pub struct ReleasedBooking {
+    pub id: i32,
+    pub resource_id: i32,
+    pub user_id: i32,
+    pub starts_at: DateTime<Utc>,
+}
+
+pub struct BookingService {
+    pool: PgPool,
+}
+
+impl BookingService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 예약 자원 목록 (종류, 이름 순)
+    pub async fn find_resources(&self, query: &ResourceListQuery) -> Result<Vec<Resource>> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT");
+        builder.push(RESOURCE_COLUMNS).push(" FROM resources WHERE TRUE");
+        push_resource_filters(&mut builder, query.kind, query.min_capacity, query.include_inactive);
+        builder.push(" ORDER BY kind, name, id");
+
+        Ok(builder.build_query_as::<Resource>().fetch_all(&self.pool).await?)
+    }
+
+    // 예약 자원 조회
+    pub async fn find_resource(&self, id: i32) -> Result<Option<Resource>> {
+        let resource = sqlx::query_as::<_, Resource>(&format!("SELECT {} FROM resources WHERE id = $1", RESOURCE_COLUMNS))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(resource)
+    }
+
+    // 예약 자원 생성 (이름 중복은 resources_name_key 제약으로 409)
+    pub async fn create_resource(&self, request: &CreateResourceRequest) -> Result<Resource> {
+        let resource = sqlx::query_as::<_, Resource>(&format!(
+            r#"
+            INSERT INTO resources (name, kind, capacity, location, description, requires_check_in)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING {}
+            "#,
+            RESOURCE_COLUMNS
+        ))
+        .bind(&request.name)
+        .bind(request.kind)
+        .bind(request.capacity)
+        .bind(&request.location)
+        .bind(&request.description)
+        .bind(request.requires_check_in)
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("예약 자원 생성 완료: {} ({})", resource.name, resource.id);
+        Ok(resource)
+    }
+
+    // 예약 자원 수정 (생략한 항목은 유지)
+    pub async fn update_resource(&self, id: i32, request: &UpdateResourceRequest) -> Result<Option<Resource>> {
+        let resource = sqlx::query_as::<_, Resource>(&format!(
+            r#"
+            UPDATE resources
+            SET name = COALESCE($1, name),
+                kind = COALESCE($2, kind),
+                capacity = CASE WHEN $3 THEN $4 ELSE capacity END,
+                location = CASE WHEN $5 THEN $6 ELSE location END,
+                description = CASE WHEN $7 THEN $8 ELSE description END,
+                requires_check_in = COALESCE($9, requires_check_in),
+                is_active = COALESCE($10, is_active),
+                updated_at = NOW()
+            WHERE id = $11
+            RETURNING {}
+            "#,
+            RESOURCE_COLUMNS
+        ))
+        .bind(&request.name)
+        .bind(request.kind)
+        .bind(request.capacity.is_some())
+        .bind(request.capacity.flatten())
+        .bind(request.location.is_some())
+        .bind(request.location.clone().flatten())
+        .bind(request.description.is_some())
+        .bind(request.description.clone().flatten())
+        .bind(request.requires_check_in)
+        .bind(request.is_active)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if resource.is_some() {
+            tracing::info!("예약 자원 수정 완료: ID {}", id);
+        }
+        Ok(resource)
+    }
+
+    // 아직 끝나지 않은 확정/체크인 예약이 있는지 (있으면 자원 삭제 불가)
+    pub async fn has_upcoming_bookings(&self, resource_id: i32) -> Result<bool> {
+        let exists = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM bookings
+                WHERE resource_id = $1 AND status IN ('confirmed', 'checked_in') AND ends_at > NOW()
+            )
+            "#,
+        )
+        .bind(resource_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+
+    // 예약 자원 삭제 (지난 예약도 함께 삭제)
+    pub async fn delete_resource(&self, id: i32) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM resources WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            tracing::info!("예약 자원 삭제 완료: ID {}", id);
+        }
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 기간 동안의 자원별 예약 가능 여부 (사용 중인 자원만, 종류/이름 순)
+    pub async fn availability(&self, query: &ResourceAvailabilityQuery) -> Result<Vec<ResourceAvailability>> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT");
+        builder.push(RESOURCE_COLUMNS).push(" FROM resources WHERE TRUE");
+        push_resource_filters(&mut builder, query.kind, query.min_capacity, false);
+        builder.push(" ORDER BY kind, name, id");
+        let resources = builder.build_query_as::<Resource>().fetch_all(&self.pool).await?;
+
+        let resource_ids: Vec<i32> = resources.iter().map(|resource| resource.id).collect();
+        let busy = sqlx::query_as::<_, BusySlot>(
+            r#"
+            SELECT resource_id, id AS booking_id, starts_at, ends_at
+            FROM bookings
+            WHERE resource_id = ANY($1)
+              AND status IN ('confirmed', 'checked_in')
+              AND tstzrange(starts_at, ends_at) && tstzrange($2, $3)
+            ORDER BY starts_at, id
+            "#,
+        )
+        .bind(&resource_ids)
+        .bind(query.from)
+        .bind(query.to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(resources
+            .into_iter()
+            .map(|resource| {
+                let busy: Vec<BusySlot> = busy
+                    .iter()
+                    .filter(|slot| slot.resource_id == resource.id)
+                    .cloned()
+                    .collect();
+                ResourceAvailability {
+                    resource,
+                    available: busy.is_empty(),
+                    busy,
+                }
+            })
+            .filter(|availability| availability.available || !query.available_only)
+            .collect())
+    }
+
+    // 예약 (반복 규칙이 있으면 모든 회차를 한 번에 예약하고 하나라도 겹치면 전체 거부)
+    // 겹침 확인 후 저장 사이에 다른 예약이 끼어들어도 bookings_no_overlap 제약으로 409
+    pub async fn create(&self, user_id: i32, request: &CreateBookingRequest) -> Result<(Option<Uuid>, Vec<Booking>), BookingError> {
+        if request.ends_at <= request.starts_at {
+            return Err(BookingError::InvalidRange("종료 시각은 시작 시각 이후여야 합니다"));
+        }
+        let duration = request.ends_at - request.starts_at;
+
+        let (series_id, starts) = match &request.recurrence {
+            Some(recurrence) => {
+                let rule = RecurrenceRule::parse(recurrence)?;
+                (Some(Uuid::new_v4()), rule.occurrences(request.starts_at)?)
+            }
+            None => (None, vec![request.starts_at]),
+        };
+        let starts: Vec<DateTime<Utc>> = starts.iter().map(|start| start.with_timezone(&Utc)).collect();
+        let ends: Vec<DateTime<Utc>> = starts.iter().map(|start| *start + duration).collect();
+        if starts[0] < Utc::now() {
+            return Err(BookingError::InvalidRange("이미 지난 시간은 예약할 수 없습니다"));
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        // 예약하는 동안 자원이 사용 중지되지 않도록 잠금
+        sqlx::query_scalar::<_, i32>("SELECT id FROM resources WHERE id = $1 AND is_active FOR SHARE")
+            .bind(request.resource_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(BookingError::InvalidResource)?;
+
+        if let Some(conflict) = first_conflict(&mut *tx, request.resource_id, &starts, &ends, None).await? {
+            return Err(BookingError::Conflict(conflict));
+        }
+
+        let ids = sqlx::query_scalar::<_, i32>(
+            r#"
+            INSERT INTO bookings (resource_id, user_id, title, starts_at, ends_at, series_id, recurrence)
+            SELECT $1, $2, $3, o.starts_at, o.ends_at, $6, $7
+            FROM unnest($4::TIMESTAMPTZ[], $5::TIMESTAMPTZ[]) AS o(starts_at, ends_at)
+            RETURNING id
+            "#,
+        )
+        .bind(request.resource_id)
+        .bind(user_id)
+        .bind(&request.title)
+        .bind(&starts)
+        .bind(&ends)
+        .bind(series_id)
+        .bind(&request.recurrence)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("예약 완료: 사용자 ID {}, 자원 ID {} ({}회)", user_id, request.resource_id, ids.len());
+        let mut query = QueryBuilder::<Postgres>::new("SELECT");
+        query.push(BOOKING_COLUMNS).push(BOOKING_FROM);
+        query.push(" WHERE b.id = ANY(").push_bind(ids).push(") ORDER BY b.starts_at, b.id");
+        let bookings = query.build_query_as::<Booking>().fetch_all(&self.pool).await?;
+
+        Ok((series_id, bookings))
+    }
+
+    // 예약 조회
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<Booking>> {
+        Ok(fetch_booking(&self.pool, id).await?)
+    }
+
+    // 예약 목록 (시작 시각 순)
+    pub async fn find_page(&self, query: &BookingListQuery) -> Result<(Vec<Booking>, i64)> {
+        let push_filters = |builder: &mut QueryBuilder<Postgres>| {
+            builder.push(" WHERE TRUE");
+            if let Some(resource_id) = query.resource_id {
+                builder.push(" AND b.resource_id = ").push_bind(resource_id);
+            }
+            if let Some(user_id) = query.user_id {
+                builder.push(" AND b.user_id = ").push_bind(user_id);
+            }
+            if let Some(status) = query.status {
+                builder.push(" AND b.status = ").push_bind(status);
+            }
+            if let Some(from) = query.from {
+                builder.push(" AND b.ends_at > ").push_bind(from);
+            }
+            if let Some(to) = query.to {
+                builder.push(" AND b.starts_at < ").push_bind(to);
+            }
+        };
+
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*)");
+        count_query.push(BOOKING_FROM);
+        push_filters(&mut count_query);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut list_query = QueryBuilder::<Postgres>::new("SELECT");
+        list_query.push(BOOKING_COLUMNS).push(BOOKING_FROM);
+        push_filters(&mut list_query);
+        list_query.push(" ORDER BY b.starts_at, b.id");
+        list_query.push(" LIMIT ").push_bind(query.per_page);
+        list_query.push(" OFFSET ").push_bind((query.page - 1) * query.per_page);
+
+        let bookings = list_query
+            .build_query_as::<Booking>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok((bookings, total))
+    }
+
+    // 예약 한 회차 변경 (예약자나 resources.manage 권한 보유자, 시작 전의 확정 예약만)
+    pub async fn update(
+        &self,
+        id: i32,
+        actor_id: i32,
+        is_manager: bool,
+        request: &UpdateBookingRequest,
+    ) -> Result<Booking, BookingError> {
+        let mut tx = self.pool.begin().await?;
+        let locked = lock_booking(&mut *tx, id).await?.ok_or(BookingError::NotFound)?;
+        if locked.user_id != actor_id && !is_manager {
+            return Err(BookingError::NotOwner);
+        }
+        if locked.status != BookingStatus::Confirmed {
+            return Err(BookingError::InvalidTransition(locked.status));
+        }
+        if locked.starts_at <= Utc::now() {
+            return Err(BookingError::AlreadyStarted);
+        }
+
+        let resource_id = request.resource_id.unwrap_or(locked.resource_id);
+        let starts_at = request.starts_at.unwrap_or(locked.starts_at);
+        let ends_at = request.ends_at.unwrap_or(locked.ends_at);
+        if ends_at <= starts_at {
+            return Err(BookingError::InvalidRange("종료 시각은 시작 시각 이후여야 합니다"));
+        }
+        if starts_at < Utc::now() {
+            return Err(BookingError::InvalidRange("이미 지난 시간은 예약할 수 없습니다"));
+        }
+        if resource_id != locked.resource_id {
+            sqlx::query_scalar::<_, i32>("SELECT id FROM resources WHERE id = $1 AND is_active FOR SHARE")
+                .bind(resource_id)
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or(BookingError::InvalidResource)?;
+        }
+        if let Some(conflict) = first_conflict(&mut *tx, resource_id, &[starts_at], &[ends_at], Some(id)).await? {
+            return Err(BookingError::Conflict(conflict));
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE bookings
+            SET resource_id = $1, title = COALESCE($2, title), starts_at = $3, ends_at = $4, updated_at = NOW()
+            WHERE id = $5
+            "#,
+        )
+        .bind(resource_id)
+        .bind(&request.title)
+        .bind(starts_at)
+        .bind(ends_at)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("예약 변경 완료: 예약 ID {}", id);
+        self.find_booking(id).await
+    }
+
+    // 예약 취소 (예약자나 resources.manage 권한 보유자, 확정 예약만)
+    // series면 같은 반복 예약에서 이 회차 이후의 확정 예약도 함께 취소하고 취소한 예약 ID 반환
+    pub async fn cancel(&self, id: i32, actor_id: i32, is_manager: bool, series: bool) -> Result<Vec<i32>, BookingError> {
+        let mut tx = self.pool.begin().await?;
+        let locked = lock_booking(&mut *tx, id).await?.ok_or(BookingError::NotFound)?;
+        if locked.user_id != actor_id && !is_manager {
+            return Err(BookingError::NotOwner);
+        }
+        if locked.status != BookingStatus::Confirmed {
+            return Err(BookingError::InvalidTransition(locked.status));
+        }
+
+        let series_id = if series { locked.series_id } else { None };
+        let ids = sqlx::query_scalar::<_, i32>(
+            r#"
+            UPDATE bookings
+            SET status = 'cancelled', updated_at = NOW()
+            WHERE id = $1
+               OR ($2::UUID IS NOT NULL AND series_id = $2 AND starts_at >= $3 AND status = 'confirmed')
+            RETURNING id
+            "#,
+        )
+        .bind(id)
+        .bind(series_id)
+        .bind(locked.starts_at)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("예약 취소 완료: 예약 ID {} ({}건)", id, ids.len());
+        Ok(ids)
+    }
+
+    // 체크인 (예약자만, 시작 전후 체크인 가능 시간 안에서)
+    pub async fn check_in(&self, id: i32, actor_id: i32, grace_minutes: i64) -> Result<Booking, BookingError> {
+        let mut tx = self.pool.begin().await?;
+        let locked = lock_booking(&mut *tx, id).await?.ok_or(BookingError::NotFound)?;
+        if locked.user_id != actor_id {
+            return Err(BookingError::NotOwner);
+        }
+        if locked.status != BookingStatus::Confirmed {
+            return Err(BookingError::InvalidTransition(locked.status));
+        }
+        check_in_window(locked.starts_at, locked.ends_at, Utc::now(), grace_minutes)?;
+
+        sqlx::query("UPDATE bookings SET status = 'checked_in', checked_in_at = NOW(), updated_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("체크인 완료: 예약 ID {}", id);
+        self.find_booking(id).await
+    }
+
+    // 체크인이 필요한 자원에서 시작 후 유예 시간이 지나도록 체크인하지 않은 예약 해제
+    pub async fn release_no_shows(&self, grace_minutes: i64) -> Result<Vec<ReleasedBooking>> {
+        let released = sqlx::query_as::<_, ReleasedBooking>(
+            r#"
+            UPDATE bookings b
+            SET status = 'released', updated_at = NOW()
+            FROM resources r
+            WHERE r.id = b.resource_id
+              AND r.requires_check_in
+              AND b.status = 'confirmed'
+              AND b.starts_at + make_interval(mins => $1) < NOW()
+            RETURNING b.id, b.resource_id, b.user_id, b.starts_at
+            "#,
+        )
+        .bind(grace_minutes as i32)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(released)
+    }
+
+    // 방금 변경한 예약 다시 조회
+    async fn find_booking(&self, id: i32) -> Result<Booking, BookingError> {
+        fetch_booking(&self.pool, id).await?.ok_or(BookingError::NotFound)
+    }
+}
+
+// 체크인하지 않은 예약을 주기적으로 해제하는 백그라운드 작업
+// 해제된 시간대는 다른 사용자가 바로 예약할 수 있음
+pub fn spawn_release_task(pool: PgPool, grace_minutes: i64, interval: Duration) {
+    tokio::spawn(async move {
+        let booking_service = BookingService::new(pool.clone());
+        let audit_service = AuditService::new(pool);
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+
+            let released = match booking_service.release_no_shows(grace_minutes).await {
+                Ok(released) => released,
+                Err(err) => {
+                    tracing::error!("체크인하지 않은 예약 해제 실패: {}", err);
+                    continue;
+                }
+            };
+
+            for booking in released {
+                tracing::info!("체크인하지 않은 예약 해제: 예약 ID {}", booking.id);
+                audit_service
+                    .record(
+                        NewAuditEvent::new("booking.release", &ClientInfo::default())
+                            .target("booking", booking.id)
+                            .changes(json!({
+                                "resource_id": booking.resource_id,
+                                "user_id": booking.user_id,
+                                "starts_at": booking.starts_at,
+                                "grace_minutes": grace_minutes,
+                            })),
+                    )
+                    .await;
+            }
+        }
+    });
+}
+
+// 자원 목록 필터 (종류, 최소 수용 인원, 사용 여부)
+fn push_resource_filters(
+    builder: &mut QueryBuilder<Postgres>,
+    kind: Option<ResourceKind>,
+    min_capacity: Option<i32>,
+    include_inactive: bool,
+) {
+    if !include_inactive {
+        builder.push(" AND is_active");
+    }
+    if let Some(kind) = kind {
+        builder.push(" AND kind = ").push_bind(kind);
+    }
+    if let Some(min_capacity) = min_capacity {
+        builder.push(" AND capacity >= ").push_bind(min_capacity);
+    }
+}
+
+// 회차 중 같은 자원의 확정/체크인 예약과 겹치는 첫 회차의 시작 시각 (exclude_id는 변경 중인 예약)
+async fn first_conflict<'e>(
+    executor: impl PgExecutor<'e>,
+    resource_id: i32,
+    starts: &[DateTime<Utc>],
+    ends: &[DateTime<Utc>],
+    exclude_id: Option<i32>,
+) -> sqlx::Result<Option<DateTime<Utc>>> {
+    sqlx::query_scalar::<_, DateTime<Utc>>(
+        r#"
+        SELECT o.starts_at
+        FROM unnest($2::TIMESTAMPTZ[], $3::TIMESTAMPTZ[]) AS o(starts_at, ends_at)
+        WHERE EXISTS (
+            SELECT 1 FROM bookings b
+            WHERE b.resource_id = $1
+              AND b.status IN ('confirmed', 'checked_in')
+              AND tstzrange(b.starts_at, b.ends_at) && tstzrange(o.starts_at, o.ends_at)
+              AND ($4::INTEGER IS NULL OR b.id <> $4)
+        )
+        ORDER BY o.starts_at
+        LIMIT 1
+        "#,
+    )
+    .bind(resource_id)
+    .bind(starts)
+    .bind(ends)
+    .bind(exclude_id)
+    .fetch_optional(executor)
+    .await
+}
+
+async fn fetch_booking<'e>(executor: impl PgExecutor<'e>, id: i32) -> sqlx::Result<Option<Booking>> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT");
+    query.push(BOOKING_COLUMNS).push(BOOKING_FROM);
+    query.push(" WHERE b.id = ").push_bind(id);
+
+    query.build_query_as::<Booking>().fetch_optional(executor).await
+}
+
+// 상태를 바꾸기 전에 예약을 잠금 (동시에 체크인/취소해도 한 번만 전이)
+async fn lock_booking<'e>(executor: impl PgExecutor<'e>, id: i32) -> sqlx::Result<Option<LockedBooking>> {
+    sqlx::query_as::<_, LockedBooking>(
+        "SELECT resource_id, user_id, starts_at, ends_at, series_id, status FROM bookings WHERE id = $1 FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn check_in_opens_before_start_and_closes_after_grace() {
+        let starts_at = at("2024-07-01T09:00:00+09:00");
+        let ends_at = at("2024-07-01T10:00:00+09:00");
+
+        assert!(check_in_window(starts_at, ends_at, at("2024-07-01T08:50:00+09:00"), 15).is_ok());
+        assert!(check_in_window(starts_at, ends_at, at("2024-07-01T09:15:00+09:00"), 15).is_ok());
+        assert!(matches!(
+            check_in_window(starts_at, ends_at, at("2024-07-01T08:49:59+09:00"), 15),
+            Err(BookingError::CheckInNotOpen(opens_at)) if opens_at == at("2024-07-01T08:50:00+09:00")
+        ));
+        assert!(matches!(
+            check_in_window(starts_at, ends_at, at("2024-07-01T09:15:01+09:00"), 15),
+            Err(BookingError::CheckInClosed)
+        ));
+
+        // 유예 시간이 예약 시간보다 길어도 종료 후에는 체크인할 수 없음
+        assert!(matches!(
+            check_in_window(starts_at, ends_at, at("2024-07-01T10:00:01+09:00"), 120),
+            Err(BookingError::CheckInClosed)
+        ));
+    }
+}
@@ -0,0 +1,457 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+use super::{tokens, DepartmentService, LeaveService};
+use crate::{
+    ical::{self, EventStatus, EventTime},
+    models::{
+        CalendarEvent, CalendarEventKind, CalendarEventQuery, CalendarFeed, CalendarImportResponse,
+        CreateCalendarEventRequest, HalfDay, LeaveCalendarQuery, LeaveStatus, SkippedCalendarEvent,
+        UpdateCalendarEventRequest,
+    },
+};
+
+// 구독 피드에 넣는 기간 (오늘 기준 과거/미래 일수)
+const FEED_PAST_DAYS: i64 = 90;
+const FEED_FUTURE_DAYS: i64 = 365;
+
+// 피드 일정 UID의 도메인 부분
+const UID_DOMAIN: &str = "sample-intranet";
+
+// 가져온 일정의 제목/설명/장소 최대 길이 (calendar_events 컬럼 길이)
+const MAX_TITLE_CHARS: usize = 200;
+const MAX_DESCRIPTION_CHARS: usize = 2000;
+const MAX_LOCATION_CHARS: usize = 200;
+const MAX_SOURCE_UID_CHARS: usize = 255;
+
+const EVENT_COLUMNS: &str = r#"
+    id, kind, title, description, location, starts_at, ends_at, all_day, source_uid, created_by, created_at, updated_at
+"#;
+
+// 피드에 넣을 본인 휴가 신청
+#[derive(FromRow)]
+struct FeedLeave {
+    id: i32,
+    leave_type_name: String,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    half_day: Option<HalfDay>,
+    status: LeaveStatus,
+}
+
+// 피드에 넣을 본인 예약
+#[derive(FromRow)]
+struct FeedBooking {
+    id: i32,
+    title: String,
+    resource_name: String,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+pub struct CalendarService {
+    pool: PgPool,
+}
+
+impl CalendarService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 기간과 겹치는 회사 일정 (시작 시각 순)
+    pub async fn find_events(&self, query: &CalendarEventQuery) -> Result<Vec<CalendarEvent>> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT");
+        builder
+            .push(EVENT_COLUMNS)
+            .push(" FROM calendar_events WHERE starts_at < ")
+            .push_bind(query.to)
+            .push(" AND ends_at > ")
+            .push_bind(query.from);
+        if let Some(kind) = query.kind {
+            builder.push(" AND kind = ").push_bind(kind);
+        }
+        builder.push(" ORDER BY starts_at, id");
+
+        Ok(builder.build_query_as::<CalendarEvent>().fetch_all(&self.pool).await?)
+    }
+
+    // 회사 일정 조회
+    pub async fn find_event(&self, id: i32) -> Result<Option<CalendarEvent>> {
+        let event = sqlx::query_as::<_, CalendarEvent>(&format!(
+            "SELECT {} FROM calendar_events WHERE id = $1",
+            EVENT_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(event)
+    }
+
+    // 회사 일정 등록
+    pub async fn create_event(&self, created_by: i32, request: &CreateCalendarEventRequest) -> Result<CalendarEvent> {
+        let event = sqlx::query_as::<_, CalendarEvent>(&format!(
+            r#"
+            INSERT INTO calendar_events (kind, title, description, location, starts_at, ends_at, all_day, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {}
+            "#,
+            EVENT_COLUMNS
+        ))
+        .bind(request.kind)
+        .bind(&request.title)
+        .bind(&request.description)
+        .bind(&request.location)
+        .bind(request.starts_at)
+        .bind(request.ends_at)
+        .bind(request.all_day)
+        .bind(created_by)
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("회사 일정 등록 완료: ID {}", event.id);
+        Ok(event)
+    }
+
+    // 회사 일정 수정 (시각 검증은 핸들러에서 기존 값과 합쳐 확인)
+    pub async fn update_event(&self, id: i32, request: &UpdateCalendarEventRequest) -> Result<Option<CalendarEvent>> {
+        let event = sqlx::query_as::<_, CalendarEvent>(&format!(
+            r#"
+            UPDATE calendar_events
+            SET kind = COALESCE($1, kind),
+                title = COALESCE($2, title),
+                description = CASE WHEN $3 THEN $4 ELSE description END,
+                location = CASE WHEN $5 THEN $6 ELSE location END,
+                starts_at = COALESCE($7, starts_at),
+                ends_at = COALESCE($8, ends_at),
+                all_day = COALESCE($9, all_day),
+                updated_at = NOW()
+            WHERE id = $10
+            RETURNING {}
+            "#,
+            EVENT_COLUMNS
+        ))
+        .bind(request.kind)
+        .bind(&request.title)
+        .bind(request.description.is_some())
+        .bind(request.description.clone().flatten())
+        .bind(request.location.is_some())
+        .bind(request.location.clone().flatten())
+        .bind(request.starts_at)
+        .bind(request.ends_at)
+        .bind(request.all_day)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if event.is_some() {
+            tracing::info!("회사 일정 수정 완료: ID {}", id);
+        }
+        Ok(event)
+    }
+
+    // 회사 일정 삭제
+    pub async fn delete_event(&self, id: i32) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM calendar_events WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            tracing::info!("회사 일정 삭제 완료: ID {}", id);
+        }
+        Ok(result.rows_affected() > 0)
+    }
+
+    // .ics 파일에서 읽은 일정 저장 (UID가 같은 일정은 갱신, 한 트랜잭션)
+    // 컬럼 길이를 넘는 제목/설명/장소는 잘라서 저장
+    pub async fn import(
+        &self,
+        created_by: i32,
+        kind: CalendarEventKind,
+        parsed: ical::Parsed,
+    ) -> Result<CalendarImportResponse> {
+        let mut skipped: Vec<SkippedCalendarEvent> = parsed
+            .skipped
+            .into_iter()
+            .map(|skipped| SkippedCalendarEvent {
+                uid: skipped.uid,
+                summary: skipped.summary,
+                reason: skipped.reason.to_string(),
+            })
+            .collect();
+        let mut created = 0;
+        let mut updated = 0;
+
+        let mut tx = self.pool.begin().await?;
+        for event in parsed.events {
+            if event.uid.chars().count() > MAX_SOURCE_UID_CHARS {
+                skipped.push(SkippedCalendarEvent {
+                    uid: Some(event.uid),
+                    summary: Some(event.summary),
+                    reason: format!("UID는 {}자 이하여야 합니다", MAX_SOURCE_UID_CHARS),
+                });
+                continue;
+            }
+
+            let title = match truncate(&event.summary, MAX_TITLE_CHARS) {
+                title if title.is_empty() => "(제목 없음)".to_string(),
+                title => title,
+            };
+            let inserted = sqlx::query_scalar::<_, bool>(
+                r#"
+                INSERT INTO calendar_events
+                    (kind, title, description, location, starts_at, ends_at, all_day, source_uid, created_by)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                ON CONFLICT ON CONSTRAINT calendar_events_source_uid_key DO UPDATE
+                SET kind = EXCLUDED.kind,
+                    title = EXCLUDED.title,
+                    description = EXCLUDED.description,
+                    location = EXCLUDED.location,
+                    starts_at = EXCLUDED.starts_at,
+                    ends_at = EXCLUDED.ends_at,
+                    all_day = EXCLUDED.all_day,
+                    updated_at = NOW()
+                RETURNING xmax = 0
+                "#,
+            )
+            .bind(kind)
+            .bind(title)
+            .bind(event.description.as_deref().map(|text| truncate(text, MAX_DESCRIPTION_CHARS)))
+            .bind(event.location.as_deref().map(|text| truncate(text, MAX_LOCATION_CHARS)))
+            .bind(event.start.to_utc())
+            .bind(event.end.to_utc())
+            .bind(matches!(event.start, EventTime::Date(_)))
+            .bind(&event.uid)
+            .bind(created_by)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if inserted {
+                created += 1;
+            } else {
+                updated += 1;
+            }
+        }
+        tx.commit().await?;
+
+        tracing::info!("회사 일정 가져오기 완료: 추가 {}, 갱신 {}, 제외 {}", created, updated, skipped.len());
+        Ok(CalendarImportResponse {
+            created,
+            updated,
+            skipped,
+        })
+    }
+
+    // 달력 구독 정보
+    pub async fn find_feed(&self, user_id: i32) -> Result<Option<CalendarFeed>> {
+        let feed = sqlx::query_as::<_, CalendarFeed>(
+            "SELECT created_at, last_accessed_at FROM calendar_feeds WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(feed)
+    }
+
+    // 달력 구독 토큰 발급 (이미 있으면 새 토큰으로 교체해 이전 주소는 더 이상 동작하지 않음)
+    pub async fn issue_feed(&self, user_id: i32) -> Result<(String, CalendarFeed)> {
+        let token = tokens::generate_token();
+        let feed = sqlx::query_as::<_, CalendarFeed>(
+            r#"
+            INSERT INTO calendar_feeds (user_id, token_hash)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE
+            SET token_hash = EXCLUDED.token_hash, created_at = NOW(), last_accessed_at = NULL
+            RETURNING created_at, last_accessed_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(tokens::hash_token(&token))
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("달력 구독 토큰 발급: 사용자 ID {}", user_id);
+        Ok((token, feed))
+    }
+
+    // 달력 구독 해지
+    pub async fn revoke_feed(&self, user_id: i32) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM calendar_feeds WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 구독 토큰의 사용자 (사용 중인 계정만, 마지막 접근 시각 기록)
+    pub async fn find_feed_user(&self, token: &str) -> Result<Option<i32>> {
+        let user_id = sqlx::query_scalar::<_, i32>(
+            r#"
+            UPDATE calendar_feeds f
+            SET last_accessed_at = NOW()
+            FROM users u
+            WHERE f.token_hash = $1 AND u.id = f.user_id AND u.deleted_at IS NULL AND u.is_active
+            RETURNING f.user_id
+            "#,
+        )
+        .bind(tokens::hash_token(token))
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(user_id)
+    }
+
+    // 사용자 구독 피드의 일정 (회사 일정, 본인 휴가/예약, 소속 팀의 승인된 휴가)
+    pub async fn feed_events(&self, user_id: i32, now: DateTime<Utc>) -> Result<Vec<ical::Event>> {
+        let from = now - Duration::days(FEED_PAST_DAYS);
+        let to = now + Duration::days(FEED_FUTURE_DAYS);
+        let mut events = Vec::new();
+
+        let company = self.find_events(&CalendarEventQuery { from, to, kind: None }).await?;
+        events.extend(company.into_iter().map(|event| {
+            let (start, end) = if event.all_day {
+                (
+                    EventTime::Date(event.starts_at.date_naive()),
+                    EventTime::Date(event.ends_at.date_naive()),
+                )
+            } else {
+                (EventTime::DateTime(event.starts_at), EventTime::DateTime(event.ends_at))
+            };
+            ical::Event {
+                uid: feed_uid("event", event.id),
+                summary: event.title,
+                description: event.description,
+                location: event.location,
+                start,
+                end,
+                status: Some(EventStatus::Confirmed),
+                categories: Some(
+                    match event.kind {
+                        CalendarEventKind::Event => "회사 일정",
+                        CalendarEventKind::Holiday => "공휴일",
+                    }
+                    .to_string(),
+                ),
+                last_modified: Some(event.updated_at),
+            }
+        }));
+
+        // 본인 휴가 (승인 대기는 미정으로 표시)
+        let leaves = sqlx::query_as::<_, FeedLeave>(
+            r#"
+            SELECT r.id, lt.name AS leave_type_name, r.start_date, r.end_date, r.half_day, r.status
+            FROM leave_requests r
+            JOIN leave_types lt ON lt.id = r.leave_type_id
+            WHERE r.user_id = $1
+              AND r.status IN ('pending_manager', 'pending_hr', 'approved')
+              AND r.end_date >= $2 AND r.start_date <= $3
+            ORDER BY r.start_date, r.id
+            "#,
+        )
+        .bind(user_id)
+        .bind(from.date_naive())
+        .bind(to.date_naive())
+        .fetch_all(&self.pool)
+        .await?;
+        events.extend(leaves.into_iter().map(|leave| {
+            let approved = leave.status == LeaveStatus::Approved;
+            let mut summary = leave_summary(None, &leave.leave_type_name, leave.half_day);
+            if !approved {
+                summary.push_str(" (승인 대기)");
+            }
+            leave_event(leave.id, summary, leave.start_date, leave.end_date, approved)
+        }));
+
+        // 소속 팀(하위 부서 포함)의 승인된 휴가
+        if let Some(member) = DepartmentService::new(self.pool.clone()).find_member(user_id).await? {
+            let query = LeaveCalendarQuery {
+                from: from.date_naive(),
+                to: to.date_naive(),
+                department_id: Some(member.department_id),
+                include_pending: false,
+            };
+            let team = LeaveService::new(self.pool.clone())
+                .calendar(member.department_id, &query)
+                .await?;
+            events.extend(team.into_iter().filter(|entry| entry.user_id != user_id).map(|entry| {
+                let summary = leave_summary(Some(&entry.user_name), &entry.leave_type_name, entry.half_day);
+                leave_event(entry.leave_request_id, summary, entry.start_date, entry.end_date, true)
+            }));
+        }
+
+        // 본인 예약 (확정/체크인)
+        let bookings = sqlx::query_as::<_, FeedBooking>(
+            r#"
+            SELECT b.id, b.title, r.name AS resource_name, b.starts_at, b.ends_at, b.updated_at
+            FROM bookings b
+            JOIN resources r ON r.id = b.resource_id
+            WHERE b.user_id = $1
+              AND b.status IN ('confirmed', 'checked_in')
+              AND b.ends_at > $2 AND b.starts_at < $3
+            ORDER BY b.starts_at, b.id
+            "#,
+        )
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+        events.extend(bookings.into_iter().map(|booking| ical::Event {
+            uid: feed_uid("booking", booking.id),
+            summary: booking.title,
+            description: None,
+            location: Some(booking.resource_name),
+            start: EventTime::DateTime(booking.starts_at),
+            end: EventTime::DateTime(booking.ends_at),
+            status: Some(EventStatus::Confirmed),
+            categories: Some("예약".to_string()),
+            last_modified: Some(booking.updated_at),
+        }));
+
+        Ok(events)
+    }
+}
+
+// 피드 일정 UID (같은 일정은 피드를 다시 받아도 같은 UID)
+fn feed_uid(kind: &str, id: i32) -> String {
+    format!("{}-{}@{}", kind, id, UID_DOMAIN)
+}
+
+// 휴가 일정 제목 (팀원 휴가는 이름 포함, 반차는 오전/오후 표시)
+fn leave_summary(user_name: Option<&str>, leave_type_name: &str, half_day: Option<HalfDay>) -> String {
+    let mut summary = match user_name {
+        Some(name) => format!("{} {}", name, leave_type_name),
+        None => leave_type_name.to_string(),
+    };
+    match half_day {
+        Some(HalfDay::Am) => summary.push_str(" (오전 반차)"),
+        Some(HalfDay::Pm) => summary.push_str(" (오후 반차)"),
+        None => {}
+    }
+    summary
+}
+
+// 휴가는 하루 종일 일정 (종료일은 포함이므로 다음 날까지)
+fn leave_event(id: i32, summary: String, start_date: NaiveDate, end_date: NaiveDate, approved: bool) -> ical::Event {
+    ical::Event {
+        uid: feed_uid("leave", id),
+        summary,
+        description: None,
+        location: None,
+        start: EventTime::Date(start_date),
+        end: EventTime::Date(end_date + Duration::days(1)),
+        status: Some(if approved { EventStatus::Confirmed } else { EventStatus::Tentative }),
+        categories: Some("휴가".to_string()),
+        last_modified: None,
+    }
+}
+
+// 문자 단위로 자르기
+fn truncate(text: &str, max_chars: usize) -> String {
+    text.chars().take(max_chars).collect()
+}
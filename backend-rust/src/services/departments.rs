@@ -0,0 +1,359 @@
+use anyhow::Result;
+use sqlx::{FromRow, PgPool};
+use std::collections::HashMap;
+
+use crate::models::{
+    CreateDepartmentRequest, Department, DepartmentMember, DepartmentMemberResponse,
+    DepartmentResponse, OrgChartNode, OrgChartPerson, SetDepartmentMemberRequest,
+    UpdateDepartmentRequest,
+};
+
+// 조직도 재귀 조회 최대 깊이 (잘못된 데이터로 순환이 생겨도 무한 반복하지 않도록)
+pub(super) const MAX_DEPTH: i32 = 32;
+
+// 조직도 조회 결과 행 (부서와 부서장)
+#[derive(FromRow)]
+struct OrgChartRow {
+    id: i32,
+    name: String,
+    parent_id: Option<i32>,
+    head_user_id: Option<i32>,
+    head_name: Option<String>,
+    head_email: Option<String>,
+    head_title: Option<String>,
+    head_manager_id: Option<i32>,
+}
+
+#[derive(FromRow)]
+struct OrgChartMemberRow {
+    department_id: i32,
+    #[sqlx(flatten)]
+    person: OrgChartPerson,
+}
+
+pub struct DepartmentService {
+    pool: PgPool,
+}
+
+impl DepartmentService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 전체 부서 목록 (상위 부서, 정렬 순서, 이름 순)
+    // 소속 인원 수에는 삭제된 사용자를 포함하지 않음
+    pub async fn find_all(&self) -> Result<Vec<DepartmentResponse>> {
+        let departments = sqlx::query_as::<_, DepartmentResponse>(
+            r#"
+            SELECT d.id, d.name, d.parent_id, d.head_user_id, d.sort_order,
+                   COUNT(u.id) AS member_count, d.created_at, d.updated_at
+            FROM departments d
+            LEFT JOIN department_members m ON m.department_id = d.id
+            LEFT JOIN users u ON u.id = m.user_id AND u.deleted_at IS NULL
+            GROUP BY d.id
+            ORDER BY d.parent_id NULLS FIRST, d.sort_order, d.name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(departments)
+    }
+
+    // ID로 부서 조회
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<DepartmentResponse>> {
+        let department = sqlx::query_as::<_, DepartmentResponse>(
+            r#"
+            SELECT d.id, d.name, d.parent_id, d.head_user_id, d.sort_order,
+                   COUNT(u.id) AS member_count, d.created_at, d.updated_at
+            FROM departments d
+            LEFT JOIN department_members m ON m.department_id = d.id
+            LEFT JOIN users u ON u.id = m.user_id AND u.deleted_at IS NULL
+            WHERE d.id = $1
+            GROUP BY d.id
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(department)
+    }
+
+    // 부서 소속 사용자 목록 (이름 순)
+    pub async fn members(&self, department_id: i32) -> Result<Vec<DepartmentMemberResponse>> {
+        let members = sqlx::query_as::<_, DepartmentMemberResponse>(
+            r#"
+            SELECT m.user_id, m.department_id, u.name, u.email, m.title, m.manager_id
+            FROM department_members m
+            JOIN users u ON u.id = m.user_id
+            WHERE m.department_id = $1 AND u.deleted_at IS NULL
+            ORDER BY u.name, u.id
+            "#,
+        )
+        .bind(department_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(members)
+    }
+
+    // 부서 생성
+    // 같은 상위 부서 아래 이름 중복은 departments_parent_name_key 유니크 위반 (409 department_exists)
+    pub async fn create(&self, request: &CreateDepartmentRequest) -> Result<Department> {
+        let department = sqlx::query_as::<_, Department>(
+            r#"
+            INSERT INTO departments (name, parent_id, head_user_id, sort_order)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, name, parent_id, head_user_id, sort_order, created_at, updated_at
+            "#,
+        )
+        .bind(&request.name)
+        .bind(request.parent_id)
+        .bind(request.head_user_id)
+        .bind(request.sort_order)
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("부서 생성 완료: {}", department.name);
+        Ok(department)
+    }
+
+    // 부서 수정 (상위 부서가 자신의 하위 부서가 아닌지는 호출 전 is_descendant로 확인)
+    pub async fn update(&self, id: i32, request: &UpdateDepartmentRequest) -> Result<Option<Department>> {
+        let department = sqlx::query_as::<_, Department>(
+            r#"
+            UPDATE departments
+            SET name = COALESCE($1, name),
+                parent_id = CASE WHEN $2 THEN $3 ELSE parent_id END,
+                head_user_id = CASE WHEN $4 THEN $5 ELSE head_user_id END,
+                sort_order = COALESCE($6, sort_order),
+                updated_at = NOW()
+            WHERE id = $7
+            RETURNING id, name, parent_id, head_user_id, sort_order, created_at, updated_at
+            "#,
+        )
+        .bind(&request.name)
+        .bind(request.parent_id.is_some())
+        .bind(request.parent_id.flatten())
+        .bind(request.head_user_id.is_some())
+        .bind(request.head_user_id.flatten())
+        .bind(request.sort_order)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(ref department) = department {
+            tracing::info!("부서 수정 완료: {}", department.name);
+        }
+
+        Ok(department)
+    }
+
+    // candidate가 ancestor 자신이거나 그 하위 부서인지 확인 (상위 부서 변경 시 순환 방지)
+    pub async fn is_descendant(&self, ancestor_id: i32, candidate_id: i32) -> Result<bool> {
+        let found = sqlx::query_scalar::<_, bool>(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id, 0 AS depth FROM departments WHERE id = $1
+                UNION ALL
+                SELECT d.id, s.depth + 1
+                FROM departments d
+                JOIN subtree s ON d.parent_id = s.id
+                WHERE s.depth < $3
+            )
+            SELECT EXISTS (SELECT 1 FROM subtree WHERE id = $2)
+            "#,
+        )
+        .bind(ancestor_id)
+        .bind(candidate_id)
+        .bind(MAX_DEPTH)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(found)
+    }
+
+    // 하위 부서나 소속 사용자(삭제된 사용자 포함)가 있는지 확인
+    pub async fn has_dependents(&self, id: i32) -> Result<bool> {
+        let found = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (SELECT 1 FROM departments WHERE parent_id = $1)
+                OR EXISTS (SELECT 1 FROM department_members WHERE department_id = $1)
+            "#,
+        )
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(found)
+    }
+
+    // 부서 삭제 (하위 부서나 소속 사용자가 없는지는 호출 전 has_dependents로 확인)
+    pub async fn delete(&self, id: i32) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM departments WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        let deleted = result.rows_affected() > 0;
+
+        if deleted {
+            tracing::info!("부서 삭제 완료: ID {}", id);
+        }
+
+        Ok(deleted)
+    }
+
+    // 사용자 소속 조회
+    pub async fn find_member(&self, user_id: i32) -> Result<Option<DepartmentMember>> {
+        let member = sqlx::query_as::<_, DepartmentMember>(
+            "SELECT user_id, department_id, title, manager_id FROM department_members WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(member)
+    }
+
+    // 사용자 소속 설정 (이미 소속된 부서가 있으면 이동)
+    pub async fn set_member(&self, user_id: i32, request: &SetDepartmentMemberRequest) -> Result<DepartmentMember> {
+        let member = sqlx::query_as::<_, DepartmentMember>(
+            r#"
+            INSERT INTO department_members (user_id, department_id, title, manager_id)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id) DO UPDATE
+            SET department_id = EXCLUDED.department_id,
+                title = EXCLUDED.title,
+                manager_id = EXCLUDED.manager_id,
+                updated_at = NOW()
+            RETURNING user_id, department_id, title, manager_id
+            "#,
+        )
+        .bind(user_id)
+        .bind(request.department_id)
+        .bind(&request.title)
+        .bind(request.manager_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("사용자 소속 설정 완료: 사용자 ID {}, 부서 ID {}", user_id, member.department_id);
+        Ok(member)
+    }
+
+    // 사용자 소속 해제
+    pub async fn remove_member(&self, user_id: i32) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM department_members WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 조직도 조회 (재귀 CTE로 부서 트리를 내려가며 조회한 뒤 중첩 구조로 조립)
+    // root_id를 지정하면 해당 부서를 최상위로 하는 하위 트리, 없는 부서면 빈 목록
+    pub async fn org_chart(&self, root_id: Option<i32>) -> Result<Vec<OrgChartNode>> {
+        let rows = sqlx::query_as::<_, OrgChartRow>(
+            r#"
+            WITH RECURSIVE tree AS (
+                SELECT id, name, parent_id, head_user_id, sort_order, 0 AS depth
+                FROM departments
+                WHERE ($1::INTEGER IS NULL AND parent_id IS NULL) OR id = $1
+                UNION ALL
+                SELECT d.id, d.name, d.parent_id, d.head_user_id, d.sort_order, t.depth + 1
+                FROM departments d
+                JOIN tree t ON d.parent_id = t.id
+                WHERE t.depth < $2
+            )
+            SELECT t.id, t.name, t.parent_id, h.id AS head_user_id,
+                   h.name AS head_name, h.email AS head_email,
+                   hm.title AS head_title, hm.manager_id AS head_manager_id
+            FROM tree t
+            LEFT JOIN users h ON h.id = t.head_user_id AND h.deleted_at IS NULL
+            LEFT JOIN department_members hm ON hm.user_id = h.id
+            ORDER BY t.depth, t.sort_order, t.name, t.id
+            "#,
+        )
+        .bind(root_id)
+        .bind(MAX_DEPTH)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let department_ids: Vec<i32> = rows.iter().map(|row| row.id).collect();
+        let member_rows = sqlx::query_as::<_, OrgChartMemberRow>(
+            r#"
+            SELECT m.department_id, u.id AS user_id, u.name, u.email, m.title, m.manager_id
+            FROM department_members m
+            JOIN users u ON u.id = m.user_id
+            WHERE m.department_id = ANY($1) AND u.deleted_at IS NULL
+            ORDER BY u.name, u.id
+            "#,
+        )
+        .bind(&department_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut members: HashMap<i32, Vec<OrgChartPerson>> = HashMap::new();
+        for row in member_rows {
+            members.entry(row.department_id).or_default().push(row.person);
+        }
+
+        Ok(build_tree(rows, root_id, &mut members))
+    }
+}
+
+// 부서 행을 상위 부서 기준으로 묶어 중첩 트리로 조립 (행 순서가 형제 간 정렬 순서)
+fn build_tree(
+    rows: Vec<OrgChartRow>,
+    root_id: Option<i32>,
+    members: &mut HashMap<i32, Vec<OrgChartPerson>>,
+) -> Vec<OrgChartNode> {
+    let mut roots = Vec::new();
+    let mut children: HashMap<i32, Vec<OrgChartRow>> = HashMap::new();
+    for row in rows {
+        let is_root = match root_id {
+            Some(root_id) => row.id == root_id,
+            None => row.parent_id.is_none(),
+        };
+        match row.parent_id {
+            Some(parent_id) if !is_root => children.entry(parent_id).or_default().push(row),
+            _ => roots.push(row),
+        }
+    }
+
+    fn build(
+        row: OrgChartRow,
+        children: &mut HashMap<i32, Vec<OrgChartRow>>,
+        members: &mut HashMap<i32, Vec<OrgChartPerson>>,
+    ) -> OrgChartNode {
+        let head = match (row.head_user_id, row.head_name, row.head_email) {
+            (Some(user_id), Some(name), Some(email)) => Some(OrgChartPerson {
+                user_id,
+                name,
+                email,
+                title: row.head_title,
+                manager_id: row.head_manager_id,
+            }),
+            _ => None,
+        };
+        let child_rows = children.remove(&row.id).unwrap_or_default();
+
+        OrgChartNode {
+            id: row.id,
+            name: row.name,
+            head,
+            members: members.remove(&row.id).unwrap_or_default(),
+            children: child_rows
+                .into_iter()
+                .map(|child| build(child, children, members))
+                .collect(),
+        }
+    }
+
+    roots
+        .into_iter()
+        .map(|row| build(row, &mut children, members))
+        .collect()
+}
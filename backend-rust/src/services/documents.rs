@@ -0,0 +1,1099 @@
+use anyhow::Result;
+use sqlx::{FromRow, PgExecutor, PgPool};
+use std::sync::Arc;
+
+use crate::{
+    models::{
+        CreateDocumentFolderRequest, Document, DocumentAccess, DocumentFolder, DocumentFolderAclEntry,
+        DocumentFolderAclEntryRequest, DocumentQuota, DocumentVersion, UpdateDocumentFolderRequest,
+        UpdateDocumentRequest,
+    },
+    storage::{content_hash, lock_blob, BlobStore},
+};
+
+// 폴더 최대 깊이 (최상위 폴더가 0, FOLDER_ACCESS_CTE는 이 깊이까지만 내려가므로 더 깊은 폴더는 만들거나 옮길 수 없음)
+const MAX_FOLDER_DEPTH: i32 = 32;
+
+// 폴더별 접근 권한 계산 (최상위 폴더부터 내려가며 상위 폴더의 권한을 물려받음)
+// $1 사용자 ID, $2 documents.manage 권한 여부, $3 최대 깊이
+// level은 0 없음, 1 read, 2 write, 3 manage이고 parent_level은 상위 폴더의 level
+// 역할로 부여한 권한은 has_permission과 같이 2단계 인증이 필요한 역할을 인증 전에는 제외
+const FOLDER_ACCESS_CTE: &str = r#"
+    WITH RECURSIVE principal_roles AS (
+        SELECT r.id
+        FROM roles r
+        WHERE (r.name = (SELECT role FROM users WHERE id = $1)
+               OR r.id IN (SELECT role_id FROM user_roles WHERE user_id = $1))
+          AND (NOT r.require_mfa
+               OR EXISTS (SELECT 1 FROM user_mfa WHERE user_id = $1 AND confirmed_at IS NOT NULL))
+    ),
+    grants AS (
+        SELECT f.id, f.parent_id,
+               CASE WHEN $2 OR f.owner_id = $1 THEN 3
+                    ELSE COALESCE((
+                        SELECT MAX(CASE a.access WHEN 'read' THEN 1 WHEN 'write' THEN 2 ELSE 3 END)
+                        FROM document_folder_acl a
+                        WHERE a.folder_id = f.id
+                          AND (a.user_id = $1 OR a.role_id IN (SELECT id FROM principal_roles))
+                    ), 0)
+               END AS level
+        FROM document_folders f
+    ),
+    tree AS (
+        SELECT id, parent_id, level, 0 AS parent_level, 0 AS depth
+        FROM grants
+        WHERE parent_id IS NULL
+        UNION ALL
+        SELECT g.id, g.parent_id, GREATEST(g.level, t.level), t.level, t.depth + 1
+        FROM grants g
+        JOIN tree t ON g.parent_id = t.id
+        WHERE t.depth < $3
+    )
+"#;
+
+// 폴더 조회 컬럼 (FOLDER_ACCESS_CTE의 tree와 함께 사용)
+const FOLDER_COLUMNS: &str = r#"
+    f.id, f.parent_id, f.name, f.owner_id, u.name AS owner_name,
+    (CASE t.level WHEN 1 THEN 'read' WHEN 2 THEN 'write' ELSE 'manage' END)::VARCHAR AS access,
+    f.created_at, f.updated_at
+"#;
+
+const FOLDER_FROM: &str = r#"
+    FROM tree t
+    JOIN document_folders f ON f.id = t.id
+    LEFT JOIN users u ON u.id = f.owner_id
+"#;
+
+// 문서 조회 컬럼 (크기, 형식, 체크섬은 현재 버전)
+const DOCUMENT_COLUMNS: &str = r#"
+    d.id, d.folder_id, d.name, d.owner_id, u.name AS owner_name, d.current_version,
+    v.size_bytes, v.content_type, v.checksum_sha256, d.created_at, d.updated_at
+"#;
+
+const DOCUMENT_FROM: &str = r#"
+    FROM documents d
+    JOIN document_versions v ON v.document_id = d.id AND v.version = d.current_version
+    LEFT JOIN users u ON u.id = d.owner_id
+"#;
+
+const VERSION_COLUMNS: &str = r#"
+    v.version, v.size_bytes, v.content_type, v.checksum_sha256, v.uploaded_by,
+    u.name AS uploaded_by_name, v.comment, v.created_at
+"#;
+
+// 문서함 처리 오류
+// 읽기 권한도 없는 폴더와 문서는 존재 여부를 알 수 없도록 NotFound
+#[derive(Debug, thiserror::Error)]
+pub enum DocumentError {
+    #[error("폴더를 찾을 수 없습니다")]
+    FolderNotFound,
+
+    #[error("문서를 찾을 수 없습니다")]
+    DocumentNotFound,
+
+    #[error("문서 버전을 찾을 수 없습니다")]
+    VersionNotFound,
+
+    #[error("'{}' 이상의 폴더 접근 권한이 필요합니다", .0.as_str())]
+    AccessDenied(DocumentAccess),
+
+    #[error("상위 폴더로 지정할 수 없는 폴더입니다")]
+    InvalidParent,
+
+    #[error("최상위 폴더로 옮기려면 폴더를 만든 사용자이거나 documents.manage 권한이 필요합니다")]
+    RootNotAllowed,
+
+    #[error("옮길 수 없는 폴더입니다")]
+    InvalidFolder,
+
+    #[error("하위 폴더나 문서가 있는 폴더는 삭제할 수 없습니다")]
+    FolderNotEmpty,
+
+    #[error("{0}")]
+    InvalidAclEntry(String),
+
+    #[error("업로드한 파일의 SHA-256({0})이 checksum과 다릅니다")]
+    ChecksumMismatch(String),
+
+    #[error("문서 용량 할당량을 초과합니다 (사용 {used}바이트, 업로드 {size}바이트, 할당량 {quota}바이트)")]
+    QuotaExceeded { used: i64, size: i64, quota: i64 },
+
+    #[error(transparent)]
+    Storage(#[from] anyhow::Error),
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+// 업로드한 파일 (문서 생성과 새 버전 업로드에 사용)
+pub struct DocumentUpload {
+    pub data: Vec<u8>,
+    pub content_type: String,
+    pub checksum: Option<String>, // 클라이언트가 보낸 SHA-256 (16진수 소문자)
+    pub comment: Option<String>,
+}
+
+// 내려받을 바이트 범위 (끝 포함)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+// Range 헤더 해석 결과
+#[derive(Debug, PartialEq, Eq)]
+pub enum RangeRequest {
+    // 전체 내용 (Range가 없거나 해석할 수 없거나 여러 범위)
+    Full,
+    Partial(ByteRange),
+    // 파일 범위를 벗어남 (416)
+    Unsatisfiable,
+}
+
+// Range 헤더 해석 (bytes 단위의 단일 범위만 지원: "a-b", "a-", "-n")
+// RFC 9110에 따라 형식이 잘못된 헤더는 무시하고 전체 내용을 응답
+pub fn parse_range(header: &str, size: u64) -> RangeRequest {
+    let Some((unit, spec)) = header.trim().split_once('=') else {
+        return RangeRequest::Full;
+    };
+    if !unit.trim().eq_ignore_ascii_case("bytes") || spec.contains(',') {
+        return RangeRequest::Full;
+    }
+    let Some((first, last)) = spec.trim().split_once('-') else {
+        return RangeRequest::Full;
+    };
+    let number = |text: &str| -> Option<u64> {
+        let text = text.trim();
+        if text.is_empty() || !text.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        text.parse().ok()
+    };
+
+    match (first.trim().is_empty(), last.trim().is_empty()) {
+        // 마지막 n바이트
+        (true, false) => match number(last) {
+            Some(0) => RangeRequest::Unsatisfiable,
+            Some(_) if size == 0 => RangeRequest::Unsatisfiable,
+            Some(length) => RangeRequest::Partial(ByteRange {
+                start: size - length.min(size),
+                end: size - 1,
+            }),
+            None => RangeRequest::Full,
+        },
+        (false, _) => {
+            let Some(start) = number(first) else {
+                return RangeRequest::Full;
+            };
+            let end = if last.trim().is_empty() {
+                u64::MAX
+            } else {
+                match number(last) {
+                    Some(end) if end >= start => end,
+                    _ => return RangeRequest::Full,
+                }
+            };
+            if start >= size {
+                return RangeRequest::Unsatisfiable;
+            }
+            RangeRequest::Partial(ByteRange {
+                start,
+                end: end.min(size - 1),
+            })
+        }
+        (true, true) => RangeRequest::Full,
+    }
+}
+
+// Content-Disposition 헤더 값 (filename에는 ASCII 대체 이름, filename*에는 UTF-8 원래 이름)
+pub fn content_disposition(name: &str) -> String {
+    let fallback: String = name
+        .chars()
+        .map(|c| {
+            if (c.is_ascii_graphic() || c == ' ') && c != '"' && c != '\\' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let encoded: String = name
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'!' | b'#' | b'$' | b'&' | b'+' | b'-' | b'.' | b'^' | b'_'
+            | b'`' | b'|' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect();
+
+    format!("attachment; filename=\"{}\"; filename*=UTF-8''{}", fallback, encoded)
+}
+
+// 할당량 확인용 사용량
+#[derive(FromRow)]
+struct QuotaRow {
+    used_bytes: i64,
+    quota_bytes: Option<i64>,
+}
+
+// 새 버전을 올리기 전 잠근 문서
+#[derive(FromRow)]
+struct LockedDocument {
+    owner_id: Option<i32>,
+    current_version: i32,
+}
+
+pub struct DocumentService {
+    pool: PgPool,
+    store: Arc<dyn BlobStore>,
+    default_quota_bytes: i64,
+}
+
+impl DocumentService {
+    pub fn new(pool: PgPool, store: Arc<dyn BlobStore>, default_quota_bytes: i64) -> Self {
+        Self {
+            pool,
+            store,
+            default_quota_bytes,
+        }
+    }
+
+    // 저장소 키 (내용 기반이라 같은 파일은 문서/버전이 달라도 한 번만 저장)
+    pub fn key(checksum: &str) -> String {
+        format!("documents/{}/{}", &checksum[..2], checksum)
+    }
+
+    // 폴더 목록 (이름 순)
+    // parent_id를 지정하면 그 폴더의 하위 폴더 (상위 폴더의 권한을 물려받으므로 모두 접근 가능)
+    // 생략하면 접근할 수 있는 최상위 폴더와 상위 폴더에는 접근할 수 없는 공유 폴더
+    pub async fn find_folders(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        parent_id: Option<i32>,
+    ) -> Result<Vec<DocumentFolder>, DocumentError> {
+        if let Some(parent_id) = parent_id {
+            self.folder_with_access(actor_id, is_manager, parent_id, DocumentAccess::Read)
+                .await?;
+        }
+
+        let folders = sqlx::query_as::<_, DocumentFolder>(&format!(
+            r#"
+            {} SELECT {} {}
+            WHERE t.parent_id = $4
+               OR ($4::INTEGER IS NULL AND t.level > 0 AND t.parent_level = 0)
+            ORDER BY f.name, f.id
+            "#,
+            FOLDER_ACCESS_CTE, FOLDER_COLUMNS, FOLDER_FROM
+        ))
+        .bind(actor_id)
+        .bind(is_manager)
+        .bind(MAX_FOLDER_DEPTH)
+        .bind(parent_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(folders)
+    }
+
+    // 폴더 조회 (읽기 권한이 없으면 None)
+    pub async fn find_folder(&self, actor_id: i32, is_manager: bool, id: i32) -> Result<Option<DocumentFolder>> {
+        let folder = sqlx::query_as::<_, DocumentFolder>(&format!(
+            "{} SELECT {} {} WHERE t.id = $4 AND t.level > 0",
+            FOLDER_ACCESS_CTE, FOLDER_COLUMNS, FOLDER_FROM
+        ))
+        .bind(actor_id)
+        .bind(is_manager)
+        .bind(MAX_FOLDER_DEPTH)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(folder)
+    }
+
+    // 필요한 접근 권한을 확인하고 폴더 조회
+    pub async fn folder_with_access(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        id: i32,
+        required: DocumentAccess,
+    ) -> Result<DocumentFolder, DocumentError> {
+        let folder = self
+            .find_folder(actor_id, is_manager, id)
+            .await?
+            .ok_or(DocumentError::FolderNotFound)?;
+        if folder.access < required {
+            return Err(DocumentError::AccessDenied(required));
+        }
+        Ok(folder)
+    }
+
+    // 폴더 생성 (만든 사용자가 소유자, 하위 폴더는 상위 폴더의 write 권한 필요)
+    // 같은 상위 폴더 아래 이름 중복은 document_folders_parent_name_key 유니크 위반 (409)
+    pub async fn create_folder(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        request: &CreateDocumentFolderRequest,
+    ) -> Result<DocumentFolder, DocumentError> {
+        if let Some(parent_id) = request.parent_id {
+            self.folder_with_access(actor_id, is_manager, parent_id, DocumentAccess::Write)
+                .await
+                .map_err(|err| match err {
+                    DocumentError::FolderNotFound => DocumentError::InvalidParent,
+                    err => err,
+                })?;
+            if self.exceeds_max_depth(parent_id, 0).await? {
+                return Err(DocumentError::InvalidParent);
+            }
+        }
+
+        let id = sqlx::query_scalar::<_, i32>(
+            "INSERT INTO document_folders (parent_id, name, owner_id) VALUES ($1, $2, $3) RETURNING id",
+        )
+        .bind(request.parent_id)
+        .bind(&request.name)
+        .bind(actor_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("문서함 폴더 생성 완료: {} ({})", request.name, id);
+        self.folder_with_access(actor_id, is_manager, id, DocumentAccess::Read).await
+    }
+
+    // 폴더 수정 (이름 변경, 이동) 후 변경 전/후 폴더 반환
+    // 다른 폴더 아래로 옮기려면 대상 폴더의 write 권한, 최상위로 옮기려면 소유자 또는 documents.manage 필요
+    pub async fn update_folder(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        id: i32,
+        request: &UpdateDocumentFolderRequest,
+    ) -> Result<(DocumentFolder, DocumentFolder), DocumentError> {
+        let before = self
+            .folder_with_access(actor_id, is_manager, id, DocumentAccess::Manage)
+            .await?;
+
+        match request.parent_id {
+            Some(Some(parent_id)) => {
+                if self.is_descendant(id, parent_id).await? {
+                    return Err(DocumentError::InvalidParent);
+                }
+                self.folder_with_access(actor_id, is_manager, parent_id, DocumentAccess::Write)
+                    .await
+                    .map_err(|err| match err {
+                        DocumentError::FolderNotFound => DocumentError::InvalidParent,
+                        err => err,
+                    })?;
+                // 옮긴 뒤 하위 폴더까지 최대 깊이 안에 있어야 함 (하위 트리가 최대 깊이보다 깊으면 is_descendant가
+                // 순환을 놓칠 수 있지만 그 경우도 여기서 거부됨)
+                let height = self.subtree_height(id).await?;
+                if self.exceeds_max_depth(parent_id, height).await? {
+                    return Err(DocumentError::InvalidParent);
+                }
+            }
+            Some(None) if !is_manager && before.owner_id != Some(actor_id) => {
+                return Err(DocumentError::RootNotAllowed);
+            }
+            _ => {}
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE document_folders
+            SET name = COALESCE($1, name),
+                parent_id = CASE WHEN $2 THEN $3 ELSE parent_id END
+            WHERE id = $4
+            "#,
+        )
+        .bind(&request.name)
+        .bind(request.parent_id.is_some())
+        .bind(request.parent_id.flatten())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        tracing::info!("문서함 폴더 수정 완료: ID {}", id);
+        let after = self
+            .folder_with_access(actor_id, is_manager, id, DocumentAccess::Read)
+            .await?;
+        Ok((before, after))
+    }
+
+    // candidate가 ancestor 자신이거나 그 하위 폴더인지 확인 (폴더 이동 시 순환 방지)
+    async fn is_descendant(&self, ancestor_id: i32, candidate_id: i32) -> Result<bool> {
+        let found = sqlx::query_scalar::<_, bool>(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id, 0 AS depth FROM document_folders WHERE id = $1
+                UNION ALL
+                SELECT f.id, s.depth + 1
+                FROM document_folders f
+                JOIN subtree s ON f.parent_id = s.id
+                WHERE s.depth < $3
+            )
+            SELECT EXISTS (SELECT 1 FROM subtree WHERE id = $2)
+            "#,
+        )
+        .bind(ancestor_id)
+        .bind(candidate_id)
+        .bind(MAX_FOLDER_DEPTH)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(found)
+    }
+
+    // 폴더 아래 가장 깊은 하위 폴더까지의 단계 수 (하위 폴더가 없으면 0, 최대 깊이를 넘으면 MAX_FOLDER_DEPTH + 1)
+    async fn subtree_height(&self, id: i32) -> Result<i32> {
+        let height = sqlx::query_scalar::<_, i32>(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id, 0 AS depth FROM document_folders WHERE id = $1
+                UNION ALL
+                SELECT f.id, s.depth + 1
+                FROM document_folders f
+                JOIN subtree s ON f.parent_id = s.id
+                WHERE s.depth <= $2
+            )
+            SELECT COALESCE(MAX(depth), 0) FROM subtree
+            "#,
+        )
+        .bind(id)
+        .bind(MAX_FOLDER_DEPTH)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(height)
+    }
+
+    // parent_id 아래에 높이 height인 폴더를 두면 최대 깊이를 넘는지 확인
+    async fn exceeds_max_depth(&self, parent_id: i32, height: i32) -> Result<bool> {
+        let parent_depth = sqlx::query_scalar::<_, i32>(
+            r#"
+            WITH RECURSIVE ancestors AS (
+                SELECT parent_id, 0 AS depth FROM document_folders WHERE id = $1
+                UNION ALL
+                SELECT f.parent_id, a.depth + 1
+                FROM document_folders f
+                JOIN ancestors a ON f.id = a.parent_id
+                WHERE a.depth <= $2
+            )
+            SELECT COALESCE(MAX(depth), 0) FROM ancestors
+            "#,
+        )
+        .bind(parent_id)
+        .bind(MAX_FOLDER_DEPTH)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(parent_depth + 1 + height > MAX_FOLDER_DEPTH)
+    }
+
+    // 폴더 삭제 (하위 폴더나 문서가 있으면 409, 접근 권한 항목은 함께 삭제)
+    pub async fn delete_folder(&self, actor_id: i32, is_manager: bool, id: i32) -> Result<DocumentFolder, DocumentError> {
+        let folder = self
+            .folder_with_access(actor_id, is_manager, id, DocumentAccess::Manage)
+            .await?;
+
+        let not_empty = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (SELECT 1 FROM document_folders WHERE parent_id = $1)
+                OR EXISTS (SELECT 1 FROM documents WHERE folder_id = $1)
+            "#,
+        )
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+        if not_empty {
+            return Err(DocumentError::FolderNotEmpty);
+        }
+
+        sqlx::query("DELETE FROM document_folders WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        tracing::info!("문서함 폴더 삭제 완료: ID {}", id);
+        Ok(folder)
+    }
+
+    // 폴더에 직접 부여한 접근 권한 (역할, 사용자 순)
+    pub async fn folder_acl(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        id: i32,
+    ) -> Result<Vec<DocumentFolderAclEntry>, DocumentError> {
+        self.folder_with_access(actor_id, is_manager, id, DocumentAccess::Manage)
+            .await?;
+        Ok(find_acl(&self.pool, id).await?)
+    }
+
+    // 폴더 접근 권한 교체 후 변경 전/후 항목 반환
+    pub async fn set_folder_acl(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        id: i32,
+        entries: &[DocumentFolderAclEntryRequest],
+    ) -> Result<(Vec<DocumentFolderAclEntry>, Vec<DocumentFolderAclEntry>), DocumentError> {
+        self.folder_with_access(actor_id, is_manager, id, DocumentAccess::Manage)
+            .await?;
+        self.check_acl_entries(entries).await?;
+
+        let mut tx = self.pool.begin().await?;
+        let before = find_acl(&mut *tx, id).await?;
+        sqlx::query("DELETE FROM document_folder_acl WHERE folder_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        for entry in entries {
+            sqlx::query(
+                r#"
+                INSERT INTO document_folder_acl (folder_id, user_id, role_id, access)
+                VALUES ($1, $2, (SELECT id FROM roles WHERE name = $3), $4)
+                "#,
+            )
+            .bind(id)
+            .bind(entry.user_id)
+            .bind(&entry.role)
+            .bind(entry.access)
+            .execute(&mut *tx)
+            .await?;
+        }
+        let after = find_acl(&mut *tx, id).await?;
+        tx.commit().await?;
+
+        tracing::info!("문서함 폴더 접근 권한 변경 완료: ID {} ({}개)", id, after.len());
+        Ok((before, after))
+    }
+
+    // 접근 권한 항목 확인 (사용자/역할 중 하나, 중복 불가, 존재하는 사용자와 역할)
+    async fn check_acl_entries(&self, entries: &[DocumentFolderAclEntryRequest]) -> Result<(), DocumentError> {
+        let mut user_ids: Vec<i32> = Vec::new();
+        let mut roles: Vec<String> = Vec::new();
+        for entry in entries {
+            let duplicate = match (entry.user_id, &entry.role) {
+                (Some(user_id), None) => {
+                    let duplicate = user_ids.contains(&user_id);
+                    user_ids.push(user_id);
+                    duplicate
+                }
+                (None, Some(role)) => {
+                    let duplicate = roles.contains(role);
+                    roles.push(role.clone());
+                    duplicate
+                }
+                _ => {
+                    return Err(DocumentError::InvalidAclEntry(
+                        "각 항목에는 user_id와 role 중 하나만 지정해야 합니다".to_string(),
+                    ))
+                }
+            };
+            if duplicate {
+                return Err(DocumentError::InvalidAclEntry(
+                    "같은 사용자나 역할을 두 번 지정할 수 없습니다".to_string(),
+                ));
+            }
+        }
+
+        let unknown_users = sqlx::query_scalar::<_, i32>(
+            r#"
+            SELECT id FROM UNNEST($1::int[]) AS id
+            WHERE id NOT IN (SELECT id FROM users WHERE deleted_at IS NULL)
+            "#,
+        )
+        .bind(&user_ids)
+        .fetch_all(&self.pool)
+        .await?;
+        if !unknown_users.is_empty() {
+            let ids: Vec<String> = unknown_users.iter().map(|id| id.to_string()).collect();
+            return Err(DocumentError::InvalidAclEntry(format!(
+                "존재하지 않는 사용자입니다: {}",
+                ids.join(", ")
+            )));
+        }
+
+        let unknown_roles = sqlx::query_scalar::<_, String>(
+            "SELECT name FROM UNNEST($1::text[]) AS name WHERE name NOT IN (SELECT name FROM roles)",
+        )
+        .bind(&roles)
+        .fetch_all(&self.pool)
+        .await?;
+        if !unknown_roles.is_empty() {
+            return Err(DocumentError::InvalidAclEntry(format!(
+                "존재하지 않는 역할입니다: {}",
+                unknown_roles.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+
+    // 폴더의 문서 목록 (이름 순)
+    pub async fn find_documents(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        folder_id: i32,
+    ) -> Result<Vec<Document>, DocumentError> {
+        self.folder_with_access(actor_id, is_manager, folder_id, DocumentAccess::Read)
+            .await?;
+
+        let documents = sqlx::query_as::<_, Document>(&format!(
+            "SELECT {} {} WHERE d.folder_id = $1 ORDER BY d.name, d.id",
+            DOCUMENT_COLUMNS, DOCUMENT_FROM
+        ))
+        .bind(folder_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(documents)
+    }
+
+    // 필요한 폴더 접근 권한을 확인하고 문서 조회
+    pub async fn document_with_access(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        id: i32,
+        required: DocumentAccess,
+    ) -> Result<Document, DocumentError> {
+        let document = find_document(&self.pool, id)
+            .await?
+            .ok_or(DocumentError::DocumentNotFound)?;
+        self.folder_with_access(actor_id, is_manager, document.folder_id, required)
+            .await
+            .map_err(|err| match err {
+                DocumentError::FolderNotFound => DocumentError::DocumentNotFound,
+                err => err,
+            })?;
+        Ok(document)
+    }
+
+    // 문서 버전 목록 (최신 버전부터)
+    pub async fn versions(&self, actor_id: i32, is_manager: bool, id: i32) -> Result<Vec<DocumentVersion>, DocumentError> {
+        self.document_with_access(actor_id, is_manager, id, DocumentAccess::Read)
+            .await?;
+
+        let versions = sqlx::query_as::<_, DocumentVersion>(&format!(
+            r#"
+            SELECT {}
+            FROM document_versions v
+            LEFT JOIN users u ON u.id = v.uploaded_by
+            WHERE v.document_id = $1
+            ORDER BY v.version DESC
+            "#,
+            VERSION_COLUMNS
+        ))
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(versions)
+    }
+
+    // 내려받을 문서와 버전 조회 (version을 생략하면 현재 버전)
+    pub async fn find_version(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        id: i32,
+        version: Option<i32>,
+    ) -> Result<(Document, DocumentVersion), DocumentError> {
+        let document = self
+            .document_with_access(actor_id, is_manager, id, DocumentAccess::Read)
+            .await?;
+
+        let found = sqlx::query_as::<_, DocumentVersion>(&format!(
+            r#"
+            SELECT {}
+            FROM document_versions v
+            LEFT JOIN users u ON u.id = v.uploaded_by
+            WHERE v.document_id = $1 AND v.version = $2
+            "#,
+            VERSION_COLUMNS
+        ))
+        .bind(id)
+        .bind(version.unwrap_or(document.current_version))
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(DocumentError::VersionNotFound)?;
+
+        Ok((document, found))
+    }
+
+    // 파일 내용 읽기 (range를 지정하면 일부만)
+    pub async fn read(&self, checksum: &str, range: Option<ByteRange>) -> Result<Vec<u8>, DocumentError> {
+        let key = Self::key(checksum);
+        let data = match range {
+            Some(range) => self.store.get_range(&key, range.start, range.end).await?,
+            None => self.store.get(&key).await?,
+        };
+
+        data.ok_or_else(|| DocumentError::Storage(anyhow::anyhow!("저장소에 문서 파일이 없습니다: {}", key)))
+    }
+
+    // 문서 업로드 (폴더의 write 권한 필요, 올린 사용자가 소유자)
+    // 같은 폴더에 같은 이름이 있으면 documents_folder_name_key 유니크 위반 (409, 새 버전으로 올려야 함)
+    pub async fn create_document(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        folder_id: i32,
+        name: &str,
+        upload: DocumentUpload,
+    ) -> Result<Document, DocumentError> {
+        self.folder_with_access(actor_id, is_manager, folder_id, DocumentAccess::Write)
+            .await?;
+        let checksum = verify_checksum(&upload)?;
+
+        let mut tx = self.pool.begin().await?;
+        self.check_quota(&mut tx, actor_id, upload.data.len() as i64).await?;
+        let id = sqlx::query_scalar::<_, i32>(
+            "INSERT INTO documents (folder_id, name, owner_id) VALUES ($1, $2, $3) RETURNING id",
+        )
+        .bind(folder_id)
+        .bind(name)
+        .bind(actor_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        insert_version(&mut *tx, id, 1, actor_id, &checksum, &upload).await?;
+
+        // 파일 저장에 실패하면 문서도 만들지 않음
+        self.save(&mut tx, &checksum, upload).await?;
+        tx.commit().await?;
+
+        tracing::info!("문서 업로드 완료: {} ({})", name, id);
+        find_document(&self.pool, id)
+            .await?
+            .ok_or(DocumentError::DocumentNotFound)
+    }
+
+    // 새 버전 업로드 (폴더의 write 권한 필요, 용량은 문서 소유자의 할당량에서 계산)
+    pub async fn add_version(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        id: i32,
+        upload: DocumentUpload,
+    ) -> Result<Document, DocumentError> {
+        self.document_with_access(actor_id, is_manager, id, DocumentAccess::Write)
+            .await?;
+        let checksum = verify_checksum(&upload)?;
+
+        let mut tx = self.pool.begin().await?;
+        let locked = sqlx::query_as::<_, LockedDocument>(
+            "SELECT owner_id, current_version FROM documents WHERE id = $1 FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(DocumentError::DocumentNotFound)?;
+
+        // 소유자가 영구 삭제되어 없는 문서는 새 버전을 올린 사용자가 소유자가 됨
+        let owner_id = locked.owner_id.unwrap_or(actor_id);
+        self.check_quota(&mut tx, owner_id, upload.data.len() as i64).await?;
+        let version = locked.current_version + 1;
+        insert_version(&mut *tx, id, version, actor_id, &checksum, &upload).await?;
+        sqlx::query("UPDATE documents SET current_version = $1, owner_id = $2 WHERE id = $3")
+            .bind(version)
+            .bind(owner_id)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        self.save(&mut tx, &checksum, upload).await?;
+        tx.commit().await?;
+
+        tracing::info!("문서 새 버전 업로드 완료: 문서 ID {}, 버전 {}", id, version);
+        find_document(&self.pool, id)
+            .await?
+            .ok_or(DocumentError::DocumentNotFound)
+    }
+
+    // 파일 저장 (같은 내용이 이미 있으면 다시 쓰지 않음)
+    // 버전 행을 넣은 트랜잭션에서 호출해 커밋할 때까지 같은 파일의 삭제를 막음
+    async fn save(&self, conn: &mut sqlx::PgConnection, checksum: &str, upload: DocumentUpload) -> Result<()> {
+        let key = Self::key(checksum);
+        lock_blob(conn, &key).await?;
+        if !self.store.exists(&key).await? {
+            self.store.put(&key, upload.data, &upload.content_type).await?;
+        }
+        Ok(())
+    }
+
+    // 업로드 후 소유자의 사용량이 할당량을 넘지 않는지 확인
+    // 같은 사용자의 동시 업로드가 함께 할당량을 넘지 않도록 트랜잭션 끝까지 사용자 행을 잠금
+    async fn check_quota(&self, conn: &mut sqlx::PgConnection, owner_id: i32, size: i64) -> Result<(), DocumentError> {
+        sqlx::query("SELECT 1 FROM users WHERE id = $1 FOR NO KEY UPDATE")
+            .bind(owner_id)
+            .execute(&mut *conn)
+            .await?;
+
+        let quota = self.quota_in(&mut *conn, owner_id).await?;
+        if quota.used_bytes + size > quota.quota_bytes {
+            return Err(DocumentError::QuotaExceeded {
+                used: quota.used_bytes,
+                size,
+                quota: quota.quota_bytes,
+            });
+        }
+        Ok(())
+    }
+
+    // 문서 수정 (이름 변경, 다른 폴더로 이동) 후 변경 전/후 문서 반환
+    // 다른 폴더로 옮기려면 대상 폴더의 write 권한 필요
+    pub async fn update_document(
+        &self,
+        actor_id: i32,
+        is_manager: bool,
+        id: i32,
+        request: &UpdateDocumentRequest,
+    ) -> Result<(Document, Document), DocumentError> {
+        let before = self
+            .document_with_access(actor_id, is_manager, id, DocumentAccess::Write)
+            .await?;
+        if let Some(folder_id) = request.folder_id.filter(|folder_id| *folder_id != before.folder_id) {
+            self.folder_with_access(actor_id, is_manager, folder_id, DocumentAccess::Write)
+                .await
+                .map_err(|err| match err {
+                    DocumentError::FolderNotFound => DocumentError::InvalidFolder,
+                    err => err,
+                })?;
+        }
+
+        sqlx::query("UPDATE documents SET name = COALESCE($1, name), folder_id = COALESCE($2, folder_id) WHERE id = $3")
+            .bind(&request.name)
+            .bind(request.folder_id)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        tracing::info!("문서 수정 완료: ID {}", id);
+        let after = find_document(&self.pool, id)
+            .await?
+            .ok_or(DocumentError::DocumentNotFound)?;
+        Ok((before, after))
+    }
+
+    // 문서 삭제 (모든 버전 포함, 다른 문서가 쓰지 않는 파일은 저장소에서도 삭제)
+    pub async fn delete_document(&self, actor_id: i32, is_manager: bool, id: i32) -> Result<Document, DocumentError> {
+        let document = self
+            .document_with_access(actor_id, is_manager, id, DocumentAccess::Write)
+            .await?;
+
+        let checksums = sqlx::query_scalar::<_, String>(
+            r#"
+            WITH deleted AS (
+                DELETE FROM documents WHERE id = $1 RETURNING id
+            )
+            SELECT DISTINCT checksum_sha256 FROM document_versions WHERE document_id IN (SELECT id FROM deleted)
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+        for checksum in checksums {
+            self.delete_if_unused(&checksum).await;
+        }
+
+        tracing::info!("문서 삭제 완료: ID {}", id);
+        Ok(document)
+    }
+
+    // 더 이상 쓰는 버전이 없으면 파일 삭제 (실패는 로그로만 남김)
+    async fn delete_if_unused(&self, checksum: &str) {
+        let result = async {
+            // 같은 내용을 올리는 업로드가 끝날 때까지 기다린 뒤 커밋된 참조를 확인
+            let key = Self::key(checksum);
+            let mut tx = self.pool.begin().await?;
+            lock_blob(&mut tx, &key).await?;
+            let in_use = sqlx::query_scalar::<_, bool>(
+                "SELECT EXISTS (SELECT 1 FROM document_versions WHERE checksum_sha256 = $1)",
+            )
+            .bind(checksum)
+            .fetch_one(&mut *tx)
+            .await?;
+            if !in_use {
+                self.store.delete(&key).await?;
+            }
+            tx.commit().await?;
+            anyhow::Ok(())
+        }
+        .await;
+
+        if let Err(err) = result {
+            tracing::error!("문서 파일 삭제 실패 ({}): {}", checksum, err);
+        }
+    }
+
+    // 사용자의 문서 용량 사용량과 할당량
+    pub async fn quota(&self, user_id: i32) -> Result<DocumentQuota> {
+        self.quota_in(&self.pool, user_id).await
+    }
+
+    async fn quota_in<'e>(&self, executor: impl PgExecutor<'e>, user_id: i32) -> Result<DocumentQuota> {
+        let row = sqlx::query_as::<_, QuotaRow>(
+            r#"
+            SELECT COALESCE((
+                       SELECT SUM(v.size_bytes)
+                       FROM document_versions v
+                       JOIN documents d ON d.id = v.document_id
+                       WHERE d.owner_id = $1
+                   ), 0)::BIGINT AS used_bytes,
+                   (SELECT quota_bytes FROM document_quotas WHERE user_id = $1) AS quota_bytes
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(executor)
+        .await?;
+
+        Ok(DocumentQuota {
+            user_id,
+            used_bytes: row.used_bytes,
+            quota_bytes: row.quota_bytes.unwrap_or(self.default_quota_bytes),
+            is_default: row.quota_bytes.is_none(),
+        })
+    }
+
+    // 사용자별 할당량 설정 (None이면 기본값으로 되돌림)
+    pub async fn set_quota(&self, user_id: i32, quota_bytes: Option<i64>) -> Result<DocumentQuota> {
+        match quota_bytes {
+            Some(quota_bytes) => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO document_quotas (user_id, quota_bytes)
+                    VALUES ($1, $2)
+                    ON CONFLICT (user_id) DO UPDATE
+                    SET quota_bytes = EXCLUDED.quota_bytes, updated_at = NOW()
+                    "#,
+                )
+                .bind(user_id)
+                .bind(quota_bytes)
+                .execute(&self.pool)
+                .await?;
+            }
+            None => {
+                sqlx::query("DELETE FROM document_quotas WHERE user_id = $1")
+                    .bind(user_id)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+
+        tracing::info!("문서 용량 할당량 설정 완료: 사용자 ID {}", user_id);
+        self.quota(user_id).await
+    }
+}
+
+// 받은 파일의 SHA-256 계산 (클라이언트가 보낸 checksum이 있으면 비교)
+fn verify_checksum(upload: &DocumentUpload) -> Result<String, DocumentError> {
+    let checksum = content_hash(&upload.data);
+    match &upload.checksum {
+        Some(expected) if *expected != checksum => Err(DocumentError::ChecksumMismatch(checksum)),
+        _ => Ok(checksum),
+    }
+}
+
+async fn find_document<'e>(executor: impl PgExecutor<'e>, id: i32) -> sqlx::Result<Option<Document>> {
+    sqlx::query_as::<_, Document>(&format!("SELECT {} {} WHERE d.id = $1", DOCUMENT_COLUMNS, DOCUMENT_FROM))
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+}
+
+async fn find_acl<'e>(executor: impl PgExecutor<'e>, folder_id: i32) -> sqlx::Result<Vec<DocumentFolderAclEntry>> {
+    sqlx::query_as::<_, DocumentFolderAclEntry>(
+        r#"
+        SELECT a.user_id, u.name AS user_name, r.name AS role, a.access
+        FROM document_folder_acl a
+        LEFT JOIN users u ON u.id = a.user_id
+        LEFT JOIN roles r ON r.id = a.role_id
+        WHERE a.folder_id = $1
+        ORDER BY a.role_id IS NULL, r.name, u.name, a.user_id
+        "#,
+    )
+    .bind(folder_id)
+    .fetch_all(executor)
+    .await
+}
+
+async fn insert_version<'e>(
+    executor: impl PgExecutor<'e>,
+    document_id: i32,
+    version: i32,
+    uploaded_by: i32,
+    checksum: &str,
+    upload: &DocumentUpload,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO document_versions
+            (document_id, version, size_bytes, content_type, checksum_sha256, uploaded_by, comment)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "#,
+    )
+    .bind(document_id)
+    .bind(version)
+    .bind(upload.data.len() as i64)
+    .bind(&upload.content_type)
+    .bind(checksum)
+    .bind(uploaded_by)
+    .bind(&upload.comment)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partial(start: u64, end: u64) -> RangeRequest {
+        RangeRequest::Partial(ByteRange { start, end })
+    }
+
+    #[test]
+    fn parses_single_byte_ranges() {
+        assert_eq!(parse_range("bytes=0-9", 100), partial(0, 9));
+        assert_eq!(parse_range("bytes=90-", 100), partial(90, 99));
+        assert_eq!(parse_range("bytes=-10", 100), partial(90, 99));
+        assert_eq!(parse_range("bytes=95-200", 100), partial(95, 99));
+        assert_eq!(parse_range("bytes=-500", 100), partial(0, 99));
+        assert_eq!(parse_range("Bytes = 5-5", 100), partial(5, 5));
+    }
+
+    #[test]
+    fn out_of_bounds_ranges_are_unsatisfiable() {
+        assert_eq!(parse_range("bytes=100-", 100), RangeRequest::Unsatisfiable);
+        assert_eq!(parse_range("bytes=150-160", 100), RangeRequest::Unsatisfiable);
+        assert_eq!(parse_range("bytes=-0", 100), RangeRequest::Unsatisfiable);
+        assert_eq!(parse_range("bytes=-5", 0), RangeRequest::Unsatisfiable);
+        assert_eq!(parse_range("bytes=0-", 0), RangeRequest::Unsatisfiable);
+    }
+
+    // 해석할 수 없거나 여러 범위인 요청은 무시하고 전체 응답
+    #[test]
+    fn invalid_or_multiple_ranges_fall_back_to_full() {
+        for header in ["items=0-9", "bytes=9-0", "bytes=a-b", "bytes=-", "bytes=0-1,5-6", "bytes 0-9", "bytes=+1-2"] {
+            assert_eq!(parse_range(header, 100), RangeRequest::Full, "{:?}", header);
+        }
+    }
+
+    #[test]
+    fn content_disposition_keeps_utf8_name() {
+        assert_eq!(
+            content_disposition("회의록 \"최종\".pdf"),
+            "attachment; filename=\"___ ____.pdf\"; \
+             filename*=UTF-8''%ED%9A%8C%EC%9D%98%EB%A1%9D%20%22%EC%B5%9C%EC%A2%85%22.pdf"
+        );
+        assert_eq!(
+            content_disposition("report-2024.csv"),
+            "attachment; filename=\"report-2024.csv\"; filename*=UTF-8''report-2024.csv"
+        );
+    }
+}
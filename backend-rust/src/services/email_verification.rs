@@ -0,0 +1,96 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+
+use super::tokens::{generate_token, hash_token};
+use crate::models::User;
+
+pub struct EmailVerificationService {
+    pool: PgPool,
+    expires_hours: i64,
+}
+
+impl EmailVerificationService {
+    pub fn new(pool: PgPool, expires_hours: i64) -> Self {
+        Self { pool, expires_hours }
+    }
+
+    // 현재 이메일에 대한 인증 토큰 발급 (이전에 발급된 미사용 토큰은 무효화)
+    pub async fn create_token(&self, user: &User) -> Result<String> {
+        let token = generate_token();
+        let expires_at = Utc::now() + Duration::hours(self.expires_hours);
+
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("UPDATE email_verification_tokens SET used_at = NOW() WHERE user_id = $1 AND used_at IS NULL")
+            .bind(user.id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO email_verification_tokens (user_id, email, token_hash, expires_at)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(user.id)
+        .bind(&user.email)
+        .bind(hash_token(&token))
+        .bind(expires_at)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(token)
+    }
+
+    // 토큰으로 이메일 인증
+    // 유효하지 않거나 만료/사용된 토큰, 발급 이후 이메일이 바뀐 경우 None 반환
+    pub async fn verify(&self, token: &str) -> Result<Option<User>> {
+        let mut tx = self.pool.begin().await?;
+
+        let stored = sqlx::query_as::<_, (i32, String)>(
+            r#"
+            SELECT user_id, email
+            FROM email_verification_tokens
+            WHERE token_hash = $1 AND used_at IS NULL AND expires_at > NOW()
+            FOR UPDATE
+            "#,
+        )
+        .bind(hash_token(token))
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let (user_id, email) = match stored {
+            Some(stored) => stored,
+            None => return Ok(None),
+        };
+
+        let user = sqlx::query_as::<_, User>(
+            r#"
+            UPDATE users SET email_verified_at = COALESCE(email_verified_at, NOW())
+            WHERE id = $1 AND email = $2 AND deleted_at IS NULL
+            RETURNING id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash
+            "#,
+        )
+        .bind(user_id)
+        .bind(&email)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let user = match user {
+            Some(user) => user,
+            None => return Ok(None),
+        };
+
+        sqlx::query("UPDATE email_verification_tokens SET used_at = NOW() WHERE user_id = $1 AND used_at IS NULL")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("이메일 인증 완료: {}", user.email);
+        Ok(Some(user))
+    }
+}
@@ -0,0 +1,772 @@
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate, Utc, Weekday};
+use sqlx::{FromRow, PgExecutor, PgPool, Postgres, QueryBuilder};
+
+use super::departments::MAX_DEPTH;
+use crate::models::{
+    CreateLeaveRequest, CreateLeaveTypeRequest, HalfDay, LeaveAccrual, LeaveApproval, LeaveBalance,
+    LeaveCalendarEntry, LeaveCalendarQuery, LeaveDecision, LeaveRequest, LeaveRequestListQuery, LeaveStatus,
+    LeaveStep, LeaveType, SetLeaveBalanceRequest, UpdateLeaveTypeRequest,
+};
+
+const LEAVE_TYPE_COLUMNS: &str = r#"
+    id, code, name, annual_days::FLOAT8 AS annual_days, accrual,
+    max_carryover_days::FLOAT8 AS max_carryover_days, paid, is_active, created_at, updated_at
+"#;
+
+// 휴가 신청 조회 컬럼 (신청자 이름, 휴가 종류 포함)
+const LEAVE_REQUEST_COLUMNS: &str = r#"
+    r.id, r.user_id, u.name AS user_name, r.leave_type_id, t.code AS leave_type_code,
+    t.name AS leave_type_name, r.start_date, r.end_date, r.half_day, r.days::FLOAT8 AS days,
+    r.reason, r.status, r.manager_id, r.created_at, r.updated_at
+"#;
+
+const LEAVE_REQUEST_FROM: &str = r#"
+    FROM leave_requests r
+    JOIN users u ON u.id = r.user_id
+    JOIN leave_types t ON t.id = r.leave_type_id
+"#;
+
+// 휴가 신청 처리 오류
+#[derive(Debug, thiserror::Error)]
+pub enum LeaveError {
+    #[error("휴가 신청을 찾을 수 없습니다")]
+    NotFound,
+
+    #[error("사용할 수 없는 휴가 종류입니다")]
+    InvalidLeaveType,
+
+    #[error("{0}")]
+    InvalidRange(&'static str),
+
+    #[error("이미 신청한 휴가와 기간이 겹칩니다 (신청 ID {0})")]
+    Overlap(i32),
+
+    #[error("잔여 일수가 부족합니다 (잔여 {remaining}일, 신청 {requested}일)")]
+    InsufficientBalance { remaining: f64, requested: f64 },
+
+    #[error("'{}' 상태의 신청에는 할 수 없는 작업입니다", .0.as_str())]
+    InvalidTransition(LeaveStatus),
+
+    #[error("이미 시작된 휴가는 취소할 수 없습니다")]
+    AlreadyStarted,
+
+    #[error("이 단계의 승인 권한이 없습니다")]
+    NotApprover,
+
+    #[error("본인의 휴가 신청만 취소할 수 있습니다")]
+    NotRequester,
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+// 휴가 신청에 대한 작업
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaveAction {
+    Approve,
+    Reject,
+    Cancel,
+}
+
+// 상태 전이 규칙 (허용되지 않는 작업이면 None)
+// 승인은 직속 상사 승인 대기 -> 인사 승인 대기 -> 승인 순서로만 진행
+pub fn next_status(status: LeaveStatus, action: LeaveAction) -> Option<LeaveStatus> {
+    use LeaveStatus::*;
+
+    match (status, action) {
+        (PendingManager, LeaveAction::Approve) => Some(PendingHr),
+        (PendingHr, LeaveAction::Approve) => Some(Approved),
+        (PendingManager | PendingHr, LeaveAction::Reject) => Some(Rejected),
+        (PendingManager | PendingHr | Approved, LeaveAction::Cancel) => Some(Cancelled),
+        _ => None,
+    }
+}
+
+// 주말을 제외한 사용 일수 (반차는 0.5)
+pub fn count_leave_days(start_date: NaiveDate, end_date: NaiveDate, half_day: Option<HalfDay>) -> f64 {
+    let weekdays = start_date
+        .iter_days()
+        .take_while(|date| *date <= end_date)
+        .filter(|date| !matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+        .count();
+
+    match (weekdays, half_day) {
+        (0, _) => 0.0,
+        (_, Some(_)) => 0.5,
+        (weekdays, None) => weekdays as f64,
+    }
+}
+
+// 적립 규칙에 따라 as_of 날짜까지 부여된 일수
+// 입사 연도에는 입사 월부터 비례 부여하고, 입사 전 연도는 0
+pub fn accrued_days(
+    accrual: LeaveAccrual,
+    annual_days: f64,
+    year: i32,
+    hire_date: Option<NaiveDate>,
+    as_of: NaiveDate,
+) -> f64 {
+    let first_month = match hire_date {
+        Some(hire_date) if hire_date.year() > year => return 0.0,
+        Some(hire_date) if hire_date.year() == year => hire_date.month(),
+        _ => 1,
+    };
+
+    let months = match accrual {
+        LeaveAccrual::None => return 0.0,
+        LeaveAccrual::Yearly => 13 - first_month,
+        LeaveAccrual::Monthly => {
+            let last_month = match as_of.year() {
+                as_of_year if as_of_year < year => return 0.0,
+                as_of_year if as_of_year > year => 12,
+                _ => as_of.month(),
+            };
+            (last_month + 1).saturating_sub(first_month)
+        }
+    };
+
+    round_days(annual_days * months as f64 / 12.0)
+}
+
+// 일수는 소수점 둘째 자리까지 (DB의 NUMERIC(5, 2)와 같은 정밀도)
+fn round_days(days: f64) -> f64 {
+    (days * 100.0).round() / 100.0
+}
+
+// 잔여 일수 계산용 휴가 종류별 집계 행
+#[derive(FromRow)]
+struct BalanceRow {
+    leave_type_id: i32,
+    code: String,
+    name: String,
+    annual_days: f64,
+    accrual: LeaveAccrual,
+    carried_over_days: f64,
+    adjustment_days: f64,
+    used_days: f64,
+    pending_days: f64,
+}
+
+impl BalanceRow {
+    fn into_balance(self, year: i32, hire_date: Option<NaiveDate>, as_of: NaiveDate) -> LeaveBalance {
+        let accrued_days = accrued_days(self.accrual, self.annual_days, year, hire_date, as_of);
+        let entitled_days = round_days(accrued_days + self.carried_over_days + self.adjustment_days);
+
+        LeaveBalance {
+            leave_type_id: self.leave_type_id,
+            leave_type_code: self.code,
+            leave_type_name: self.name,
+            year,
+            accrued_days,
+            carried_over_days: self.carried_over_days,
+            adjustment_days: self.adjustment_days,
+            entitled_days,
+            used_days: self.used_days,
+            pending_days: self.pending_days,
+            remaining_days: round_days(entitled_days - self.used_days - self.pending_days),
+        }
+    }
+}
+
+// 상태 변경 전 잠근 신청
+#[derive(FromRow)]
+struct LockedRequest {
+    user_id: i32,
+    status: LeaveStatus,
+    manager_id: Option<i32>,
+    start_date: NaiveDate,
+}
+
+pub struct LeaveService {
+    pool: PgPool,
+}
+
+impl LeaveService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 휴가 종류 목록
+    pub async fn find_types(&self, include_inactive: bool) -> Result<Vec<LeaveType>> {
+        let types = sqlx::query_as::<_, LeaveType>(&format!(
+            "SELECT {} FROM leave_types WHERE is_active OR $1 ORDER BY id",
+            LEAVE_TYPE_COLUMNS
+        ))
+        .bind(include_inactive)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(types)
+    }
+
+    // 휴가 종류 조회
+    pub async fn find_type(&self, id: i32) -> Result<Option<LeaveType>> {
+        let leave_type = sqlx::query_as::<_, LeaveType>(&format!(
+            "SELECT {} FROM leave_types WHERE id = $1",
+            LEAVE_TYPE_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(leave_type)
+    }
+
+    // 휴가 종류 생성 (코드 중복은 leave_types_code_key 제약으로 409)
+    pub async fn create_type(&self, request: &CreateLeaveTypeRequest) -> Result<LeaveType> {
+        let leave_type = sqlx::query_as::<_, LeaveType>(&format!(
+            r#"
+            INSERT INTO leave_types (code, name, annual_days, accrual, max_carryover_days, paid)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING {}
+            "#,
+            LEAVE_TYPE_COLUMNS
+        ))
+        .bind(&request.code)
+        .bind(&request.name)
+        .bind(request.annual_days)
+        .bind(request.accrual)
+        .bind(request.max_carryover_days)
+        .bind(request.paid)
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("휴가 종류 생성 완료: {} ({})", leave_type.code, leave_type.id);
+        Ok(leave_type)
+    }
+
+    // 휴가 종류 수정 (생략한 항목은 유지)
+    pub async fn update_type(&self, id: i32, request: &UpdateLeaveTypeRequest) -> Result<Option<LeaveType>> {
+        let leave_type = sqlx::query_as::<_, LeaveType>(&format!(
+            r#"
+            UPDATE leave_types
+            SET name = COALESCE($1, name),
+                annual_days = COALESCE($2, annual_days),
+                accrual = COALESCE($3, accrual),
+                max_carryover_days = COALESCE($4, max_carryover_days),
+                paid = COALESCE($5, paid),
+                is_active = COALESCE($6, is_active),
+                updated_at = NOW()
+            WHERE id = $7
+            RETURNING {}
+            "#,
+            LEAVE_TYPE_COLUMNS
+        ))
+        .bind(&request.name)
+        .bind(request.annual_days)
+        .bind(request.accrual)
+        .bind(request.max_carryover_days)
+        .bind(request.paid)
+        .bind(request.is_active)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if leave_type.is_some() {
+            tracing::info!("휴가 종류 수정 완료: ID {}", id);
+        }
+        Ok(leave_type)
+    }
+
+    // 연도별 잔여 일수 (사용 중인 휴가 종류 중 잔여 일수를 관리하는 종류만)
+    pub async fn balances(&self, user_id: i32, year: i32) -> Result<Vec<LeaveBalance>> {
+        let hire_date = hire_date(&self.pool, user_id).await?;
+        let today = Utc::now().date_naive();
+
+        Ok(balance_rows(&self.pool, user_id, year, None)
+            .await?
+            .into_iter()
+            .map(|row| row.into_balance(year, hire_date, today))
+            .collect())
+    }
+
+    // 연도별 이월/조정 일수 저장 (없으면 생성, 있으면 교체)
+    pub async fn set_balance(&self, user_id: i32, request: &SetLeaveBalanceRequest) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO leave_balances (user_id, leave_type_id, year, carried_over_days, adjustment_days, note)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (user_id, leave_type_id, year) DO UPDATE
+            SET carried_over_days = EXCLUDED.carried_over_days,
+                adjustment_days = EXCLUDED.adjustment_days,
+                note = EXCLUDED.note,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(user_id)
+        .bind(request.leave_type_id)
+        .bind(request.year)
+        .bind(request.carried_over_days)
+        .bind(request.adjustment_days)
+        .bind(&request.note)
+        .execute(&self.pool)
+        .await?;
+
+        tracing::info!(
+            "휴가 잔여 일수 조정 완료: 사용자 ID {}, 휴가 종류 ID {}, {}년",
+            user_id,
+            request.leave_type_id,
+            request.year
+        );
+        Ok(())
+    }
+
+    // 휴가 신청
+    // 직속 상사(없으면 부서장)가 있으면 상사 승인 대기, 없으면 바로 인사 승인 대기로 시작
+    // 잔여 일수는 휴가 종료일까지 적립된 일수 기준이며 승인 대기 중인 신청도 미리 차감
+    pub async fn submit(&self, user_id: i32, request: &CreateLeaveRequest) -> Result<LeaveRequest, LeaveError> {
+        if request.end_date < request.start_date {
+            return Err(LeaveError::InvalidRange("종료일은 시작일 이후여야 합니다"));
+        }
+        if request.start_date.year() != request.end_date.year() {
+            return Err(LeaveError::InvalidRange("연도를 넘는 휴가는 연도별로 나눠서 신청해야 합니다"));
+        }
+        if request.half_day.is_some() && request.start_date != request.end_date {
+            return Err(LeaveError::InvalidRange("반차는 하루짜리 신청에만 지정할 수 있습니다"));
+        }
+        let days = count_leave_days(request.start_date, request.end_date, request.half_day);
+        if days == 0.0 {
+            return Err(LeaveError::InvalidRange("기간에 평일이 없습니다"));
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        // 같은 사용자의 신청은 순서대로 처리 (중복/잔여 일수 확인과 저장 사이에 다른 신청이 끼어들지 않도록)
+        sqlx::query("SELECT id FROM users WHERE id = $1 FOR UPDATE")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let leave_type = sqlx::query_as::<_, LeaveType>(&format!(
+            "SELECT {} FROM leave_types WHERE id = $1 AND is_active",
+            LEAVE_TYPE_COLUMNS
+        ))
+        .bind(request.leave_type_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(LeaveError::InvalidLeaveType)?;
+
+        // 진행 중이거나 승인된 신청과 기간이 겹치면 거부 (같은 날 오전/오후 반차끼리는 허용)
+        let overlapping = sqlx::query_scalar::<_, i32>(
+            r#"
+            SELECT id FROM leave_requests
+            WHERE user_id = $1
+              AND status IN ('pending_manager', 'pending_hr', 'approved')
+              AND start_date <= $3 AND end_date >= $2
+              AND NOT (half_day IS NOT NULL AND $4::VARCHAR IS NOT NULL AND half_day <> $4)
+            ORDER BY start_date
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .bind(request.start_date)
+        .bind(request.end_date)
+        .bind(request.half_day)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if let Some(overlapping) = overlapping {
+            return Err(LeaveError::Overlap(overlapping));
+        }
+
+        if leave_type.accrual != LeaveAccrual::None {
+            let year = request.start_date.year();
+            let hire_date = hire_date(&mut *tx, user_id).await?;
+            let remaining = balance_rows(&mut *tx, user_id, year, Some(leave_type.id))
+                .await?
+                .into_iter()
+                .next()
+                .map_or(0.0, |row| row.into_balance(year, hire_date, request.end_date).remaining_days);
+            if remaining < days {
+                return Err(LeaveError::InsufficientBalance { remaining, requested: days });
+            }
+        }
+
+        let manager_id = manager_for(&mut *tx, user_id).await?;
+        let status = if manager_id.is_some() {
+            LeaveStatus::PendingManager
+        } else {
+            LeaveStatus::PendingHr
+        };
+
+        let id = sqlx::query_scalar::<_, i32>(
+            r#"
+            INSERT INTO leave_requests (user_id, leave_type_id, start_date, end_date, half_day, days, reason, status, manager_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id
+            "#,
+        )
+        .bind(user_id)
+        .bind(leave_type.id)
+        .bind(request.start_date)
+        .bind(request.end_date)
+        .bind(request.half_day)
+        .bind(days)
+        .bind(&request.reason)
+        .bind(status)
+        .bind(manager_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("휴가 신청 완료: 사용자 ID {}, 신청 ID {} ({}일)", user_id, id, days);
+        self.find_request(id).await
+    }
+
+    // 휴가 신청 조회
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<LeaveRequest>> {
+        Ok(fetch_request(&self.pool, id).await?)
+    }
+
+    // 휴가 신청의 승인/반려 기록 (처리 순)
+    pub async fn approvals(&self, id: i32) -> Result<Vec<LeaveApproval>> {
+        let approvals = sqlx::query_as::<_, LeaveApproval>(
+            r#"
+            SELECT a.step, a.actor_id, u.name AS actor_name, a.decision, a.comment, a.decided_at
+            FROM leave_approvals a
+            LEFT JOIN users u ON u.id = a.actor_id
+            WHERE a.leave_request_id = $1
+            ORDER BY a.decided_at, a.id
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(approvals)
+    }
+
+    // 사용자의 휴가 신청 목록 (시작일 최근 순)
+    pub async fn find_page(&self, user_id: i32, query: &LeaveRequestListQuery) -> Result<(Vec<LeaveRequest>, i64)> {
+        let push_filters = |builder: &mut QueryBuilder<Postgres>| {
+            builder.push(" WHERE r.user_id = ").push_bind(user_id);
+            push_list_filters(builder, query);
+        };
+
+        self.fetch_page(query, push_filters, " ORDER BY r.start_date DESC, r.id DESC").await
+    }
+
+    // 조회자의 결정을 기다리는 신청 목록 (오래된 신청 순)
+    // 직속 상사 승인 대기 중 본인이 승인자인 신청과, 인사 담당자면 다른 사용자의 인사 승인 대기 신청
+    pub async fn find_pending(
+        &self,
+        approver_id: i32,
+        is_hr: bool,
+        query: &LeaveRequestListQuery,
+    ) -> Result<(Vec<LeaveRequest>, i64)> {
+        let push_filters = |builder: &mut QueryBuilder<Postgres>| {
+            builder
+                .push(" WHERE ((r.status = 'pending_manager' AND r.manager_id = ")
+                .push_bind(approver_id)
+                .push(") OR (r.status = 'pending_hr' AND ")
+                .push_bind(is_hr)
+                .push(" AND r.user_id <> ")
+                .push_bind(approver_id)
+                .push("))");
+            if let Some(user_id) = query.user_id {
+                builder.push(" AND r.user_id = ").push_bind(user_id);
+            }
+            push_list_filters(builder, query);
+        };
+
+        self.fetch_page(query, push_filters, " ORDER BY r.created_at, r.id").await
+    }
+
+    async fn fetch_page(
+        &self,
+        query: &LeaveRequestListQuery,
+        push_filters: impl Fn(&mut QueryBuilder<Postgres>),
+        order_by: &str,
+    ) -> Result<(Vec<LeaveRequest>, i64)> {
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*)");
+        count_query.push(LEAVE_REQUEST_FROM);
+        push_filters(&mut count_query);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut list_query = QueryBuilder::<Postgres>::new("SELECT");
+        list_query.push(LEAVE_REQUEST_COLUMNS).push(LEAVE_REQUEST_FROM);
+        push_filters(&mut list_query);
+        list_query.push(order_by);
+        list_query.push(" LIMIT ").push_bind(query.per_page);
+        list_query.push(" OFFSET ").push_bind((query.page - 1) * query.per_page);
+
+        let requests = list_query
+            .build_query_as::<LeaveRequest>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok((requests, total))
+    }
+
+    // 승인/반려 (단계에 맞는 승인자만 가능)
+    // 직속 상사 승인 대기는 신청 시 지정된 manager_id, 인사 승인 대기는 신청자가 아닌 leave.manage 권한 보유자
+    pub async fn decide(
+        &self,
+        id: i32,
+        actor_id: i32,
+        is_hr: bool,
+        decision: LeaveDecision,
+        comment: Option<&str>,
+    ) -> Result<LeaveRequest, LeaveError> {
+        let action = match decision {
+            LeaveDecision::Approved => LeaveAction::Approve,
+            LeaveDecision::Rejected => LeaveAction::Reject,
+        };
+
+        let mut tx = self.pool.begin().await?;
+        let locked = lock_request(&mut *tx, id).await?.ok_or(LeaveError::NotFound)?;
+        let next = next_status(locked.status, action).ok_or(LeaveError::InvalidTransition(locked.status))?;
+        let step = match locked.status {
+            LeaveStatus::PendingManager if locked.manager_id == Some(actor_id) => LeaveStep::Manager,
+            LeaveStatus::PendingHr if is_hr && locked.user_id != actor_id => LeaveStep::Hr,
+            _ => return Err(LeaveError::NotApprover),
+        };
+
+        sqlx::query("UPDATE leave_requests SET status = $1, updated_at = NOW() WHERE id = $2")
+            .bind(next)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO leave_approvals (leave_request_id, step, actor_id, decision, comment)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(id)
+        .bind(step)
+        .bind(actor_id)
+        .bind(decision)
+        .bind(comment)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("휴가 신청 처리 완료: 신청 ID {}, {} -> {}", id, locked.status.as_str(), next.as_str());
+        self.find_request(id).await
+    }
+
+    // 신청 취소 (신청자만 가능, 승인된 휴가는 시작일 전까지만)
+    pub async fn cancel(&self, id: i32, actor_id: i32) -> Result<LeaveRequest, LeaveError> {
+        let mut tx = self.pool.begin().await?;
+        let locked = lock_request(&mut *tx, id).await?.ok_or(LeaveError::NotFound)?;
+        if locked.user_id != actor_id {
+            return Err(LeaveError::NotRequester);
+        }
+        let next = next_status(locked.status, LeaveAction::Cancel)
+            .ok_or(LeaveError::InvalidTransition(locked.status))?;
+        if locked.status == LeaveStatus::Approved && locked.start_date <= Utc::now().date_naive() {
+            return Err(LeaveError::AlreadyStarted);
+        }
+
+        sqlx::query("UPDATE leave_requests SET status = $1, updated_at = NOW() WHERE id = $2")
+            .bind(next)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("휴가 신청 취소 완료: 신청 ID {}", id);
+        self.find_request(id).await
+    }
+
+    // 팀 휴가 달력 (부서와 하위 부서 소속 사용자의 휴가 중 기간이 겹치는 것, 시작일 순)
+    pub async fn calendar(&self, department_id: i32, query: &LeaveCalendarQuery) -> Result<Vec<LeaveCalendarEntry>> {
+        let entries = sqlx::query_as::<_, LeaveCalendarEntry>(
+            r#"
+            WITH RECURSIVE team AS (
+                SELECT id, 0 AS depth FROM departments WHERE id = $1
+                UNION ALL
+                SELECT d.id, t.depth + 1
+                FROM departments d
+                JOIN team t ON d.parent_id = t.id
+                WHERE t.depth < $5
+            )
+            SELECT r.id AS leave_request_id, r.user_id, u.name AS user_name, m.department_id,
+                   lt.code AS leave_type_code, lt.name AS leave_type_name, r.start_date, r.end_date,
+                   r.half_day, r.days::FLOAT8 AS days, r.status
+            FROM leave_requests r
+            JOIN users u ON u.id = r.user_id AND u.deleted_at IS NULL
+            JOIN department_members m ON m.user_id = r.user_id
+            JOIN leave_types lt ON lt.id = r.leave_type_id
+            WHERE m.department_id IN (SELECT id FROM team)
+              AND r.start_date <= $3 AND r.end_date >= $2
+              AND (r.status = 'approved' OR ($4 AND r.status IN ('pending_manager', 'pending_hr')))
+            ORDER BY r.start_date, u.name, r.id
+            "#,
+        )
+        .bind(department_id)
+        .bind(query.from)
+        .bind(query.to)
+        .bind(query.include_pending)
+        .bind(MAX_DEPTH)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    // 방금 변경한 신청 다시 조회
+    async fn find_request(&self, id: i32) -> Result<LeaveRequest, LeaveError> {
+        fetch_request(&self.pool, id).await?.ok_or(LeaveError::NotFound)
+    }
+}
+
+// 목록 공통 필터 (상태, 시작일 기준 연도)
+fn push_list_filters(builder: &mut QueryBuilder<Postgres>, query: &LeaveRequestListQuery) {
+    if let Some(status) = query.status {
+        builder.push(" AND r.status = ").push_bind(status);
+    }
+    if let Some(year) = query.year {
+        builder
+            .push(" AND r.start_date >= make_date(")
+            .push_bind(year)
+            .push(", 1, 1) AND r.start_date < make_date(")
+            .push_bind(year)
+            .push(" + 1, 1, 1)");
+    }
+}
+
+async fn fetch_request<'e>(executor: impl PgExecutor<'e>, id: i32) -> sqlx::Result<Option<LeaveRequest>> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT");
+    query.push(LEAVE_REQUEST_COLUMNS).push(LEAVE_REQUEST_FROM);
+    query.push(" WHERE r.id = ").push_bind(id);
+
+    query.build_query_as::<LeaveRequest>().fetch_optional(executor).await
+}
+
+// 입사일 (프로필에 없으면 None)
+async fn hire_date<'e>(executor: impl PgExecutor<'e>, user_id: i32) -> sqlx::Result<Option<NaiveDate>> {
+    let hire_date = sqlx::query_scalar::<_, Option<NaiveDate>>("SELECT hire_date FROM user_profiles WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_optional(executor)
+        .await?;
+
+    Ok(hire_date.flatten())
+}
+
+// 1단계 승인자 (소속의 직속 상사, 없으면 본인이 아닌 부서장, 삭제/비활성 사용자 제외)
+async fn manager_for<'e>(executor: impl PgExecutor<'e>, user_id: i32) -> sqlx::Result<Option<i32>> {
+    sqlx::query_scalar::<_, Option<i32>>(
+        r#"
+        SELECT COALESCE(
+            (SELECT mgr.id
+             FROM department_members m
+             JOIN users mgr ON mgr.id = m.manager_id
+             WHERE m.user_id = $1 AND mgr.deleted_at IS NULL AND mgr.is_active),
+            (SELECT head.id
+             FROM department_members m
+             JOIN departments d ON d.id = m.department_id
+             JOIN users head ON head.id = d.head_user_id
+             WHERE m.user_id = $1 AND head.id <> $1 AND head.deleted_at IS NULL AND head.is_active)
+        )
+        "#,
+    )
+    .bind(user_id)
+    .fetch_one(executor)
+    .await
+}
+
+// 휴가 종류별 이월/조정 일수와 해당 연도 신청 일수 집계 (leave_type_id를 지정하면 그 종류만)
+async fn balance_rows<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: i32,
+    year: i32,
+    leave_type_id: Option<i32>,
+) -> sqlx::Result<Vec<BalanceRow>> {
+    sqlx::query_as::<_, BalanceRow>(
+        r#"
+        SELECT t.id AS leave_type_id, t.code, t.name, t.annual_days::FLOAT8 AS annual_days, t.accrual,
+               COALESCE(b.carried_over_days, 0)::FLOAT8 AS carried_over_days,
+               COALESCE(b.adjustment_days, 0)::FLOAT8 AS adjustment_days,
+               COALESCE(r.used_days, 0)::FLOAT8 AS used_days,
+               COALESCE(r.pending_days, 0)::FLOAT8 AS pending_days
+        FROM leave_types t
+        LEFT JOIN leave_balances b ON b.leave_type_id = t.id AND b.user_id = $1 AND b.year = $2
+        LEFT JOIN (
+            SELECT leave_type_id,
+                   SUM(days) FILTER (WHERE status = 'approved') AS used_days,
+                   SUM(days) FILTER (WHERE status IN ('pending_manager', 'pending_hr')) AS pending_days
+            FROM leave_requests
+            WHERE user_id = $1 AND start_date >= make_date($2, 1, 1) AND start_date < make_date($2 + 1, 1, 1)
+            GROUP BY leave_type_id
+        ) r ON r.leave_type_id = t.id
+        WHERE t.is_active AND t.accrual <> 'none' AND ($3::INTEGER IS NULL OR t.id = $3)
+        ORDER BY t.id
+        "#,
+    )
+    .bind(user_id)
+    .bind(year)
+    .bind(leave_type_id)
+    .fetch_all(executor)
+    .await
+}
+
+// 상태를 바꾸기 전에 신청을 잠금 (동시에 승인/취소해도 한 번만 전이)
+async fn lock_request<'e>(executor: impl PgExecutor<'e>, id: i32) -> sqlx::Result<Option<LockedRequest>> {
+    sqlx::query_as::<_, LockedRequest>(
+        "SELECT user_id, status, manager_id, start_date FROM leave_requests WHERE id = $1 FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn approval_chain_goes_through_manager_then_hr() {
+        use LeaveStatus::*;
+
+        assert_eq!(next_status(PendingManager, LeaveAction::Approve), Some(PendingHr));
+        assert_eq!(next_status(PendingHr, LeaveAction::Approve), Some(Approved));
+        assert_eq!(next_status(PendingManager, LeaveAction::Reject), Some(Rejected));
+        assert_eq!(next_status(PendingHr, LeaveAction::Reject), Some(Rejected));
+        assert_eq!(next_status(Approved, LeaveAction::Cancel), Some(Cancelled));
+
+        // 끝난 신청은 다시 처리할 수 없음
+        assert_eq!(next_status(Approved, LeaveAction::Approve), None);
+        assert_eq!(next_status(Approved, LeaveAction::Reject), None);
+        assert_eq!(next_status(Rejected, LeaveAction::Cancel), None);
+        assert_eq!(next_status(Cancelled, LeaveAction::Approve), None);
+    }
+
+    #[test]
+    fn counts_weekdays_and_half_days() {
+        // 2024-07-05(금) ~ 2024-07-09(화): 주말 제외 3일
+        assert_eq!(count_leave_days(date(2024, 7, 5), date(2024, 7, 9), None), 3.0);
+        assert_eq!(count_leave_days(date(2024, 7, 8), date(2024, 7, 8), Some(HalfDay::Am)), 0.5);
+        assert_eq!(count_leave_days(date(2024, 7, 6), date(2024, 7, 7), None), 0.0);
+        assert_eq!(count_leave_days(date(2024, 7, 6), date(2024, 7, 6), Some(HalfDay::Pm)), 0.0);
+    }
+
+    #[test]
+    fn accrues_by_rule_and_prorates_hire_year() {
+        let as_of = date(2024, 4, 15);
+
+        assert_eq!(accrued_days(LeaveAccrual::Yearly, 15.0, 2024, None, as_of), 15.0);
+        assert_eq!(accrued_days(LeaveAccrual::Yearly, 15.0, 2024, Some(date(2024, 7, 1)), as_of), 7.5);
+        assert_eq!(accrued_days(LeaveAccrual::Yearly, 15.0, 2023, Some(date(2024, 7, 1)), as_of), 0.0);
+
+        // 1~4월 적립, 지난 연도는 12개월, 다음 연도는 아직 없음
+        assert_eq!(accrued_days(LeaveAccrual::Monthly, 12.0, 2024, None, as_of), 4.0);
+        assert_eq!(accrued_days(LeaveAccrual::Monthly, 12.0, 2023, None, as_of), 12.0);
+        assert_eq!(accrued_days(LeaveAccrual::Monthly, 12.0, 2025, None, as_of), 0.0);
+        assert_eq!(accrued_days(LeaveAccrual::Monthly, 12.0, 2024, Some(date(2024, 3, 20)), as_of), 2.0);
+        assert_eq!(accrued_days(LeaveAccrual::Monthly, 15.0, 2024, None, as_of), 5.0);
+
+        assert_eq!(accrued_days(LeaveAccrual::None, 15.0, 2024, None, as_of), 0.0);
+    }
+}
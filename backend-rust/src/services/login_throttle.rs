@@ -0,0 +1,209 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::config::LoginThrottleConfig;
+
+const ACCOUNT_SCOPE: &str = "account";
+const IP_SCOPE: &str = "ip";
+
+// 실패 기록 시 만료된 카운터를 정리할 확률 (256분의 n)
+const PURGE_PROBABILITY: u8 = 3;
+
+// 잠금 시간 상한 (지수 증가가 무한히 커지지 않도록)
+const MAX_LOCKOUT_MINUTES: i64 = 24 * 60;
+
+// 로그인 차단 사유
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoginBlock {
+    // 실패 횟수 초과로 계정 잠금 (423)
+    AccountLocked { retry_after: i64 },
+    // 연속 실패 후 대기 시간이 지나지 않음 (429)
+    Backoff { retry_after: i64 },
+    // 같은 IP에서 실패가 너무 많음 (429)
+    IpLimited { retry_after: i64 },
+}
+
+#[derive(sqlx::FromRow)]
+struct Throttle {
+    failed_count: i32,
+    last_failed_at: DateTime<Utc>,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+pub struct LoginThrottleService {
+    pool: PgPool,
+    config: LoginThrottleConfig,
+}
+
+impl LoginThrottleService {
+    pub fn new(pool: PgPool, config: LoginThrottleConfig) -> Self {
+        Self { pool, config }
+    }
+
+    // 로그인 시도 전 차단 여부 확인 (비밀번호 검증 전에 호출)
+    pub async fn check(&self, email: &str, ip_address: Option<&str>) -> Result<Option<LoginBlock>> {
+        let now = Utc::now();
+
+        if let Some(ip) = ip_address {
+            if let Some(throttle) = self.find(IP_SCOPE, ip).await? {
+                if let Some(locked_until) = throttle.locked_until.filter(|until| *until > now) {
+                    return Ok(Some(LoginBlock::IpLimited { retry_after: seconds_until(now, locked_until) }));
+                }
+            }
+        }
+
+        let throttle = match self.find(ACCOUNT_SCOPE, &account_key(email)).await? {
+            Some(throttle) => throttle,
+            None => return Ok(None),
+        };
+
+        if let Some(locked_until) = throttle.locked_until.filter(|until| *until > now) {
+            return Ok(Some(LoginBlock::AccountLocked { retry_after: seconds_until(now, locked_until) }));
+        }
+
+        // 잠금 전 단계: 실패할 때마다 다음 시도까지의 대기 시간이 두 배로 증가
+        if throttle.failed_count > 0 && throttle.failed_count < self.config.max_failures {
+            let retry_at = throttle.last_failed_at + self.backoff(throttle.failed_count);
+            if retry_at > now {
+                return Ok(Some(LoginBlock::Backoff { retry_after: seconds_until(now, retry_at) }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    // 로그인 실패 기록, 이번 실패로 잠금이 걸리면 차단 사유 반환
+    pub async fn record_failure(&self, email: &str, ip_address: Option<&str>) -> Result<Option<LoginBlock>> {
+        let mut tx = self.pool.begin().await?;
+
+        if let Some(ip) = ip_address {
+            self.increment(&mut tx, IP_SCOPE, ip, self.config.ip_max_failures).await?;
+        }
+        let locked_until = self
+            .increment(&mut tx, ACCOUNT_SCOPE, &account_key(email), self.config.max_failures)
+            .await?;
+
+        tx.commit().await?;
+
+        // 존재하지 않는 이메일로 시도해도 행이 쌓이지 않도록 가끔 만료된 카운터 정리
+        if rand::random::<u8>() < PURGE_PROBABILITY {
+            self.purge_expired().await?;
+        }
+
+        Ok(locked_until.map(|until| LoginBlock::AccountLocked {
+            retry_after: seconds_until(Utc::now(), until),
+        }))
+    }
+
+    // 로그인 성공 시 계정 카운터 초기화 (IP 카운터는 유지)
+    pub async fn record_success(&self, email: &str) -> Result<()> {
+        self.unlock(email).await
+    }
+
+    // 계정 잠금 해제 (관리자)
+    pub async fn unlock(&self, email: &str) -> Result<()> {
+        sqlx::query("DELETE FROM login_throttles WHERE scope = $1 AND key = $2")
+            .bind(ACCOUNT_SCOPE)
+            .bind(account_key(email))
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // 집계 기간과 잠금이 모두 끝난 카운터 삭제
+    async fn purge_expired(&self) -> Result<()> {
+        sqlx::query(
+            r#"
+            DELETE FROM login_throttles
+            WHERE GREATEST(last_failed_at, locked_until) < NOW() - make_interval(mins => $1)
+            "#,
+        )
+        .bind(self.config.window_minutes as i32)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find(&self, scope: &str, key: &str) -> Result<Option<Throttle>> {
+        let throttle = sqlx::query_as::<_, Throttle>(
+            "SELECT failed_count, last_failed_at, locked_until FROM login_throttles WHERE scope = $1 AND key = $2",
+        )
+        .bind(scope)
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(throttle)
+    }
+
+    // 실패 횟수 증가 (행 잠금으로 여러 인스턴스의 동시 실패도 정확히 집계)
+    // 마지막 실패(또는 잠금 해제) 이후 집계 기간이 지나면 1부터 다시 센다
+    // 최대 실패 횟수에 도달하면 잠금 시간을 설정해 반환
+    async fn increment(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        scope: &str,
+        key: &str,
+        max_failures: i32,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let failed_count = sqlx::query_scalar::<_, i32>(
+            r#"
+            INSERT INTO login_throttles (scope, key, failed_count, last_failed_at)
+            VALUES ($1, $2, 1, NOW())
+            ON CONFLICT (scope, key) DO UPDATE SET
+                failed_count = CASE
+                    WHEN GREATEST(login_throttles.last_failed_at, login_throttles.locked_until)
+                         < NOW() - make_interval(mins => $3) THEN 1
+                    ELSE login_throttles.failed_count + 1
+                END,
+                last_failed_at = NOW()
+            RETURNING failed_count
+            "#,
+        )
+        .bind(scope)
+        .bind(key)
+        .bind(self.config.window_minutes as i32)
+        .fetch_one(&mut **tx)
+        .await?;
+
+        if failed_count < max_failures {
+            return Ok(None);
+        }
+
+        // 잠금 이후에도 계속 실패하면 잠금 시간이 두 배씩 증가
+        let exponent = (failed_count - max_failures).min(16) as u32;
+        let minutes = self
+            .config
+            .lockout_minutes
+            .saturating_mul(2_i64.pow(exponent))
+            .min(MAX_LOCKOUT_MINUTES);
+        let locked_until = Utc::now() + Duration::minutes(minutes);
+
+        sqlx::query("UPDATE login_throttles SET locked_until = $1 WHERE scope = $2 AND key = $3")
+            .bind(locked_until)
+            .bind(scope)
+            .bind(key)
+            .execute(&mut **tx)
+            .await?;
+
+        tracing::warn!("로그인 실패 횟수 초과로 잠금: {} {} ({}분)", scope, key, minutes);
+        Ok(Some(locked_until))
+    }
+
+    // n번 연속 실패 후 대기 시간 (기본 1초 → 2초 → 4초 ...)
+    fn backoff(&self, failed_count: i32) -> Duration {
+        let exponent = (failed_count - 1).clamp(0, 16) as u32;
+        Duration::seconds(self.config.backoff_base_seconds.saturating_mul(2_i64.pow(exponent)))
+    }
+}
+
+fn account_key(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+fn seconds_until(now: DateTime<Utc>, until: DateTime<Utc>) -> i64 {
+    (until - now).num_seconds().max(1)
+}
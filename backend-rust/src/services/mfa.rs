@@ -0,0 +1,383 @@
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{Duration, Utc};
+use rand::{rngs::OsRng, Rng, RngCore};
+use sha2::{Digest, Sha256};
+use sqlx::{PgConnection, PgPool};
+use totp_rs::{Algorithm, TOTP};
+
+use super::tokens::{generate_token, hash_token};
+use crate::{config::Config, models::User};
+
+// 발급하는 복구 코드 수
+const RECOVERY_CODE_COUNT: usize = 10;
+
+// 2단계 로그인 토큰 하나로 시도할 수 있는 최대 횟수
+const MAX_CHALLENGE_ATTEMPTS: i32 = 5;
+
+// 혼동하기 쉬운 문자(0/o, 1/l/i)를 뺀 복구 코드 문자
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+// TOTP 설정 (RFC 6238 권장값: SHA-1, 6자리, 30초, 앞뒤 1단계 허용)
+const TOTP_DIGITS: usize = 6;
+const TOTP_STEP: u64 = 30;
+const TOTP_SKEW: u64 = 1;
+
+// 2단계 인증 코드 (인증 앱의 TOTP 또는 복구 코드)
+#[derive(Debug, Clone, Copy)]
+pub enum MfaCode<'a> {
+    Totp(&'a str),
+    Recovery(&'a str),
+}
+
+// 2단계 로그인 검증 결과
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChallengeOutcome {
+    Verified(i32),
+    InvalidCode(i32),
+    InvalidChallenge,
+}
+
+// 등록 시작 결과 (인증 앱에 입력할 비밀 키와 QR 코드용 otpauth URI)
+pub struct MfaEnrollment {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+pub struct MfaService {
+    pool: PgPool,
+    config: Config,
+}
+
+impl MfaService {
+    pub fn new(pool: PgPool, config: Config) -> Self {
+        Self { pool, config }
+    }
+
+    // 2단계 인증 등록 완료 여부
+    pub async fn is_enabled(&self, user_id: i32) -> Result<bool> {
+        let enabled = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS (SELECT 1 FROM user_mfa WHERE user_id = $1 AND confirmed_at IS NOT NULL)",
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(enabled)
+    }
+
+    // 등록 시작: 새 비밀 키 생성 (확인 전 상태로 저장, 이미 등록된 경우 None)
+    // 확인 전에 다시 호출하면 비밀 키를 새로 발급
+    pub async fn start_enrollment(&self, user: &User) -> Result<Option<MfaEnrollment>> {
+        let mut secret = vec![0u8; 20];
+        OsRng.fill_bytes(&mut secret);
+        let totp = self.totp(secret.clone(), &user.email)?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO user_mfa (user_id, secret)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE SET secret = EXCLUDED.secret, last_used_step = 0, created_at = NOW()
+            WHERE user_mfa.confirmed_at IS NULL
+            "#,
+        )
+        .bind(user.id)
+        .bind(self.encrypt(&secret)?)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(MfaEnrollment {
+            secret: totp.get_secret_base32(),
+            otpauth_uri: totp.get_url(),
+        }))
+    }
+
+    // 등록 확인: 인증 앱의 코드가 맞으면 활성화하고 복구 코드 발급
+    // 확인 대기 중인 등록이 없거나 코드가 틀리면 None
+    pub async fn confirm(&self, user: &User, code: &str) -> Result<Option<Vec<String>>> {
+        let mut tx = self.pool.begin().await?;
+
+        let pending = sqlx::query_as::<_, (String, i64)>(
+            "SELECT secret, last_used_step FROM user_mfa WHERE user_id = $1 AND confirmed_at IS NULL FOR UPDATE",
+        )
+        .bind(user.id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let (secret, last_used_step) = match pending {
+            Some(pending) => pending,
+            None => return Ok(None),
+        };
+
+        let step = match self.matching_step(&secret, &user.email, code, last_used_step)? {
+            Some(step) => step,
+            None => return Ok(None),
+        };
+
+        sqlx::query("UPDATE user_mfa SET confirmed_at = NOW(), last_used_step = $1 WHERE user_id = $2")
+            .bind(step)
+            .bind(user.id)
+            .execute(&mut *tx)
+            .await?;
+
+        let recovery_codes = replace_recovery_codes(&mut tx, user.id).await?;
+        tx.commit().await?;
+
+        tracing::info!("2단계 인증 등록 완료: {}", user.email);
+        Ok(Some(recovery_codes))
+    }
+
+    // 등록된 2단계 인증 코드 검증 (TOTP는 재사용 불가, 복구 코드는 1회용)
+    pub async fn verify(&self, user: &User, code: MfaCode<'_>) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let verified = self.verify_in(&mut tx, user.id, &user.email, code).await?;
+        tx.commit().await?;
+        Ok(verified)
+    }
+
+    // 복구 코드 재발급 (기존 코드는 모두 무효화)
+    pub async fn regenerate_recovery_codes(&self, user_id: i32) -> Result<Vec<String>> {
+        let mut tx = self.pool.begin().await?;
+        let recovery_codes = replace_recovery_codes(&mut tx, user_id).await?;
+        tx.commit().await?;
+        Ok(recovery_codes)
+    }
+
+    // 2단계 인증 해제
+    pub async fn disable(&self, user_id: i32) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM mfa_recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM user_mfa WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("2단계 인증 해제: 사용자 ID {}", user_id);
+        Ok(())
+    }
+
+    // 비밀번호 확인 후 2단계 로그인 토큰 발급
+    pub async fn create_challenge(&self, user_id: i32) -> Result<String> {
+        let token = generate_token();
+        let expires_at = Utc::now() + Duration::minutes(self.config.mfa_challenge_expires_minutes);
+
+        sqlx::query(
+            r#"
+            INSERT INTO mfa_challenges (user_id, token_hash, expires_at)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(user_id)
+        .bind(hash_token(&token))
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(token)
+    }
+
+    // 2단계 로그인 토큰과 코드 검증
+    // 만료/사용되었거나 시도 횟수를 초과한 토큰이면 InvalidChallenge
+    pub async fn verify_challenge(&self, token: &str, code: MfaCode<'_>) -> Result<ChallengeOutcome> {
+        let mut tx = self.pool.begin().await?;
+
+        let challenge = sqlx::query_as::<_, (i64, i32, String)>(
+            r#"
+            SELECT c.id, c.user_id, u.email
+            FROM mfa_challenges c
+            JOIN users u ON u.id = c.user_id
+            WHERE c.token_hash = $1 AND c.used_at IS NULL AND c.expires_at > NOW() AND c.attempts < $2
+            FOR UPDATE OF c
+            "#,
+        )
+        .bind(hash_token(token))
+        .bind(MAX_CHALLENGE_ATTEMPTS)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let (challenge_id, user_id, email) = match challenge {
+            Some(challenge) => challenge,
+            None => return Ok(ChallengeOutcome::InvalidChallenge),
+        };
+
+        let verified = self.verify_in(&mut tx, user_id, &email, code).await?;
+        let update = if verified {
+            "UPDATE mfa_challenges SET used_at = NOW(), attempts = attempts + 1 WHERE id = $1"
+        } else {
+            "UPDATE mfa_challenges SET attempts = attempts + 1 WHERE id = $1"
+        };
+        sqlx::query(update)
+            .bind(challenge_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(if verified {
+            ChallengeOutcome::Verified(user_id)
+        } else {
+            ChallengeOutcome::InvalidCode(user_id)
+        })
+    }
+
+    async fn verify_in(&self, conn: &mut PgConnection, user_id: i32, email: &str, code: MfaCode<'_>) -> Result<bool> {
+        match code {
+            MfaCode::Totp(code) => {
+                let enrolled = sqlx::query_as::<_, (String, i64)>(
+                    "SELECT secret, last_used_step FROM user_mfa WHERE user_id = $1 AND confirmed_at IS NOT NULL FOR UPDATE",
+                )
+                .bind(user_id)
+                .fetch_optional(&mut *conn)
+                .await?;
+
+                let (secret, last_used_step) = match enrolled {
+                    Some(enrolled) => enrolled,
+                    None => return Ok(false),
+                };
+
+                match self.matching_step(&secret, email, code, last_used_step)? {
+                    Some(step) => {
+                        sqlx::query("UPDATE user_mfa SET last_used_step = $1 WHERE user_id = $2")
+                            .bind(step)
+                            .bind(user_id)
+                            .execute(&mut *conn)
+                            .await?;
+                        Ok(true)
+                    }
+                    None => Ok(false),
+                }
+            }
+            MfaCode::Recovery(code) => {
+                let result = sqlx::query(
+                    r#"
+                    UPDATE mfa_recovery_codes SET used_at = NOW()
+                    WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL
+                    "#,
+                )
+                .bind(user_id)
+                .bind(hash_token(&normalize_recovery_code(code)))
+                .execute(&mut *conn)
+                .await?;
+
+                Ok(result.rows_affected() > 0)
+            }
+        }
+    }
+
+    // 코드가 일치하는 시간 단계 반환 (이미 사용된 단계 이하의 코드는 재사용으로 거부)
+    fn matching_step(&self, encrypted_secret: &str, email: &str, code: &str, last_used_step: i64) -> Result<Option<i64>> {
+        let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+        let totp = self.totp(self.decrypt(encrypted_secret)?, email)?;
+        let current_step = Utc::now().timestamp() as u64 / TOTP_STEP;
+
+        for step in current_step.saturating_sub(TOTP_SKEW)..=current_step + TOTP_SKEW {
+            if step as i64 > last_used_step && totp.generate(step * TOTP_STEP) == code {
+                return Ok(Some(step as i64));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn totp(&self, secret: Vec<u8>, email: &str) -> Result<TOTP> {
+        let totp = TOTP::new(
+            Algorithm::SHA1,
+            TOTP_DIGITS,
+            TOTP_SKEW as u8,
+            TOTP_STEP,
+            secret,
+            Some(self.config.mfa_issuer.clone()),
+            email.to_string(),
+        )?;
+
+        Ok(totp)
+    }
+
+    // 비밀 키 암호화 (AES-256-GCM, base64(nonce || ciphertext))
+    fn encrypt(&self, secret: &[u8]) -> Result<String> {
+        let mut nonce = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce);
+
+        let ciphertext = self
+            .cipher()
+            .encrypt(Nonce::from_slice(&nonce), secret)
+            .map_err(|_| anyhow!("2단계 인증 비밀 키 암호화 실패"))?;
+
+        Ok(STANDARD.encode([nonce.as_slice(), &ciphertext].concat()))
+    }
+
+    fn decrypt(&self, encrypted: &str) -> Result<Vec<u8>> {
+        let bytes = STANDARD.decode(encrypted)?;
+        if bytes.len() < 12 {
+            return Err(anyhow!("잘못된 2단계 인증 비밀 키 형식"));
+        }
+
+        let (nonce, ciphertext) = bytes.split_at(12);
+        self.cipher()
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!("2단계 인증 비밀 키 복호화 실패 (MFA_ENCRYPTION_KEY 확인 필요)"))
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        let key = Sha256::digest(self.config.mfa_encryption_key.as_bytes());
+        Aes256Gcm::new(&key)
+    }
+}
+
+// 복구 코드 전체 교체 (원문은 이번 응답에서만 보여주고 해시만 저장)
+async fn replace_recovery_codes(conn: &mut PgConnection, user_id: i32) -> Result<Vec<String>> {
+    sqlx::query("DELETE FROM mfa_recovery_codes WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&mut *conn)
+        .await?;
+
+    let codes: Vec<String> = (0..RECOVERY_CODE_COUNT).map(|_| generate_recovery_code()).collect();
+    let hashes: Vec<String> = codes
+        .iter()
+        .map(|code| hash_token(&normalize_recovery_code(code)))
+        .collect();
+
+    sqlx::query(
+        r#"
+        INSERT INTO mfa_recovery_codes (user_id, code_hash)
+        SELECT $1, UNNEST($2::text[])
+        "#,
+    )
+    .bind(user_id)
+    .bind(&hashes)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(codes)
+}
+
+// 복구 코드 생성 (예: k7m2p-x9qrt)
+fn generate_recovery_code() -> String {
+    let chars: String = (0..10)
+        .map(|_| RECOVERY_CODE_ALPHABET[OsRng.gen_range(0..RECOVERY_CODE_ALPHABET.len())] as char)
+        .collect();
+    format!("{}-{}", &chars[..5], &chars[5..])
+}
+
+// 입력 편의를 위해 대소문자, 하이픈, 공백 무시
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
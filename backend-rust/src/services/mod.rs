@@ -0,0 +1,37 @@
+mod announcements;
+mod audit;
+mod avatars;
+mod bookings;
+mod calendar;
+mod departments;
+mod documents;
+mod email_verification;
+mod leaves;
+mod login_throttle;
+mod mfa;
+mod password_reset;
+mod profiles;
+mod roles;
+mod search;
+mod sessions;
+mod tokens;
+mod users;
+
+pub use announcements::AnnouncementService;
+pub use audit::{user_changes, AuditService};
+pub use avatars::{AvatarError, AvatarService};
+pub use bookings::{spawn_release_task, BookingError, BookingService};
+pub use calendar::CalendarService;
+pub use departments::DepartmentService;
+pub use documents::{content_disposition, parse_range, ByteRange, DocumentError, DocumentService, DocumentUpload, RangeRequest};
+pub use email_verification::EmailVerificationService;
+pub use leaves::{LeaveError, LeaveService};
+pub use login_throttle::{LoginBlock, LoginThrottleService};
+pub use mfa::{ChallengeOutcome, MfaCode, MfaService};
+pub use password_reset::PasswordResetService;
+pub use profiles::ProfileService;
+pub use roles::RoleService;
+pub use search::{highlight_user, SearchService};
+pub use sessions::{SessionCache, SessionService};
+pub use tokens::{IssuedTokens, TokenService};
+pub use users::{spawn_purge_task, UserService};
@@ -0,0 +1,100 @@
+use anyhow::Result;
+use bcrypt::{hash, DEFAULT_COST};
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+
+use super::tokens::{generate_token, hash_token};
+use crate::models::User;
+
+pub struct PasswordResetService {
+    pool: PgPool,
+    expires_minutes: i64,
+}
+
+impl PasswordResetService {
+    pub fn new(pool: PgPool, expires_minutes: i64) -> Self {
+        Self { pool, expires_minutes }
+    }
+
+    // 재설정 토큰 발급 (이전에 발급된 미사용 토큰은 무효화)
+    // 토큰 원문은 메일로만 전달되고 데이터베이스에는 해시만 저장
+    pub async fn create_token(&self, user_id: i32) -> Result<String> {
+        let token = generate_token();
+        let expires_at = Utc::now() + Duration::minutes(self.expires_minutes);
+
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("UPDATE password_reset_tokens SET used_at = NOW() WHERE user_id = $1 AND used_at IS NULL")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO password_reset_tokens (user_id, token_hash, expires_at)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(user_id)
+        .bind(hash_token(&token))
+        .bind(expires_at)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(token)
+    }
+
+    // 토큰을 사용해 비밀번호 변경
+    // 유효하지 않거나 만료/사용된 토큰, 비활성화된 사용자이면 None 반환
+    pub async fn reset_password(&self, token: &str, new_password: &str) -> Result<Option<User>> {
+        let mut tx = self.pool.begin().await?;
+
+        // 같은 토큰으로 동시에 재설정하지 못하도록 행 잠금
+        let user_id = sqlx::query_scalar::<_, i32>(
+            r#"
+            SELECT user_id
+            FROM password_reset_tokens
+            WHERE token_hash = $1 AND used_at IS NULL AND expires_at > NOW()
+            FOR UPDATE
+            "#,
+        )
+        .bind(hash_token(token))
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let user_id = match user_id {
+            Some(user_id) => user_id,
+            None => return Ok(None),
+        };
+
+        let hashed_password = hash(new_password, DEFAULT_COST)?;
+        let user = sqlx::query_as::<_, User>(
+            r#"
+            UPDATE users SET password = $1, updated_at = NOW()
+            WHERE id = $2 AND is_active = true AND deleted_at IS NULL
+            RETURNING id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash
+            "#,
+        )
+        .bind(&hashed_password)
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let user = match user {
+            Some(user) => user,
+            None => return Ok(None),
+        };
+
+        // 사용한 토큰과 함께 발급된 다른 미사용 토큰도 모두 소진
+        sqlx::query("UPDATE password_reset_tokens SET used_at = NOW() WHERE user_id = $1 AND used_at IS NULL")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("비밀번호 재설정 완료: {}", user.email);
+        Ok(Some(user))
+    }
+}
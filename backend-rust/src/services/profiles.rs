@@ -0,0 +1,142 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{ProfileVisibility, UpdateProfileRequest, UserProfile};
+
+pub struct ProfileService {
+    pool: PgPool,
+}
+
+impl ProfileService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 사용자 프로필 조회 (작성하지 않았으면 None)
+    pub async fn find(&self, user_id: i32) -> Result<Option<UserProfile>> {
+        let profile = sqlx::query_as::<_, UserProfile>(
+            r#"
+            SELECT user_id, phone, extension, office_location, job_title, hire_date, birthday,
+                   bio, skills, visibility
+            FROM user_profiles
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(profile)
+    }
+
+    // 여러 사용자의 프로필을 한 번에 조회 (목록 응답용)
+    pub async fn find_many(&self, user_ids: &[i32]) -> Result<Vec<UserProfile>> {
+        let profiles = sqlx::query_as::<_, UserProfile>(
+            r#"
+            SELECT user_id, phone, extension, office_location, job_title, hire_date, birthday,
+                   bio, skills, visibility
+            FROM user_profiles
+            WHERE user_id = ANY($1)
+            "#,
+        )
+        .bind(user_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(profiles)
+    }
+
+    // 프로필 저장 (없으면 생성, 있으면 전체 교체)
+    // 기술 태그는 앞뒤 공백을 제거하고 대소문자 구분 없이 중복을 제거 (처음 나온 표기 유지)
+    pub async fn upsert(&self, user_id: i32, request: &UpdateProfileRequest) -> Result<UserProfile> {
+        let mut seen = HashSet::new();
+        let skills: Vec<String> = request
+            .skills
+            .iter()
+            .map(|skill| skill.trim().to_string())
+            .filter(|skill| seen.insert(skill.to_lowercase()))
+            .collect();
+
+        let profile = sqlx::query_as::<_, UserProfile>(
+            r#"
+            INSERT INTO user_profiles
+                (user_id, phone, extension, office_location, job_title, hire_date, birthday, bio, skills, visibility)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (user_id) DO UPDATE
+            SET phone = EXCLUDED.phone,
+                extension = EXCLUDED.extension,
+                office_location = EXCLUDED.office_location,
+                job_title = EXCLUDED.job_title,
+                hire_date = EXCLUDED.hire_date,
+                birthday = EXCLUDED.birthday,
+                bio = EXCLUDED.bio,
+                skills = EXCLUDED.skills,
+                visibility = EXCLUDED.visibility,
+                updated_at = NOW()
+            RETURNING user_id, phone, extension, office_location, job_title, hire_date, birthday,
+                      bio, skills, visibility
+            "#,
+        )
+        .bind(user_id)
+        .bind(&request.phone)
+        .bind(&request.extension)
+        .bind(&request.office_location)
+        .bind(&request.job_title)
+        .bind(request.hire_date)
+        .bind(request.birthday)
+        .bind(&request.bio)
+        .bind(&skills)
+        .bind(sqlx::types::Json(&request.visibility))
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("프로필 저장 완료: 사용자 ID {}", user_id);
+        Ok(profile)
+    }
+
+    // 조회자가 각 사용자의 프로필을 볼 수 있는 수준
+    // 본인이나 관리자(viewer_is_admin)는 Admin, 같은 부서나 직속 상사/부하는 Colleagues, 그 외는 Public
+    pub async fn access_levels(
+        &self,
+        viewer_id: i32,
+        viewer_is_admin: bool,
+        user_ids: &[i32],
+    ) -> Result<HashMap<i32, ProfileVisibility>> {
+        let colleagues: HashSet<i32> = if viewer_is_admin {
+            HashSet::new()
+        } else {
+            sqlx::query_scalar::<_, i32>(
+                r#"
+                SELECT target.id
+                FROM UNNEST($2::INTEGER[]) AS target(id)
+                LEFT JOIN department_members tm ON tm.user_id = target.id
+                LEFT JOIN department_members vm ON vm.user_id = $1
+                WHERE tm.department_id = vm.department_id
+                   OR tm.manager_id = $1
+                   OR vm.manager_id = target.id
+                "#,
+            )
+            .bind(viewer_id)
+            .bind(user_ids)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .collect()
+        };
+
+        Ok(user_ids
+            .iter()
+            .map(|&user_id| {
+                let access = if viewer_is_admin || user_id == viewer_id {
+                    ProfileVisibility::Admin
+                } else if colleagues.contains(&user_id) {
+                    ProfileVisibility::Colleagues
+                } else {
+                    ProfileVisibility::Public
+                };
+                (user_id, access)
+            })
+            .collect())
+    }
+}
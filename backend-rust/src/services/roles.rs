@@ -0,0 +1,344 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::{CreateRoleRequest, Permission, Role, UpdateRoleRequest};
+
+pub struct RoleService {
+    pool: PgPool,
+}
+
+impl RoleService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 사용자의 유효 권한 조회
+    // users.role(기본 역할)과 user_roles(추가 역할)에 부여된 권한의 합집합
+    // 2단계 인증이 필요한 역할의 권한은 2단계 인증을 등록한 경우에만 포함
+    pub async fn permissions_for_user(&self, user_id: i32) -> Result<Vec<String>> {
+        let permissions = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT DISTINCT p.code
+            FROM permissions p
+            JOIN role_permissions rp ON rp.permission_id = p.id
+            JOIN roles r ON r.id = rp.role_id
+            WHERE (r.name = (SELECT role FROM users WHERE id = $1)
+                   OR r.id IN (SELECT role_id FROM user_roles WHERE user_id = $1))
+              AND (NOT r.require_mfa
+                   OR EXISTS (SELECT 1 FROM user_mfa WHERE user_id = $1 AND confirmed_at IS NOT NULL))
+            ORDER BY p.code
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(permissions)
+    }
+
+    // 사용자가 특정 권한을 가지고 있는지 확인
+    pub async fn has_permission(&self, user_id: i32, permission: &str) -> Result<bool> {
+        let granted = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM permissions p
+                JOIN role_permissions rp ON rp.permission_id = p.id
+                JOIN roles r ON r.id = rp.role_id
+                WHERE p.code = $2
+                  AND (r.name = (SELECT role FROM users WHERE id = $1)
+                       OR r.id IN (SELECT role_id FROM user_roles WHERE user_id = $1))
+                  AND (NOT r.require_mfa
+                       OR EXISTS (SELECT 1 FROM user_mfa WHERE user_id = $1 AND confirmed_at IS NOT NULL))
+            )
+            "#,
+        )
+        .bind(user_id)
+        .bind(permission)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(granted)
+    }
+
+    // 2단계 인증을 등록하면 얻을 수 있는 권한인지 확인 (권한 거부 사유 안내용)
+    pub async fn permission_requires_mfa(&self, user_id: i32, permission: &str) -> Result<bool> {
+        let requires = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM permissions p
+                JOIN role_permissions rp ON rp.permission_id = p.id
+                JOIN roles r ON r.id = rp.role_id
+                WHERE p.code = $2
+                  AND r.require_mfa
+                  AND (r.name = (SELECT role FROM users WHERE id = $1)
+                       OR r.id IN (SELECT role_id FROM user_roles WHERE user_id = $1))
+            )
+            "#,
+        )
+        .bind(user_id)
+        .bind(permission)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(requires)
+    }
+
+    // 사용자의 역할 중 2단계 인증이 필요한 역할이 있는지 확인
+    pub async fn user_requires_mfa(&self, user_id: i32) -> Result<bool> {
+        let requires = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM roles r
+                WHERE r.require_mfa
+                  AND (r.name = (SELECT role FROM users WHERE id = $1)
+                       OR r.id IN (SELECT role_id FROM user_roles WHERE user_id = $1))
+            )
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(requires)
+    }
+
+    // 모든 역할 조회 (권한 코드 포함)
+    pub async fn find_all(&self) -> Result<Vec<(Role, Vec<String>)>> {
+        let roles = sqlx::query_as::<_, Role>(
+            "SELECT id, name, description, is_system, require_mfa, created_at, updated_at FROM roles ORDER BY id"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut result = Vec::with_capacity(roles.len());
+        for role in roles {
+            let permissions = self.role_permissions(role.id).await?;
+            result.push((role, permissions));
+        }
+
+        Ok(result)
+    }
+
+    // ID로 역할 조회
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<(Role, Vec<String>)>> {
+        let role = sqlx::query_as::<_, Role>(
+            "SELECT id, name, description, is_system, require_mfa, created_at, updated_at FROM roles WHERE id = $1"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match role {
+            Some(role) => {
+                let permissions = self.role_permissions(role.id).await?;
+                Ok(Some((role, permissions)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    // 역할 이름 존재 여부 확인
+    pub async fn role_exists(&self, name: &str) -> Result<bool> {
+        let exists = sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM roles WHERE name = $1)")
+            .bind(name)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(exists)
+    }
+
+    // 존재하지 않는 역할 이름 목록
+    pub async fn unknown_roles(&self, names: &[String]) -> Result<Vec<String>> {
+        let unknown = sqlx::query_scalar::<_, String>(
+            "SELECT name FROM UNNEST($1::text[]) AS name WHERE name NOT IN (SELECT name FROM roles)"
+        )
+        .bind(names)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(unknown)
+    }
+
+    // 존재하지 않는 권한 코드 목록
+    pub async fn unknown_permissions(&self, codes: &[String]) -> Result<Vec<String>> {
+        let unknown = sqlx::query_scalar::<_, String>(
+            "SELECT code FROM UNNEST($1::text[]) AS code WHERE code NOT IN (SELECT code FROM permissions)"
+        )
+        .bind(codes)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(unknown)
+    }
+
+    // 역할 생성
+    pub async fn create_role(&self, request: CreateRoleRequest) -> Result<(Role, Vec<String>)> {
+        let mut tx = self.pool.begin().await?;
+
+        let role = sqlx::query_as::<_, Role>(
+            r#"
+            INSERT INTO roles (name, description, require_mfa)
+            VALUES ($1, $2, $3)
+            RETURNING id, name, description, is_system, require_mfa, created_at, updated_at
+            "#,
+        )
+        .bind(&request.name)
+        .bind(&request.description)
+        .bind(request.require_mfa)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        replace_role_permissions(&mut tx, role.id, &request.permissions).await?;
+        tx.commit().await?;
+
+        tracing::info!("역할 생성 완료: {}", role.name);
+        let permissions = self.role_permissions(role.id).await?;
+        Ok((role, permissions))
+    }
+
+    // 역할 수정 (권한 목록을 보내면 전체 교체)
+    pub async fn update_role(&self, id: i32, request: UpdateRoleRequest) -> Result<Option<(Role, Vec<String>)>> {
+        let mut tx = self.pool.begin().await?;
+
+        let role = sqlx::query_as::<_, Role>(
+            r#"
+            UPDATE roles
+            SET description = COALESCE($1, description),
+                require_mfa = COALESCE($2, require_mfa),
+                updated_at = NOW()
+            WHERE id = $3
+            RETURNING id, name, description, is_system, require_mfa, created_at, updated_at
+            "#,
+        )
+        .bind(&request.description)
+        .bind(request.require_mfa)
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let role = match role {
+            Some(role) => role,
+            None => return Ok(None),
+        };
+
+        if let Some(permissions) = &request.permissions {
+            replace_role_permissions(&mut tx, role.id, permissions).await?;
+        }
+        tx.commit().await?;
+
+        tracing::info!("역할 수정 완료: {}", role.name);
+        let permissions = self.role_permissions(role.id).await?;
+        Ok(Some((role, permissions)))
+    }
+
+    // 역할 삭제 (기본 역할은 삭제 불가, 호출 전 확인 필요)
+    pub async fn delete_role(&self, id: i32) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM roles WHERE id = $1 AND is_system = false")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        let deleted = result.rows_affected() > 0;
+
+        if deleted {
+            tracing::info!("역할 삭제 완료: ID {}", id);
+        }
+
+        Ok(deleted)
+    }
+
+    // 모든 권한 조회
+    pub async fn find_all_permissions(&self) -> Result<Vec<Permission>> {
+        let permissions = sqlx::query_as::<_, Permission>(
+            "SELECT id, code, description FROM permissions ORDER BY code"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(permissions)
+    }
+
+    // 사용자에게 추가로 부여된 역할 조회
+    pub async fn user_roles(&self, user_id: i32) -> Result<Vec<String>> {
+        let roles = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT r.name
+            FROM user_roles ur
+            JOIN roles r ON r.id = ur.role_id
+            WHERE ur.user_id = $1
+            ORDER BY r.name
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(roles)
+    }
+
+    // 사용자의 추가 역할 전체 교체
+    pub async fn set_user_roles(&self, user_id: i32, roles: &[String]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM user_roles WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO user_roles (user_id, role_id)
+            SELECT $1, id FROM roles WHERE name = ANY($2)
+            "#,
+        )
+        .bind(user_id)
+        .bind(roles)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("사용자 역할 변경 완료: ID {} → {:?}", user_id, roles);
+        Ok(())
+    }
+
+    async fn role_permissions(&self, role_id: i32) -> Result<Vec<String>> {
+        let permissions = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT p.code
+            FROM role_permissions rp
+            JOIN permissions p ON p.id = rp.permission_id
+            WHERE rp.role_id = $1
+            ORDER BY p.code
+            "#,
+        )
+        .bind(role_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(permissions)
+    }
+}
+
+// 역할의 권한 목록 전체 교체
+async fn replace_role_permissions(conn: &mut PgConnection, role_id: i32, permissions: &[String]) -> Result<()> {
+    sqlx::query("DELETE FROM role_permissions WHERE role_id = $1")
+        .bind(role_id)
+        .execute(&mut *conn)
+        .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO role_permissions (role_id, permission_id)
+        SELECT $1, id FROM permissions WHERE code = ANY($2)
+        "#,
+    )
+    .bind(role_id)
+    .bind(permissions)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
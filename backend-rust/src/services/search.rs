@@ -0,0 +1,309 @@
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+use super::users::escape_like;
+use crate::error::AppResult;
+use crate::models::{User, UserSearchHighlight, UserSearchQuery};
+
+// 전문 검색 문서 식 (migrations/0015의 idx_users_search_document와 같아야 인덱스를 사용함)
+const SEARCH_DOCUMENT: &str = "to_tsvector('simple', name || ' ' || email || ' ' || COALESCE(role, ''))";
+
+// 한글 음절 = 0xAC00 + (초성 * 21 + 중성) * 28 + 종성
+const SYLLABLE_FIRST: u32 = 0xAC00;
+const SYLLABLE_LAST: u32 = 0xD7A3;
+const SYLLABLES_PER_INITIAL: u32 = 21 * 28;
+const FINALS: u32 = 28;
+
+// 초성 순서의 호환용 자모 (키보드로 입력되는 "ㄱ", "ㅎ" 등)
+const INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+#[derive(FromRow)]
+struct ScoredUser {
+    #[sqlx(flatten)]
+    user: User,
+    score: f32,
+}
+
+pub struct SearchService {
+    pool: PgPool,
+}
+
+impl SearchService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 사용자 검색 (삭제된 사용자 제외)
+    // 점수 순으로 정렬한 한 페이지와 조건에 맞는 전체 사용자 수 반환
+    pub async fn search_users(&self, query: &UserSearchQuery) -> AppResult<(Vec<(User, f32)>, i64)> {
+        let terms = SearchTerms::parse(&query.q);
+
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM users");
+        push_match(&mut count_query, &terms);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut list_query = QueryBuilder::<Postgres>::new(
+            "SELECT id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash, ",
+        );
+        push_score(&mut list_query, &terms);
+        list_query.push(" AS score FROM users");
+        push_match(&mut list_query, &terms);
+        list_query.push(" ORDER BY score DESC, name, id");
+        list_query.push(" LIMIT ").push_bind(query.per_page);
+        list_query.push(" OFFSET ").push_bind((query.page - 1) * query.per_page);
+
+        let users = list_query
+            .build_query_as::<ScoredUser>()
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|row| (row.user, row.score))
+            .collect();
+
+        Ok((users, total))
+    }
+}
+
+// 검색 결과 강조 표시 (이름, 이메일, 역할 중 검색어와 일치한 부분)
+pub fn highlight_user(user: &User, q: &str) -> UserSearchHighlight {
+    let terms = SearchTerms::parse(q).words;
+    UserSearchHighlight {
+        name: highlight(&user.name, &terms),
+        email: highlight(&user.email, &terms),
+        role: highlight(&user.role, &terms),
+    }
+}
+
+// 검색어를 SQL 조건에 쓸 형태로 정리한 값
+struct SearchTerms {
+    // 소문자로 바꾸고 공백을 하나로 줄인 전체 검색어
+    text: String,
+
+    // 공백으로 나눈 단어 (모든 단어가 이름/이메일/역할 중 하나와 일치해야 함)
+    words: Vec<String>,
+
+    // 전문 검색 접두어 쿼리 ("kim:* & admin:*"), 쓸 수 있는 단어가 없으면 None
+    tsquery: Option<String>,
+}
+
+impl SearchTerms {
+    fn parse(q: &str) -> Self {
+        let words: Vec<String> = q.split_whitespace().map(str::to_lowercase).collect();
+
+        // 초성 자모가 들어간 단어는 색인된 단어와 일치할 수 없으므로 제외
+        let lexemes: Vec<String> = words
+            .iter()
+            .flat_map(|word| word.split(|c: char| !c.is_alphanumeric()))
+            .filter(|lexeme| !lexeme.is_empty() && !lexeme.chars().any(|c| initial_index(c).is_some()))
+            .map(|lexeme| format!("'{}':*", lexeme))
+            .collect();
+
+        Self {
+            text: words.join(" "),
+            tsquery: (!lexemes.is_empty()).then(|| lexemes.join(" & ")),
+            words,
+        }
+    }
+}
+
+// 검색 조건 추가
+// 단어마다 이름(초성 포함 정규식), 이메일(부분 일치), 역할(앞부분 일치) 중 하나와 일치하거나
+// 전문 검색 또는 이름 유사도(트라이그램)로 일치하는 사용자
+fn push_match(builder: &mut QueryBuilder<Postgres>, terms: &SearchTerms) {
+    builder.push(" WHERE deleted_at IS NULL AND ((TRUE");
+    for word in &terms.words {
+        builder
+            .push(" AND (name ~* ")
+            .push_bind(name_pattern(word))
+            .push(" OR email ILIKE ")
+            .push_bind(format!("%{}%", escape_like(word)))
+            .push(" OR role ILIKE ")
+            .push_bind(format!("{}%", escape_like(word)))
+            .push(")");
+    }
+    builder.push(") OR name % ").push_bind(terms.text.clone());
+    if let Some(tsquery) = &terms.tsquery {
+        builder
+            .push(" OR ")
+            .push(SEARCH_DOCUMENT)
+            .push(" @@ ")
+            .push_bind(tsquery.clone())
+            .push("::tsquery");
+    }
+    builder.push(")");
+}
+
+// 관련도 점수 식 추가
+// 이름 정확히 일치 > 이름 앞부분 일치 > 이메일 앞부분 일치 > 이름 부분 일치 순으로 가산하고
+// 이름 유사도와 전문 검색 순위를 더함
+fn push_score(builder: &mut QueryBuilder<Postgres>, terms: &SearchTerms) {
+    let pattern = name_pattern(&terms.text);
+    builder
+        .push("(CASE WHEN lower(name) = ")
+        .push_bind(terms.text.clone())
+        .push(" THEN 4 WHEN name ~* ")
+        .push_bind(format!("^{}", pattern))
+        .push(" THEN 3 WHEN email ILIKE ")
+        .push_bind(format!("{}%", escape_like(&terms.text)))
+        .push(" THEN 2 WHEN name ~* ")
+        .push_bind(pattern)
+        .push(" THEN 1 ELSE 0 END + similarity(name, ")
+        .push_bind(terms.text.clone())
+        .push(")");
+    if let Some(tsquery) = &terms.tsquery {
+        builder
+            .push(" + ts_rank_cd(")
+            .push(SEARCH_DOCUMENT)
+            .push(", ")
+            .push_bind(tsquery.clone())
+            .push("::tsquery)");
+    }
+    builder.push(")::real");
+}
+
+// 호환용 자모가 초성이면 초성 순서 반환
+fn initial_index(c: char) -> Option<u32> {
+    INITIALS.iter().position(|&initial| initial == c).map(|index| index as u32)
+}
+
+// 검색어 글자 하나가 대신할 수 있는 한글 음절 범위
+// - 초성 자모("ㄱ")는 그 초성으로 시작하는 모든 음절 ("가"~"깋")
+// - 마지막 글자가 받침 없는 음절이면 입력 중일 수 있으므로 받침이 붙은 음절까지 ("기" -> "기"~"깋")
+fn syllable_range(c: char, is_last: bool) -> Option<(char, char)> {
+    let code = c as u32;
+    let (first, last) = if let Some(index) = initial_index(c) {
+        let first = SYLLABLE_FIRST + index * SYLLABLES_PER_INITIAL;
+        (first, first + SYLLABLES_PER_INITIAL - 1)
+    } else if is_last && (SYLLABLE_FIRST..=SYLLABLE_LAST).contains(&code) && (code - SYLLABLE_FIRST).is_multiple_of(FINALS) {
+        (code, code + FINALS - 1)
+    } else {
+        return None;
+    };
+
+    Some((char::from_u32(first)?, char::from_u32(last)?))
+}
+
+// 검색어 글자가 대상 글자와 일치하는지 (대상은 소문자로 바꾼 글자)
+fn char_matches(query: char, target: char, is_last: bool) -> bool {
+    query == target
+        || syllable_range(query, is_last).is_some_and(|(first, last)| (first..=last).contains(&target))
+}
+
+// 이름 검색용 PostgreSQL 정규식 (초성/입력 중인 음절은 음절 범위로 바꾸고 나머지 기호는 이스케이프)
+fn name_pattern(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let mut pattern = String::new();
+
+    for (index, &c) in chars.iter().enumerate() {
+        match syllable_range(c, index + 1 == chars.len()) {
+            Some((first, last)) if first == c => pattern.push_str(&format!("[{}-{}]", first, last)),
+            Some((first, last)) => pattern.push_str(&format!("[{}{}-{}]", c, first, last)),
+            None if c.is_ascii_alphanumeric() || !c.is_ascii() => pattern.push(c),
+            None => {
+                pattern.push('\\');
+                pattern.push(c);
+            }
+        }
+    }
+    pattern
+}
+
+// 검색어 단어와 일치한 부분을 <mark>로 감싸고 나머지는 HTML 이스케이프
+// 일치한 부분이 없으면 None
+fn highlight(text: &str, words: &[String]) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let mut marked = vec![false; chars.len()];
+
+    for word in words {
+        let needle: Vec<char> = word.chars().collect();
+        if needle.is_empty() || needle.len() > lower.len() {
+            continue;
+        }
+
+        let mut start = 0;
+        while start + needle.len() <= lower.len() {
+            let found = needle
+                .iter()
+                .enumerate()
+                .all(|(offset, &c)| char_matches(c, lower[start + offset], offset + 1 == needle.len()));
+            if found {
+                marked[start..start + needle.len()].fill(true);
+                start += needle.len();
+            } else {
+                start += 1;
+            }
+        }
+    }
+
+    if !marked.contains(&true) {
+        return None;
+    }
+
+    let mut result = String::new();
+    for (index, &c) in chars.iter().enumerate() {
+        if marked[index] && (index == 0 || !marked[index - 1]) {
+            result.push_str("<mark>");
+        }
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+        if marked[index] && (index + 1 == chars.len() || !marked[index + 1]) {
+            result.push_str("</mark>");
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(q: &str) -> Vec<String> {
+        SearchTerms::parse(q).words
+    }
+
+    #[test]
+    fn initials_expand_to_syllable_ranges() {
+        assert_eq!(name_pattern("ㅎㄱㄷ"), "[ㅎ하-힣][ㄱ가-깋][ㄷ다-딯]");
+        // 마지막 글자가 받침 없는 음절이면 받침이 붙은 음절까지 ("기" -> "길")
+        assert_eq!(name_pattern("홍기"), "홍[기-깋]");
+        assert_eq!(name_pattern("홍길"), "홍길");
+        assert_eq!(name_pattern("a.b(c)"), "a\\.b\\(c\\)");
+    }
+
+    #[test]
+    fn tsquery_uses_prefix_lexemes_without_initials() {
+        assert_eq!(SearchTerms::parse("  Kim  hong.gd ").tsquery.as_deref(), Some("'kim':* & 'hong':* & 'gd':*"));
+        assert_eq!(SearchTerms::parse("ㅎㄱㄷ").tsquery, None);
+        assert_eq!(SearchTerms::parse("' & !").tsquery, None);
+    }
+
+    #[test]
+    fn highlight_marks_initials_and_partial_syllables() {
+        assert_eq!(highlight("홍길동", &words("ㅎㄱ")).as_deref(), Some("<mark>홍길</mark>동"));
+        assert_eq!(highlight("홍길동", &words("홍기")).as_deref(), Some("<mark>홍길</mark>동"));
+        assert_eq!(highlight("홍길동", &words("길동")).as_deref(), Some("홍<mark>길동</mark>"));
+        assert_eq!(highlight("홍길동", &words("김")), None);
+    }
+
+    #[test]
+    fn highlight_is_case_insensitive_and_escapes_html() {
+        assert_eq!(
+            highlight("Kim <Dev> & Kim", &words("kim dev")).as_deref(),
+            Some("<mark>Kim</mark> &lt;<mark>Dev</mark>&gt; &amp; <mark>Kim</mark>")
+        );
+    }
+}
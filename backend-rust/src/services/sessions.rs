@@ -0,0 +1,239 @@
+use anyhow::Result;
+use chrono::{DateTime, TimeZone, Utc};
+use sqlx::{FromRow, PgPool};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
+
+use crate::models::Claims;
+
+// 토큰 상태 캐시 (jti → 검증 결과)
+// 다른 인스턴스에서 발생한 폐기/비활성화는 TTL이 지나면 반영된다
+pub struct SessionCache {
+    ttl: Duration,
+    entries: RwLock<HashMap<Uuid, CachedStatus>>,
+}
+
+struct CachedStatus {
+    user_id: i32,
+    active: bool,
+    checked_at: Instant,
+}
+
+// 캐시가 이 크기를 넘으면 만료된 항목 정리
+const CACHE_PRUNE_THRESHOLD: usize = 10_000;
+
+impl SessionCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, jti: &Uuid) -> Option<bool> {
+        let entries = self.entries.read().unwrap();
+        entries
+            .get(jti)
+            .filter(|entry| entry.checked_at.elapsed() < self.ttl)
+            .map(|entry| entry.active)
+    }
+
+    fn put(&self, jti: Uuid, user_id: i32, active: bool) {
+        let mut entries = self.entries.write().unwrap();
+        if entries.len() >= CACHE_PRUNE_THRESHOLD {
+            let ttl = self.ttl;
+            entries.retain(|_, entry| entry.checked_at.elapsed() < ttl);
+        }
+        entries.insert(
+            jti,
+            CachedStatus {
+                user_id,
+                active,
+                checked_at: Instant::now(),
+            },
+        );
+    }
+
+    // 사용자의 캐시된 토큰 상태를 모두 제거 (다음 요청 시 DB 재확인)
+    fn invalidate_user(&self, user_id: i32) {
+        self.entries
+            .write()
+            .unwrap()
+            .retain(|_, entry| entry.user_id != user_id);
+    }
+}
+
+#[derive(FromRow)]
+struct SessionState {
+    is_active: bool,
+    jti_revoked: bool,
+    revoked_before: Option<DateTime<Utc>>,
+}
+
+pub struct SessionService {
+    pool: PgPool,
+    cache: Arc<SessionCache>,
+}
+
+impl SessionService {
+    pub fn new(pool: PgPool, cache: Arc<SessionCache>) -> Self {
+        Self { pool, cache }
+    }
+
+    // 액세스 토큰이 아직 유효한 세션인지 확인
+    // (폐기된 jti, 전체 로그아웃 이전 발급, 비활성/삭제된 사용자는 거부)
+    pub async fn is_token_active(&self, claims: &Claims) -> Result<bool> {
+        if let Some(active) = self.cache.get(&claims.jti) {
+            return Ok(active);
+        }
+
+        let user_id: i32 = match claims.sub.parse() {
+            Ok(user_id) => user_id,
+            Err(_) => return Ok(false),
+        };
+
+        let state = sqlx::query_as::<_, SessionState>(
+            r#"
+            SELECT u.is_active,
+                   EXISTS (SELECT 1 FROM revoked_tokens r WHERE r.jti = $2) AS jti_revoked,
+                   s.revoked_before
+            FROM users u
+            LEFT JOIN session_revocations s ON s.user_id = u.id
+            WHERE u.id = $1 AND u.deleted_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .bind(claims.jti)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let active = match state {
+            Some(state) => {
+                let issued_before_revocation = state
+                    .revoked_before
+                    .is_some_and(|revoked_before| issued_before(claims, revoked_before));
+                state.is_active && !state.jti_revoked && !issued_before_revocation
+            }
+            None => false,
+        };
+
+        self.cache.put(claims.jti, user_id, active);
+        Ok(active)
+    }
+
+    // 현재 액세스 토큰 폐기 (로그아웃)
+    pub async fn revoke_token(&self, claims: &Claims) -> Result<()> {
+        let user_id: i32 = claims.sub.parse()?;
+        let expires_at = Utc
+            .timestamp_opt(claims.exp as i64, 0)
+            .single()
+            .unwrap_or_else(Utc::now);
+
+        sqlx::query(
+            r#"
+            INSERT INTO revoked_tokens (jti, user_id, expires_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (jti) DO NOTHING
+            "#,
+        )
+        .bind(claims.jti)
+        .bind(user_id)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        // 만료가 지난 폐기 기록은 더 이상 필요 없음
+        sqlx::query("DELETE FROM revoked_tokens WHERE expires_at < NOW()")
+            .execute(&self.pool)
+            .await?;
+
+        self.cache.put(claims.jti, user_id, false);
+        tracing::info!("액세스 토큰 폐기 완료: 사용자 ID {}", user_id);
+        Ok(())
+    }
+
+    // 사용자의 모든 세션 폐기 (지금까지 발급된 액세스 토큰 전체 무효화)
+    pub async fn revoke_all(&self, user_id: i32) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO session_revocations (user_id, revoked_before)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE SET revoked_before = EXCLUDED.revoked_before
+            "#,
+        )
+        .bind(user_id)
+        // 토큰의 발급 시간과 같은 시계(애플리케이션 서버)로 기록
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await?;
+
+        self.cache.invalidate_user(user_id);
+        tracing::info!("전체 세션 폐기 완료: 사용자 ID {}", user_id);
+        Ok(())
+    }
+
+    // 사용자 상태 변경(비활성화/삭제) 후 캐시 무효화
+    pub fn invalidate_user(&self, user_id: i32) {
+        self.cache.invalidate_user(user_id);
+    }
+}
+
+// 전체 세션 폐기 시각 이전(같은 시각 포함)에 발급된 토큰인지 확인
+// 밀리초 발급 시간이 있으면 밀리초로 비교하고, 없으면 iat가 초 단위이므로 같은 초에 발급된 토큰도 폐기 대상으로 간주
+fn issued_before(claims: &Claims, revoked_before: DateTime<Utc>) -> bool {
+    match claims.iat_ms {
+        Some(iat_ms) => iat_ms <= revoked_before.timestamp_millis(),
+        None => claims.iat as i64 <= revoked_before.timestamp(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims_issued_at(issued_at: DateTime<Utc>, with_millis: bool) -> Claims {
+        Claims {
+            sub: "1".to_string(),
+            email: "user@example.com".to_string(),
+            role: "user".to_string(),
+            jti: Uuid::new_v4(),
+            iat: issued_at.timestamp() as usize,
+            exp: issued_at.timestamp() as usize + 900,
+            iat_ms: with_millis.then(|| issued_at.timestamp_millis()),
+        }
+    }
+
+    #[test]
+    fn login_in_same_second_after_revocation_stays_active() {
+        // 비밀번호 재설정(전체 세션 폐기) 직후 같은 초에 다시 로그인
+        let revoked_before = Utc.timestamp_millis_opt(1_700_000_000_400).unwrap();
+        let login = Utc.timestamp_millis_opt(1_700_000_000_900).unwrap();
+
+        assert!(!issued_before(&claims_issued_at(login, true), revoked_before));
+    }
+
+    #[test]
+    fn tokens_issued_before_or_at_revocation_are_revoked() {
+        let revoked_before = Utc.timestamp_millis_opt(1_700_000_000_400).unwrap();
+
+        for issued_at in [1_699_999_999_999, 1_700_000_000_100, 1_700_000_000_400] {
+            let issued_at = Utc.timestamp_millis_opt(issued_at).unwrap();
+            assert!(issued_before(&claims_issued_at(issued_at, true), revoked_before));
+        }
+    }
+
+    #[test]
+    fn tokens_without_millis_fall_back_to_seconds() {
+        let revoked_before = Utc.timestamp_millis_opt(1_700_000_000_400).unwrap();
+
+        let same_second = Utc.timestamp_millis_opt(1_700_000_000_900).unwrap();
+        assert!(issued_before(&claims_issued_at(same_second, false), revoked_before));
+
+        let next_second = Utc.timestamp_millis_opt(1_700_000_001_000).unwrap();
+        assert!(!issued_before(&claims_issued_at(next_second, false), revoked_before));
+    }
+}
@@ -0,0 +1,189 @@
+use anyhow::Result;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{Duration, Utc};
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    config::Config,
+    middleware::create_jwt_token,
+    models::{RefreshToken, User},
+};
+
+// 발급된 액세스/리프레시 토큰 쌍
+pub struct IssuedTokens {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_in: i64,
+}
+
+pub struct TokenService {
+    pool: PgPool,
+    config: Config,
+}
+
+impl TokenService {
+    pub fn new(pool: PgPool, config: Config) -> Self {
+        Self { pool, config }
+    }
+
+    // 로그인/회원가입 시 새 토큰 패밀리로 토큰 발급
+    pub async fn issue(&self, user: &User) -> Result<IssuedTokens> {
+        let mut conn = self.pool.acquire().await?;
+        self.issue_in_family(&mut conn, user, Uuid::new_v4()).await
+    }
+
+    // 리프레시 토큰 교체 (한 번 사용된 토큰은 재사용 불가)
+    // 유효하지 않거나 만료/재사용된 토큰이면 None 반환
+    pub async fn rotate(&self, refresh_token: &str) -> Result<Option<(User, IssuedTokens)>> {
+        let mut tx = self.pool.begin().await?;
+
+        // 동시 갱신 요청이 같은 토큰을 두 번 교체하지 못하도록 행 잠금
+        let stored = sqlx::query_as::<_, RefreshToken>(
+            r#"
+            SELECT id, user_id, family_id, expires_at, used_at, revoked_at
+            FROM refresh_tokens
+            WHERE token_hash = $1
+            FOR UPDATE
+            "#,
+        )
+        .bind(hash_token(refresh_token))
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let stored = match stored {
+            Some(stored) => stored,
+            None => return Ok(None),
+        };
+
+        // 이미 사용되었거나 폐기된 토큰의 재사용 → 탈취로 간주하고 패밀리 전체 폐기
+        if stored.used_at.is_some() || stored.revoked_at.is_some() {
+            revoke_family(&mut tx, stored.family_id).await?;
+            tx.commit().await?;
+            tracing::warn!(
+                "리프레시 토큰 재사용 감지, 토큰 패밀리 폐기: 사용자 ID {}, 패밀리 {}",
+                stored.user_id,
+                stored.family_id
+            );
+            return Ok(None);
+        }
+
+        if stored.expires_at <= Utc::now() {
+            return Ok(None);
+        }
+
+        let user = sqlx::query_as::<_, User>(
+            "SELECT id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash FROM users WHERE id = $1"
+        )
+        .bind(stored.user_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        // 비활성화되거나 삭제된 사용자는 갱신 불가
+        let user = match user {
+            Some(user) if user.is_active && user.deleted_at.is_none() => user,
+            _ => {
+                revoke_family(&mut tx, stored.family_id).await?;
+                tx.commit().await?;
+                return Ok(None);
+            }
+        };
+
+        sqlx::query("UPDATE refresh_tokens SET used_at = NOW() WHERE id = $1")
+            .bind(stored.id)
+            .execute(&mut *tx)
+            .await?;
+
+        let tokens = self.issue_in_family(&mut tx, &user, stored.family_id).await?;
+        tx.commit().await?;
+
+        Ok(Some((user, tokens)))
+    }
+
+    // 리프레시 토큰이 속한 패밀리 폐기 (로그아웃)
+    pub async fn revoke(&self, user_id: i32, refresh_token: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE refresh_tokens SET revoked_at = NOW()
+            WHERE revoked_at IS NULL
+              AND family_id = (
+                  SELECT family_id FROM refresh_tokens WHERE token_hash = $1 AND user_id = $2
+              )
+            "#,
+        )
+        .bind(hash_token(refresh_token))
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // 사용자의 모든 리프레시 토큰 폐기 (전체 로그아웃, 계정 비활성화)
+    pub async fn revoke_all_for_user(&self, user_id: i32) -> Result<()> {
+        sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = NOW() WHERE user_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn issue_in_family(
+        &self,
+        conn: &mut PgConnection,
+        user: &User,
+        family_id: Uuid,
+    ) -> Result<IssuedTokens> {
+        let access_token = create_jwt_token(&self.config, user.id, &user.email, &user.role)?;
+        let refresh_token = generate_token();
+        let expires_at = Utc::now() + Duration::days(self.config.refresh_token_expires_days);
+
+        sqlx::query(
+            r#"
+            INSERT INTO refresh_tokens (user_id, token_hash, family_id, expires_at)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(user.id)
+        .bind(hash_token(&refresh_token))
+        .bind(family_id)
+        .bind(expires_at)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(IssuedTokens {
+            access_token,
+            refresh_token,
+            expires_in: self.config.jwt_access_expires_minutes * 60,
+        })
+    }
+}
+
+// 토큰 패밀리 전체 폐기
+async fn revoke_family(conn: &mut PgConnection, family_id: Uuid) -> Result<()> {
+    sqlx::query(
+        "UPDATE refresh_tokens SET revoked_at = NOW() WHERE family_id = $1 AND revoked_at IS NULL",
+    )
+    .bind(family_id)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+// 추측 불가능한 불투명 토큰 생성 (256비트 난수)
+pub(crate) fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+// 저장용 토큰 해시 (SHA-256 hex)
+pub(crate) fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
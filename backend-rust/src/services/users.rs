@@ -0,0 +1,344 @@
+use crate::error::AppResult;
+use crate::models::{ClientInfo, NewAuditEvent, User, RegisterRequest, UpdateUserRequest, UserListQuery};
+use crate::services::{AuditService, AvatarService};
+use crate::storage::BlobStore;
+use bcrypt::{hash, verify, DEFAULT_COST};
+use chrono::Utc;
+use serde_json::json;
+use sqlx::{PgExecutor, PgPool, Postgres, QueryBuilder};
+use std::{sync::Arc, time::Duration};
+
+pub struct UserService {
+    pool: PgPool,
+}
+
+impl UserService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 사용자 생성 (회원가입)
+    // 누구나 호출할 수 있으므로 역할은 항상 기본 역할(user), 다른 역할은 관리자 API로 부여
+    // 이메일 중복은 users.email 유니크 제약 위반으로 AppError::Conflict(email_exists) 반환
+    pub async fn create_user(&self, request: RegisterRequest) -> AppResult<User> {
+        // 비밀번호 해싱
+        let hashed_password = hash(request.password, DEFAULT_COST)?;
+        
+        // 데이터베이스에 사용자 생성
+        let user = sqlx::query_as::<_, User>(
+            r#"
+            INSERT INTO users (email, password, name, role, is_active, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash
+            "#,
+        )
+        .bind(&request.email)
+        .bind(&hashed_password)
+        .bind(&request.name)
+        .bind("user")
+        .bind(true)
+        .bind(Utc::now())
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        tracing::info!("새 사용자 생성 완료: {}", user.email);
+        Ok(user)
+    }
+
+    // 이메일로 사용자 조회 (삭제된 사용자 제외)
+    pub async fn find_by_email(&self, email: &str) -> AppResult<Option<User>> {
+        let user = sqlx::query_as::<_, User>(
+            "SELECT id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash FROM users WHERE email = $1 AND deleted_at IS NULL"
+        )
+        .bind(email)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(user)
+    }
+
+    // ID로 사용자 조회 (삭제된 사용자 제외)
+    pub async fn find_by_id(&self, id: i32) -> AppResult<Option<User>> {
+        let user = sqlx::query_as::<_, User>(
+            "SELECT id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash FROM users WHERE id = $1 AND deleted_at IS NULL"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(user)
+    }
+
+    // ID로 사용자 조회 (영구 삭제 전의 삭제된 사용자 포함)
+    pub async fn find_by_id_including_deleted(&self, id: i32) -> AppResult<Option<User>> {
+        let user = sqlx::query_as::<_, User>(
+            "SELECT id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash FROM users WHERE id = $1"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(user)
+    }
+
+    // 사용자 목록 조회 (필터/정렬/페이지네이션), 필터 조건에 맞는 전체 개수도 함께 반환
+    pub async fn find_page(&self, query: &UserListQuery) -> AppResult<(Vec<User>, i64)> {
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM users");
+        push_filters(&mut count_query, query);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut list_query = QueryBuilder::<Postgres>::new(
+            "SELECT id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash FROM users",
+        );
+        push_filters(&mut list_query, query);
+        // 정렬 컬럼은 화이트리스트 enum에서만 오므로 직접 삽입해도 안전, 동일 값은 id로 순서 고정
+        list_query.push(format!(
+            " ORDER BY {} {}, id {}",
+            query.sort.column(),
+            query.order.keyword(),
+            query.order.keyword()
+        ));
+        list_query.push(" LIMIT ").push_bind(query.per_page);
+        list_query.push(" OFFSET ").push_bind((query.page - 1) * query.per_page);
+
+        let users = list_query
+            .build_query_as::<User>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok((users, total))
+    }
+
+    // 사용자 정보 수정
+    pub async fn update_user(&self, id: i32, request: UpdateUserRequest) -> AppResult<Option<User>> {
+        let mut query = "UPDATE users SET updated_at = NOW()".to_string();
+        let mut params: Vec<String> = vec![];
+        let mut param_count = 1;
+
+        // 동적 쿼리 생성
+        if let Some(email) = &request.email {
+            // 이메일이 바뀌면 다시 인증해야 함 (SET 절의 email은 변경 전 값)
+            query.push_str(&format!(
+                ", email = ${0}, email_verified_at = CASE WHEN email = ${0} THEN email_verified_at ELSE NULL END",
+                param_count
+            ));
+            params.push(email.clone());
+            param_count += 1;
+        }
+
+        if let Some(password) = &request.password {
+            let hashed_password = hash(password, DEFAULT_COST)?;
+            query.push_str(&format!(", password = ${}", param_count));
+            params.push(hashed_password);
+            param_count += 1;
+        }
+
+        if let Some(name) = &request.name {
+            query.push_str(&format!(", name = ${}", param_count));
+            params.push(name.clone());
+            param_count += 1;
+        }
+
+        if let Some(role) = &request.role {
+            query.push_str(&format!(", role = ${}", param_count));
+            params.push(role.clone());
+            param_count += 1;
+        }
+
+        if let Some(is_active) = request.is_active {
+            query.push_str(&format!(", is_active = ${}::boolean", param_count));
+            params.push(is_active.to_string());
+            param_count += 1;
+        }
+
+        query.push_str(&format!(
+            " WHERE id = ${} AND deleted_at IS NULL RETURNING id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash",
+            param_count
+        ));
+
+        // 쿼리 실행
+        let mut sql_query = sqlx::query_as::<_, User>(&query);
+        
+        for param in params {
+            sql_query = sql_query.bind(param);
+        }
+        sql_query = sql_query.bind(id);
+
+        let user = sql_query.fetch_optional(&self.pool).await?;
+
+        if user.is_some() {
+            tracing::info!("사용자 정보 수정 완료: ID {}", id);
+        }
+
+        Ok(user)
+    }
+
+    // 사용자 삭제 (소프트 삭제)
+    // 원래 이메일은 deleted_email에 보관하고 email을 'deleted+<id>@deleted.invalid'로 바꿔
+    // 다른 백엔드의 이메일 조회/로그인에서 제외하고 같은 이메일로 다시 가입할 수 있게 함
+    // is_active는 그대로 두어 복구하면 삭제 전 상태로 돌아가고, 보관 기간이 지나면 purge_deleted에서 영구 삭제
+    pub async fn delete_user(&self, id: i32) -> AppResult<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users SET deleted_at = NOW(), deleted_email = email, email = 'deleted+' || id || '@deleted.invalid'
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        let deleted = result.rows_affected() > 0;
+        
+        if deleted {
+            tracing::info!("사용자 삭제 완료: ID {}", id);
+        }
+
+        Ok(deleted)
+    }
+
+    // 삭제된 사용자 복구 (이메일을 되돌리고 is_active는 삭제 전 상태 유지)
+    // 삭제 후 같은 이메일로 다시 가입한 사용자가 있으면 유니크 제약 위반으로 AppError::Conflict(email_exists) 반환
+    pub async fn restore_user(&self, id: i32) -> AppResult<Option<User>> {
+        let user = sqlx::query_as::<_, User>(
+            r#"
+            UPDATE users SET deleted_at = NULL, email = COALESCE(deleted_email, email), deleted_email = NULL
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            RETURNING id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if user.is_some() {
+            tracing::info!("사용자 복구 완료: ID {}", id);
+        }
+
+        Ok(user)
+    }
+
+    // 프로필 사진 변경 (None이면 제거)
+    // 새 사진은 AvatarService::save와 같은 트랜잭션에서 바꿔야 커밋 전에 파일이 지워지지 않음
+    pub async fn set_avatar<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        id: i32,
+        avatar_hash: Option<&str>,
+    ) -> AppResult<Option<User>> {
+        let user = sqlx::query_as::<_, User>(
+            r#"
+            UPDATE users SET avatar_hash = $1, updated_at = NOW()
+            WHERE id = $2 AND deleted_at IS NULL
+            RETURNING id, email, password, name, role, is_active, email_verified_at, created_at, updated_at, deleted_at, avatar_hash
+            "#,
+        )
+        .bind(avatar_hash)
+        .bind(id)
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(user)
+    }
+
+    // 삭제 후 보관 기간이 지난 사용자 영구 삭제, 삭제된 사용자 ID와 프로필 사진 해시 목록 반환
+    // (토큰, 역할 등 연관 데이터는 외래 키 ON DELETE CASCADE로 함께 삭제)
+    pub async fn purge_deleted(&self, retention_days: i64) -> AppResult<Vec<(i32, Option<String>)>> {
+        let purged = sqlx::query_as::<_, (i32, Option<String>)>(
+            "DELETE FROM users WHERE deleted_at < NOW() - make_interval(days => $1) RETURNING id, avatar_hash",
+        )
+        .bind(retention_days as i32)
+        .fetch_all(&self.pool)
+        .await?;
+
+        if !purged.is_empty() {
+            tracing::info!("삭제된 사용자 영구 삭제 완료: {}명", purged.len());
+        }
+
+        Ok(purged)
+    }
+
+    // 비밀번호 검증
+    pub fn verify_password(&self, password: &str, hashed_password: &str) -> AppResult<bool> {
+        Ok(verify(password, hashed_password)?)
+    }
+}
+
+// 보관 기간이 지난 삭제 사용자를 주기적으로 영구 삭제하는 백그라운드 작업
+// 여러 인스턴스가 동시에 실행해도 같은 행은 한 번만 삭제되므로 별도 잠금 없이 실행
+// 더 이상 쓰는 사용자가 없는 프로필 사진 파일도 함께 삭제
+pub fn spawn_purge_task(pool: PgPool, blob_store: Arc<dyn BlobStore>, retention_days: i64, interval: Duration) {
+    tokio::spawn(async move {
+        let user_service = UserService::new(pool.clone());
+        let audit_service = AuditService::new(pool.clone());
+        let avatar_service = AvatarService::new(blob_store);
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+
+            let purged = match user_service.purge_deleted(retention_days).await {
+                Ok(purged) => purged,
+                Err(err) => {
+                    tracing::error!("삭제된 사용자 영구 삭제 실패: {}", err);
+                    continue;
+                }
+            };
+
+            for (id, avatar_hash) in purged {
+                if let Some(hash) = avatar_hash {
+                    if let Err(err) = avatar_service.delete_if_unused(&pool, &hash).await {
+                        tracing::error!("프로필 사진 삭제 실패 ({}): {}", hash, err);
+                    }
+                }
+
+                audit_service
+                    .record(
+                        NewAuditEvent::new("user.purge", &ClientInfo::default())
+                            .target("user", id)
+                            .changes(json!({ "retention_days": retention_days })),
+                    )
+                    .await;
+            }
+        }
+    });
+}
+
+// 목록 조회 필터 조건 추가 (삭제 여부, 역할, 활성 상태, 이메일/이름 검색)
+fn push_filters(builder: &mut QueryBuilder<Postgres>, query: &UserListQuery) {
+    builder.push(" WHERE TRUE");
+
+    if query.include_deleted != Some(true) {
+        builder.push(" AND deleted_at IS NULL");
+    }
+
+    if let Some(role) = &query.role {
+        builder.push(" AND role = ").push_bind(role.clone());
+    }
+
+    if let Some(is_active) = query.is_active {
+        builder.push(" AND is_active = ").push_bind(is_active);
+    }
+
+    if let Some(q) = query.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+        let pattern = format!("%{}%", escape_like(q));
+        builder
+            .push(" AND (email ILIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR name ILIKE ")
+            .push_bind(pattern)
+            .push(")");
+    }
+}
+
+// LIKE 패턴 특수문자 이스케이프
+pub(super) fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}